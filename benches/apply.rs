@@ -0,0 +1,167 @@
+//! Benchmarks the cost of applying effects with each [`EffectMode`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+use criterion::measurement::WallTime;
+use criterion::{BatchSize, BenchmarkGroup, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+#[derive(Component, Default, Clone)]
+struct Burn;
+
+/// The number of effects applied during each iteration.
+const COUNT: usize = 100;
+
+fn effect<B: Bundle>(mode: EffectMode, bundle: B) -> EffectBundle<B> {
+    EffectBundle {
+        name: Name::new("Burn"),
+        id: None,
+        category: None,
+        mode: Some(mode),
+        priority: EffectPriority::default(),
+        resolver: None,
+        stacks: None,
+        tags: EffectTags::default(),
+        polarity: EffectPolarity::default(),
+        bundle,
+    }
+}
+
+fn setup() -> (World, Entity) {
+    let mut world = World::new();
+
+    // Stacks are added manually, so that the number of merged components is controlled.
+    world.insert_resource(AlchemySettings {
+        track_merge_stacks: false,
+        ..Default::default()
+    });
+
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks)
+        .register::<Magnitude>(merge_effect_magnitude)
+        .register::<Lifetime>(merge_effect_timer::<Lifetime>)
+        .register::<Delay>(merge_effect_timer::<Delay>);
+
+    let target = world.spawn_empty().id();
+    (world, target)
+}
+
+fn stack(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stack");
+
+    group.bench_function("individual", |b| {
+        b.iter_batched(
+            setup,
+            |(mut world, target)| {
+                for _ in 0..COUNT {
+                    world
+                        .commands()
+                        .entity(target)
+                        .with_effect(effect(EffectMode::Stack, Burn));
+                }
+                world.flush();
+                black_box(world)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.bench_function("batched", |b| {
+        b.iter_batched(
+            setup,
+            |(mut world, target)| {
+                world
+                    .commands()
+                    .entity(target)
+                    .with_stacked_effects((0..COUNT).map(|_| effect(EffectMode::Stack, Burn)));
+                world.flush();
+                black_box(world)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.finish();
+}
+
+/// Applies an effect once during setup, and then reapplies it [`COUNT`] times.
+fn reapply<B: Bundle + Clone>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: &str,
+    mode: EffectMode,
+    bundle: B,
+) {
+    group.bench_function(id, |b| {
+        b.iter_batched(
+            || {
+                let (mut world, target) = setup();
+                world
+                    .commands()
+                    .entity(target)
+                    .with_effect(effect(mode, bundle.clone()));
+                world.flush();
+                (world, target)
+            },
+            |(mut world, target)| {
+                for _ in 0..COUNT {
+                    world
+                        .commands()
+                        .entity(target)
+                        .with_effect(effect(mode, bundle.clone()));
+                }
+                world.flush();
+                black_box(world)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert");
+    reapply(&mut group, "reapply", EffectMode::Insert, Burn);
+    group.finish();
+}
+
+/// Merges with an increasing number of registered merge components.
+fn merge(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge");
+
+    reapply(&mut group, "0", EffectMode::Merge, Burn);
+    reapply(&mut group, "1", EffectMode::Merge, (Burn, EffectStacks(1)));
+    reapply(
+        &mut group,
+        "2",
+        EffectMode::Merge,
+        (Burn, EffectStacks(1), Magnitude::new(1.0)),
+    );
+    reapply(
+        &mut group,
+        "3",
+        EffectMode::Merge,
+        (
+            Burn,
+            EffectStacks(1),
+            Magnitude::new(1.0),
+            Lifetime::from_seconds(1.0),
+        ),
+    );
+    reapply(
+        &mut group,
+        "4",
+        EffectMode::Merge,
+        (
+            Burn,
+            EffectStacks(1),
+            Magnitude::new(1.0),
+            Lifetime::from_seconds(1.0),
+            Delay::from_seconds(1.0),
+        ),
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, stack, insert, merge);
+criterion_main!(benches);
@@ -0,0 +1,115 @@
+//! A reactive effect, which is applied by an observer whenever the target takes damage.
+//!
+//! Rather than polling for damage every frame, [`apply_effect_on`] creates an observer that
+//! applies a bleed to the target of each `Damaged` event.
+//! Because the event is triggered using `Commands`, the bleed is applied before `deal_bleed_damage` runs.
+
+use bevy::prelude::*;
+use bevy_alchemy::{
+    AlchemyPlugin, Delay, EffectBundle, EffectMode, EffectTimer, Effecting, Lifetime,
+    apply_effect_on,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, AlchemyPlugin))
+        // Applies a bleed whenever an entity is damaged.
+        .add_observer(apply_effect_on(|damaged: &Damaged| EffectBundle {
+            mode: Some(EffectMode::Insert), // Getting hit again refreshes the bleed.
+            ..EffectBundle::new((
+                Lifetime::from_seconds(3.0), // The duration of the effect.
+                Delay::from_seconds(0.5),    // The time between damage ticks.
+                Bleed {
+                    damage: (damaged.amount / 5).max(1), // Harder hits cause worse bleeding.
+                },
+            ))
+        }))
+        .add_systems(Startup, init_scene)
+        .add_systems(Update, (on_space_pressed, deal_bleed_damage).chain())
+        .add_systems(PostUpdate, update_ui)
+        .run();
+}
+
+#[derive(Component)]
+struct Health(i32);
+
+/// Triggered when an entity takes damage.
+#[derive(EntityEvent)]
+struct Damaged {
+    entity: Entity,
+    amount: i32,
+}
+
+/// Deals damage over time to the target entity.
+#[derive(Component)]
+struct Bleed {
+    damage: i32,
+}
+
+/// Spawn a target on startup.
+fn init_scene(mut commands: Commands) {
+    commands.spawn((Name::new("Target"), Health(100)));
+    commands.spawn(Text::default());
+    commands.spawn(Camera2d);
+}
+
+/// When space is pressed, hit the target.
+fn on_space_pressed(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut target: Single<(Entity, &mut Health)>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let amount = 10;
+    target.1.0 -= amount;
+
+    commands.trigger(Damaged {
+        entity: target.0,
+        amount,
+    });
+}
+
+/// Runs every frame and deals the bleed damage.
+fn deal_bleed_damage(
+    effects: Query<(&Effecting, &Delay, &Bleed)>,
+    mut targets: Query<&mut Health>,
+) {
+    for (target, delay, bleed) in effects {
+        // We wait until the delay finishes to apply the damage.
+        // On long frames, the delay can finish more than once, so we deal damage for each tick.
+        let ticks = delay.ticks_this_frame() as i32;
+        if ticks == 0 {
+            continue;
+        }
+
+        // Skip if the target doesn't have health.
+        let Ok(mut health) = targets.get_mut(target.0) else {
+            continue;
+        };
+
+        // Otherwise, deal the damage.
+        health.0 -= bleed.damage * ticks;
+    }
+}
+
+fn update_ui(
+    mut ui: Single<&mut Text>,
+    target: Single<&Health>,
+    effects: Query<(Entity, &Lifetime, &Bleed)>,
+) {
+    ui.0 = "Press Space to hit the target\n\n".to_string();
+
+    ui.0 += &format!("Health: {}\n\n", target.0);
+
+    for (entity, lifetime, bleed) in &effects {
+        ui.0 += &format!(
+            "{} - {:.1}s ({} damage per tick)\n",
+            entity,
+            lifetime.timer.remaining_secs(),
+            bleed.damage
+        );
+    }
+}
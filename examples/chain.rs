@@ -0,0 +1,132 @@
+//! A chaining effect, where poison applies "weakened" to its target when it expires.
+//!
+//! The poison uses [`LingerOnExpire`], so that it is marked with [`EffectExpiring`] for one frame
+//! before being despawned, giving systems a chance to react to it ending.
+
+use bevy::prelude::*;
+use bevy_alchemy::{
+    AlchemyCommandsExt, AlchemyPlugin, Delay, EffectBundle, EffectCommandsExt, EffectExpiring,
+    EffectMode, EffectTimer, Effecting, Lifetime, LingerOnExpire,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, AlchemyPlugin))
+        .add_systems(Startup, init_scene)
+        .add_systems(
+            Update,
+            (
+                on_space_pressed,
+                deal_poison_damage,
+                weaken_on_poison_expired,
+            ),
+        )
+        .add_systems(PostUpdate, update_ui)
+        .run();
+}
+
+#[derive(Component)]
+struct Health(i32);
+
+/// Deals damage over time to the target entity.
+#[derive(Component, Default)]
+struct Poison {
+    damage: i32,
+}
+
+/// Applied after poison expires. This example only displays it, but it could reduce damage or speed.
+#[derive(Component, Default)]
+struct Weakened;
+
+/// Spawn a target on startup.
+fn init_scene(mut commands: Commands) {
+    commands.spawn((Name::new("Target"), Health(100)));
+    commands.spawn(Text::default());
+    commands.spawn(Camera2d);
+}
+
+/// When space is pressed, apply poison to the target.
+fn on_space_pressed(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    target: Single<Entity, With<Health>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    commands.entity(*target).with_effect(EffectBundle {
+        name: Name::new("Poison"),
+        bundle: (
+            Lifetime::from_seconds(3.0),
+            Delay::from_seconds(1.0).trigger_immediately(),
+            LingerOnExpire, // Keep the effect around for a frame after it expires.
+            Poison { damage: 1 },
+        ),
+        ..default()
+    });
+}
+
+/// Runs every frame and deals the poison damage.
+fn deal_poison_damage(
+    effects: Query<(&Effecting, &Delay, &Poison), Without<EffectExpiring>>,
+    mut targets: Query<&mut Health>,
+) {
+    for (target, delay, poison) in effects {
+        let ticks = delay.ticks_this_frame() as i32;
+        if ticks == 0 {
+            continue;
+        }
+
+        let Ok(mut health) = targets.get_mut(target.0) else {
+            continue;
+        };
+
+        health.0 -= poison.damage * ticks;
+    }
+}
+
+/// When poison expires, apply weakened to the same target.
+fn weaken_on_poison_expired(
+    mut commands: Commands,
+    effects: Query<&Effecting, (With<Poison>, Added<EffectExpiring>)>,
+) {
+    for effecting in &effects {
+        commands.apply_effect_to_target(
+            effecting,
+            EffectBundle {
+                name: Name::new("Weakened"),
+                mode: Some(EffectMode::Insert), // Re-applying refreshes the duration, rather than stacking.
+                bundle: (Lifetime::from_seconds(5.0), Weakened),
+                ..default()
+            },
+        );
+    }
+}
+
+fn update_ui(
+    mut ui: Single<&mut Text>,
+    target: Single<&Health>,
+    poison: Query<(Entity, &Lifetime), With<Poison>>,
+    weakened: Query<(Entity, &Lifetime), With<Weakened>>,
+) {
+    ui.0 = "Press Space to apply poison\n\n".to_string();
+
+    ui.0 += &format!("Health: {}\n\n", target.0);
+
+    for (entity, lifetime) in &poison {
+        ui.0 += &format!(
+            "{} - Poison {:.1}s\n",
+            entity,
+            lifetime.timer.remaining_secs()
+        );
+    }
+
+    for (entity, lifetime) in &weakened {
+        ui.0 += &format!(
+            "{} - Weakened {:.1}s\n",
+            entity,
+            lifetime.timer.remaining_secs()
+        );
+    }
+}
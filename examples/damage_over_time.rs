@@ -0,0 +1,76 @@
+//! The `poison` example, using the built-in [`DamageOverTime`] effect.
+//!
+//! Instead of writing a damage system, the health component implements [`Damageable`],
+//! and the [`DamageOverTimePlugin`] deals the damage.
+//! Requires the `damage` feature.
+
+use bevy::prelude::*;
+use bevy_alchemy::{
+    AlchemyPlugin, DamageOverTime, DamageOverTimePlugin, Damageable, Delay, EffectBundle,
+    EffectCommandsExt, EffectTimer, Lifetime,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            AlchemyPlugin,
+            DamageOverTimePlugin::<Health>::new(),
+        ))
+        .add_systems(Startup, init_scene)
+        .add_systems(Update, on_space_pressed)
+        .add_systems(PostUpdate, update_ui)
+        .run();
+}
+
+#[derive(Component)]
+struct Health(f32);
+
+impl Damageable for Health {
+    fn damage(&mut self, amount: f32) {
+        self.0 -= amount;
+    }
+}
+
+/// Spawn a target on startup.
+fn init_scene(mut commands: Commands) {
+    commands.spawn((Name::new("Target"), Health(100.0)));
+    commands.spawn(Text::default());
+    commands.spawn(Camera2d);
+}
+
+/// When space is pressed, apply poison to the target.
+fn on_space_pressed(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    target: Single<Entity, With<Health>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    commands.entity(*target).with_effect(EffectBundle::new((
+        Lifetime::from_seconds(3.0), // The duration of the effect.
+        Delay::from_seconds(1.0).trigger_immediately(), // The time between damage ticks.
+        DamageOverTime::new(1.0),    // The amount of damage to apply per tick.
+    )));
+}
+
+fn update_ui(
+    mut ui: Single<&mut Text>,
+    target: Single<&Health>,
+    effects: Query<(Entity, &Lifetime, &Delay), With<DamageOverTime>>,
+) {
+    ui.0 = "Press Space to apply poison\n\n".to_string();
+
+    ui.0 += &format!("Health: {}\n\n", target.0);
+
+    for (entity, lifetime, delay) in &effects {
+        ui.0 += &format!(
+            "{} - {:.1}s (tick in {:.1}s)\n",
+            entity,
+            lifetime.timer.remaining_secs(),
+            delay.timer.remaining_secs()
+        );
+    }
+}
@@ -5,7 +5,13 @@
 //! This uses [`EffectMode::Merge`], which prevents having multiple of the effect applied at the
 //! same time (no 10x speed multiplier for you).
 //!
-//! There is a second version of this example, which uses Bevy Auto Plugin.
+//! The buff is dynamic, meaning it is recalculated every frame. To instead use the target's stats from when
+//! the effect was applied, see [`Snapshot`].
+//!
+//! The scaling is handled by [`Decaying`], which is applied every frame by the [`StatEffectPlugin`].
+//! There is a second version of this example, which uses Bevy Auto Plugin and applies the buff manually.
+//!
+//! Requires the `immediate_stats` feature.
 
 use bevy::prelude::*;
 use bevy_alchemy::*;
@@ -13,10 +19,11 @@ use immediate_stats::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, AlchemyPlugin, ImmediateStatsPlugin))
-        .add_plugins(ResetComponentPlugin::<MovementSpeed>::new())
+        .add_plugins((DefaultPlugins, AlchemyPlugin))
+        // Resets speed every frame, and applies `Decaying<MovementSpeed>` after the reset.
+        .add_plugins(StatEffectPlugin::<MovementSpeed>::new())
         .add_systems(Startup, init_scene)
-        .add_systems(Update, (on_space_pressed, apply_speed_boost))
+        .add_systems(Update, on_space_pressed)
         .add_systems(PostUpdate, update_ui)
         .run();
 }
@@ -25,12 +32,6 @@ fn main() {
 #[derive(Component, StatContainer)]
 struct MovementSpeed(Stat);
 
-/// Applies a speed boost, which decreases throughout its duration.
-#[derive(Component, Default)]
-struct DecayingSpeed {
-    start_speed_boost: Modifier,
-}
-
 /// Spawn a target on startup.
 fn init_scene(mut commands: Commands) {
     commands.spawn((Name::new("Target"), MovementSpeed(Stat::new(100))));
@@ -49,44 +50,26 @@ fn on_space_pressed(
     }
 
     commands.entity(*target).with_effect(EffectBundle {
-        mode: EffectMode::Insert, // Block having multiple of effect stacked on a single target.
-        bundle: (
+        mode: Some(EffectMode::Insert), // Block having multiple of effect stacked on a single target.
+        ..EffectBundle::new((
             Lifetime::from_seconds(2.0), // The duration of the effect.
-            DecayingSpeed {
-                start_speed_boost: Modifier {
+            // A speed boost, which decreases throughout its duration.
+            Decaying::new(
+                Modifier {
                     bonus: 10,
                     multiplier: 2.0,
                 },
-            },
-        ),
-        ..default()
+                |speed: &mut MovementSpeed| &mut speed.0,
+            ),
+        ))
     });
 }
 
-/// Applies the effect to the target. Because of how Immediate Stats works, this needs to run every frame.
-fn apply_speed_boost(
-    effects: Query<(&Effecting, &Lifetime, &DecayingSpeed)>,
-    mut targets: Query<&mut MovementSpeed>,
-) {
-    for (target, lifetime, effect) in effects {
-        // Skip if the target doesn't have movement speed.
-        let Ok(mut speed) = targets.get_mut(target.0) else {
-            continue;
-        };
-
-        // Otherwise, apply the buff, scaled by the remaining time.
-        speed.0.apply_scaled(
-            effect.start_speed_boost,
-            lifetime.timer.fraction_remaining(),
-        );
-    }
-}
-
 /// Updates the UI to match the world state.
 fn update_ui(
     mut ui: Single<&mut Text>,
     target: Single<&MovementSpeed>,
-    effects: Query<(Entity, &Lifetime, &DecayingSpeed)>,
+    effects: Query<(Entity, &Lifetime, &Decaying<MovementSpeed>)>,
 ) {
     ui.0 = "Press Space to apply decaying movement speed\n\n".to_string();
 
@@ -97,9 +80,7 @@ fn update_ui(
             "{} - {:.1}s ({:.1})\n",
             entity,
             lifetime.timer.remaining_secs(),
-            speed
-                .start_speed_boost
-                .scaled(lifetime.timer.fraction_remaining())
+            speed.scaled(lifetime, None)
         );
     }
 }
@@ -53,7 +53,7 @@ fn on_space_pressed(
     }
 
     commands.entity(*target).with_effect(EffectBundle {
-        mode: EffectMode::Insert, // Block having multiple of effect stacked on a single target.
+        mode: Some(EffectMode::Insert), // Block having multiple of effect stacked on a single target.
         bundle: (
             Lifetime::from_seconds(2.0), // The duration of the effect.
             DecayingSpeed {
@@ -44,6 +44,7 @@ fn on_space_pressed(
         return;
     }
 
+    // The effect is applied when commands are flushed, so it won't be visible until the next system.
     commands.entity(*target).with_effect(EffectBundle {
         bundle: (
             Lifetime::from_seconds(3.0), // The duration of the effect.
@@ -62,7 +63,9 @@ fn deal_poison_damage(
 ) {
     for (target, delay, poison) in effects {
         // We wait until the delay finishes to apply the damage.
-        if !delay.timer.is_finished() {
+        // On long frames, the delay can finish more than once, so we deal damage for each tick.
+        let ticks = delay.ticks_this_frame() as i32;
+        if ticks == 0 {
             continue;
         }
 
@@ -72,7 +75,7 @@ fn deal_poison_damage(
         };
 
         // Otherwise, deal the damage.
-        health.0 -= poison.damage;
+        health.0 -= poison.damage * ticks;
     }
 }
 
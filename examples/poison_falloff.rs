@@ -48,10 +48,10 @@ fn on_space_pressed(
         return;
     }
 
+    // The effect is applied when commands are flushed, so it won't be visible until the next system.
     commands.entity(*target).with_effect(EffectBundle {
-        mode: EffectMode::Merge, // Stack tracking requires effect merging.
+        mode: Some(EffectMode::Merge), // Stacks are tracked automatically when merging.
         bundle: (
-            EffectStacks::default(),     // Enable stack tracking.
             Lifetime::from_seconds(3.0), // The duration of the effect.
             Delay::from_seconds(1.0) // The time between damage ticks.
                 .trigger_immediately(), // Make damage tick immediately when the effect is applied.
@@ -68,7 +68,9 @@ fn deal_poison_damage(
 ) {
     for (target, stacks, delay, poison) in effects {
         // We wait until the delay finishes to apply the damage.
-        if !delay.timer.is_finished() {
+        // On long frames, the delay can finish more than once, so we deal damage for each tick.
+        let ticks = delay.ticks_this_frame() as i32;
+        if ticks == 0 {
             continue;
         }
 
@@ -81,7 +83,7 @@ fn deal_poison_damage(
         // Each subsequent stack has a decreasing effect, the first deals 5 damage, the next 4, then 3, and so on.
         let stacks = poison.damage.min(stacks.0 as i32); // Clamp stacks to prevent negative damage.
         let sub = (stacks * (stacks - 1)) / 2;
-        let damage = poison.damage * stacks - sub;
+        let damage = (poison.damage * stacks - sub) * ticks;
 
         info!("Dealt {damage} damage!");
 
@@ -0,0 +1,152 @@
+//! Derive macros for [`bevy_alchemy`](https://docs.rs/bevy_alchemy).
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, parse_macro_input};
+
+/// Derives `bevy_alchemy::Effect`, generating the merge logic from `#[effect(...)]` attributes.
+///
+/// - `#[effect(merge = "...")]` on the struct sets the strategy for every field,
+///   and on a field overwrites it for that field.
+///   The strategies are `replace` (default), `keep`, `sum`, `max` and `min`.
+/// - `#[effect(reflect)]` on the struct also registers the type for reflection.
+#[proc_macro_derive(Effect, attributes(effect))]
+pub fn derive_effect(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// How a field is merged.
+#[derive(Copy, Clone)]
+enum Merge {
+    Replace,
+    Keep,
+    Sum,
+    Max,
+    Min,
+}
+
+impl Merge {
+    fn parse(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "replace" => Ok(Merge::Replace),
+            "keep" => Ok(Merge::Keep),
+            "sum" => Ok(Merge::Sum),
+            "max" => Ok(Merge::Max),
+            "min" => Ok(Merge::Min),
+            other => Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "unknown merge strategy `{other}`, expected `replace`, `keep`, `sum`, `max` or `min`"
+                ),
+            )),
+        }
+    }
+}
+
+/// The options set using `#[effect(...)]` attributes.
+#[derive(Default)]
+struct Options {
+    merge: Option<Merge>,
+    reflect: bool,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute], allow_reflect: bool) -> syn::Result<Self> {
+        let mut options = Options::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("effect")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("merge") {
+                    options.merge = Some(Merge::parse(&meta.value()?.parse()?)?);
+                    Ok(())
+                } else if allow_reflect && meta.path.is_ident("reflect") {
+                    options.reflect = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown effect attribute"))
+                }
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`Effect` can only be derived for structs",
+        ));
+    };
+
+    let options = Options::parse(&input.attrs, true)?;
+    let default = options.merge.unwrap_or(Merge::Replace);
+
+    let mut merges = Vec::new();
+    let fields = match &data.fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    for (index, field) in fields.into_iter().enumerate() {
+        let merge = Options::parse(&field.attrs, false)?
+            .merge
+            .unwrap_or(default);
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(index);
+                quote!(#index)
+            }
+        };
+
+        merges.push(match merge {
+            Merge::Replace => quote!(),
+            Merge::Keep => quote! {
+                self.#member = ::core::clone::Clone::clone(&old.#member);
+            },
+            Merge::Sum => quote! {
+                self.#member += ::core::clone::Clone::clone(&old.#member);
+            },
+            Merge::Max => quote! {
+                if old.#member > self.#member {
+                    self.#member = ::core::clone::Clone::clone(&old.#member);
+                }
+            },
+            Merge::Min => quote! {
+                if old.#member < self.#member {
+                    self.#member = ::core::clone::Clone::clone(&old.#member);
+                }
+            },
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let register = options.reflect.then(|| {
+        quote! {
+            fn register(app: &mut ::bevy_alchemy::__macro_exports::App) {
+                ::bevy_alchemy::__macro_exports::register_reflect_effect::<Self>(app);
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::bevy_alchemy::Effect for #ident #type_generics #where_clause {
+            #[allow(unused_variables)]
+            fn merge(&mut self, old: &Self) {
+                #(#merges)*
+            }
+
+            #register
+        }
+    })
+}
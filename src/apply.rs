@@ -0,0 +1,57 @@
+use crate::{AlchemySet, EffectApplyFn, EffectApplyRegistry, Effecting};
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+use std::any::TypeId;
+
+pub(crate) struct ApplyPlugin;
+
+impl Plugin for ApplyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EffectApplyRegistry>().add_systems(
+            Update,
+            run_effect_apply_fns.in_set(AlchemySet::ApplyEffects),
+        );
+    }
+}
+
+/// Excludes [inactive](crate::EffectInactive) effects.
+#[cfg(feature = "timers")]
+type Active = Without<crate::EffectInactive>;
+#[cfg(not(feature = "timers"))]
+type Active = ();
+
+/// Runs the registered [`EffectApplyFn`]s for every active effect.
+fn run_effect_apply_fns(world: &mut World, effects: &mut QueryState<(Entity, &Effecting), Active>) {
+    let applies: Vec<(TypeId, EffectApplyFn)> = world
+        .resource::<EffectApplyRegistry>()
+        .applies
+        .iter()
+        .map(|(id, f)| (*id, *f))
+        .collect();
+
+    if applies.is_empty() {
+        return;
+    }
+
+    let effects: Vec<(Entity, Entity)> = effects
+        .iter(world)
+        .map(|(effect, target)| (effect, target.0))
+        .collect();
+
+    for (effect, target) in effects {
+        for (id, apply) in &applies {
+            if !world
+                .get_entity(effect)
+                .is_ok_and(|effect| effect.contains_type_id(*id))
+            {
+                continue;
+            }
+
+            let Ok(target) = world.get_entity_mut(target) else {
+                continue;
+            };
+
+            apply(target, effect);
+        }
+    }
+}
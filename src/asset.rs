@@ -0,0 +1,312 @@
+use crate::command::{BindEffectCommand, insert_effect};
+use crate::registry::resolve_mode;
+use crate::{Delay, EffectBundle, EffectMode, EffectTimer, Lifetime};
+use bevy_app::{App, Plugin};
+use bevy_asset::io::Reader;
+use bevy_asset::{Asset, AssetApp, AssetLoader, Assets, Handle, LoadContext};
+use bevy_ecs::entity_disabling::Disabled;
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy_log::warn;
+use bevy_reflect::serde::TypedReflectDeserializer;
+use bevy_reflect::{PartialReflect, TypePath, TypeRegistry, TypeRegistryArc};
+use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::Formatter;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Adds the [`EffectAsset`] type and its loader, for defining effects in RON files.
+/// This requires the `AssetPlugin`.
+///
+/// This is not included in the [`AlchemyPlugin`](crate::AlchemyPlugin), and requires the `assets` feature.
+pub struct EffectAssetPlugin;
+
+impl Plugin for EffectAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<EffectAsset>()
+            .init_asset_loader::<EffectAssetLoader>();
+    }
+}
+
+/// A data-driven description of an effect, which allows effects to be authored without recompiling.
+/// These are normally loaded from `.effect.ron` files, and applied using
+/// [`with_effect_asset`](crate::EffectCommandsExt::with_effect_asset).
+///
+/// Components are deserialized using reflection, so they must be registered in the `AppTypeRegistry`
+/// and [reflect `Component`](ReflectComponent).
+///
+/// # Example
+/// ```ron
+/// (
+///     name: "Poison",
+///     mode: Merge,
+///     lifetime: Some(5.0),
+///     delay: Some(1.0),
+///     components: {
+///         "my_game::Poison": (damage: 1.0),
+///     },
+/// )
+/// ```
+#[derive(Asset, TypePath)]
+pub struct EffectAsset {
+    /// The name of the effect, which is used to find existing effects.
+    pub name: Name,
+    /// Describes the logic used when the effect is already applied to the target.
+    /// If `None`, the mode is resolved the same as [`EffectBundle::mode`].
+    pub mode: Option<EffectMode>,
+    /// If set, the effect is given a [`Lifetime`] with this duration.
+    pub lifetime: Option<Duration>,
+    /// If set, the effect is given a [`Delay`] with this duration.
+    pub delay: Option<Duration>,
+    /// The reflected components of the effect.
+    pub components: Vec<Box<dyn PartialReflect>>,
+}
+
+impl EffectAsset {
+    /// Deserializes an effect from a RON string, using the `registry` to look up its components.
+    pub fn from_ron(ron: &str, registry: &TypeRegistry) -> Result<Self, ron::error::SpannedError> {
+        let mut deserializer = ron::de::Deserializer::from_str(ron)?;
+        EffectAssetDeserializer { registry }
+            .deserialize(&mut deserializer)
+            .map_err(|e| deserializer.span_error(e))
+    }
+}
+
+/// Deserializes an [`EffectAsset`], using a [`TypeRegistry`] to look up its components.
+pub struct EffectAssetDeserializer<'a> {
+    /// The registry used to deserialize components.
+    pub registry: &'a TypeRegistry,
+}
+
+const FIELDS: &[&str] = &["name", "mode", "lifetime", "delay", "components"];
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Name,
+    Mode,
+    Lifetime,
+    Delay,
+    Components,
+}
+
+impl<'de> DeserializeSeed<'de> for EffectAssetDeserializer<'_> {
+    type Value = EffectAsset;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_struct("EffectAsset", FIELDS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for EffectAssetDeserializer<'_> {
+    type Value = EffectAsset;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("an effect")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut name = None;
+        let mut mode = None;
+        let mut lifetime = None;
+        let mut delay = None;
+        let mut components = None;
+
+        while let Some(field) = map.next_key::<Field>()? {
+            match field {
+                Field::Name => name = Some(map.next_value::<String>()?),
+                Field::Mode => mode = Some(map.next_value::<EffectMode>()?),
+                Field::Lifetime => lifetime = map.next_value::<Option<f32>>()?,
+                Field::Delay => delay = map.next_value::<Option<f32>>()?,
+                Field::Components => {
+                    components = Some(map.next_value_seed(ComponentsDeserializer {
+                        registry: self.registry,
+                    })?)
+                }
+            }
+        }
+
+        let seconds = |seconds: Option<f32>| {
+            seconds
+                .map(Duration::try_from_secs_f32)
+                .transpose()
+                .map_err(Error::custom)
+        };
+
+        Ok(EffectAsset {
+            name: Name::new(name.ok_or_else(|| Error::missing_field("name"))?),
+            mode,
+            lifetime: seconds(lifetime)?,
+            delay: seconds(delay)?,
+            components: components.unwrap_or_default(),
+        })
+    }
+}
+
+/// Deserializes a map of type paths to reflected components.
+struct ComponentsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ComponentsDeserializer<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ComponentsDeserializer<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of components")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut components = Vec::new();
+
+        while let Some(path) = map.next_key::<String>()? {
+            let registration = self
+                .registry
+                .get_with_type_path(&path)
+                .ok_or_else(|| Error::custom(format!("no registration found for `{path}`")))?;
+
+            components.push(
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?,
+            );
+        }
+
+        Ok(components)
+    }
+}
+
+/// Loads [`EffectAsset`]s from `.effect.ron` files.
+#[derive(TypePath)]
+pub struct EffectAssetLoader {
+    registry: TypeRegistryArc,
+}
+
+impl FromWorld for EffectAssetLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            registry: world.resource::<AppTypeRegistry>().0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by the [`EffectAssetLoader`].
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum EffectAssetLoaderError {
+    /// An [IO Error](std::io::Error).
+    #[error("Error while trying to read the effect file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON Error](ron::error::SpannedError).
+    #[error("Could not parse RON: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for EffectAssetLoader {
+    type Asset = EffectAsset;
+    type Settings = ();
+    type Error = EffectAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+
+        Ok(EffectAssetDeserializer {
+            registry: &self.registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|e| deserializer.span_error(e))?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["effect.ron"]
+    }
+}
+
+/// Applies an [`EffectAsset`] to a target entity.
+/// The effect is spawned and then [bound](BindEffectCommand), so it collides with existing effects in the same way.
+///
+/// If the asset hasn't finished loading, a warning is logged and the effect isn't applied.
+///
+/// This is normally used via [`with_effect_asset`](crate::EffectCommandsExt::with_effect_asset).
+pub struct AddEffectAssetCommand {
+    /// The entity to apply the effect to.
+    pub target: Entity,
+    /// The effect to apply.
+    pub handle: Handle<EffectAsset>,
+}
+
+impl Command for AddEffectAssetCommand {
+    fn apply(self, world: &mut World) {
+        let Some(asset) = world
+            .get_resource::<Assets<EffectAsset>>()
+            .and_then(|assets| assets.get(&self.handle))
+        else {
+            warn!("Effect asset {:?} isn't loaded.", self.handle);
+            return;
+        };
+
+        let mut bundle = EffectBundle {
+            name: asset.name.clone(),
+            mode: asset.mode,
+            ..EffectBundle::new(())
+        };
+        let lifetime = asset.lifetime.map(Lifetime::new);
+        let delay = asset.delay.map(Delay::new);
+        let components: Vec<Box<dyn PartialReflect>> = asset
+            .components
+            .iter()
+            .map(|component| component.to_dynamic())
+            .collect();
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        resolve_mode(world, &mut bundle);
+        let effect = world.spawn(Disabled).id();
+        insert_effect(world.entity_mut(effect), bundle, ());
+        let mut entity = world.entity_mut(effect);
+
+        if let Some(lifetime) = lifetime {
+            entity.insert(lifetime);
+        }
+
+        if let Some(delay) = delay {
+            entity.insert(delay);
+        }
+
+        for component in components {
+            let reflect_component = component
+                .get_represented_type_info()
+                .and_then(|info| registry.get_type_data::<ReflectComponent>(info.type_id()));
+
+            match reflect_component {
+                Some(reflect_component) => {
+                    reflect_component.insert(&mut entity, component.as_ref(), &registry)
+                }
+                None => warn!(
+                    "Effect component `{}` doesn't reflect `Component`.",
+                    component.reflect_type_path()
+                ),
+            }
+        }
+
+        BindEffectCommand {
+            target: self.target,
+            effect,
+        }
+        .apply(world);
+    }
+}
@@ -1,5 +1,9 @@
-use crate::EffectMode;
+use crate::{
+    EffectCategory, EffectId, EffectMode, EffectPolarity, EffectPriority, EffectResolver,
+    EffectTags,
+};
 use bevy_ecs::prelude::*;
+use disqualified::ShortName;
 
 /// A "bundle" of components/settings used when applying an effect.
 /// Due to technical limitations, this doesn't actually implement [`Bundle`].
@@ -13,12 +17,78 @@ use bevy_ecs::prelude::*;
 #[doc = include_str!("../docs/with_effects_example.md")]
 /// ### [`EffectedBy::spawn`](SpawnRelated::spawn)
 #[doc = include_str!("../docs/effected_by_spawn_example.md")]
-#[derive(Default)]
+#[derive(Clone)]
 pub struct EffectBundle<B: Bundle> {
     /// The name/ID of the effect. Effects with different IDs have no effect on one another.
+    ///
+    /// Defaults to the name of the bundle's type (such as `Poison`), so that different kinds of effects don't collide.
+    /// Effects that should be matched together despite having different bundles can be given the same name explicitly.
+    /// If the name is empty, effects are only matched with existing effects that have all of the bundle's components
+    /// (including effects that have extra components).
     pub name: Name,
+    /// An optional typed ID of the effect. If set, effects are matched using this instead of their [name](Self::name).
+    pub id: Option<EffectId>,
+    /// An optional category of the effect. If set, effects are matched using this instead of their [ID](Self::id) or [name](Self::name),
+    /// which allows differently named effects to interact.
+    pub category: Option<EffectCategory>,
     /// Describes the logic used when new effect collides with an existing one.
-    pub mode: EffectMode,
+    ///
+    /// Defaults to `None`, in which case the mode registered for the effect's name in the [`EffectModeRegistry`](crate::EffectModeRegistry)
+    /// is used, or [`EffectMode::Stack`] if there isn't one.
+    pub mode: Option<EffectMode>,
+    /// Decides which effect wins when the effect conflicts with an existing one.
+    pub priority: EffectPriority,
+    /// An optional custom collision policy, which overrides the [mode's](Self::mode) built-in one.
+    pub resolver: Option<EffectResolver>,
+    /// The initial number of [`EffectStacks`](crate::EffectStacks), such as for an attack that applies 3 stacks of poison at once.
+    /// When [merged](EffectMode::Merge), these are added to the existing stacks (depending on the [`StackMergeMode`](crate::StackMergeMode)).
+    ///
+    /// Defaults to `None`, in which case merge-mode effects start with a single stack.
+    /// Unlike including `EffectStacks` in the [bundle](Self::bundle), this doesn't change the effect's default [name](Self::name).
+    #[cfg(feature = "stacks")]
+    pub stacks: Option<crate::EffectStacks>,
+    /// Categories that the effect belongs to, which can be used to [filter](crate::EffectFilter) effects.
+    pub tags: EffectTags,
+    /// Whether the effect is a buff or debuff, which can be used to [filter](crate::EffectFilter) effects.
+    pub polarity: EffectPolarity,
     /// Components that will be added to the effect. This is where the actual effect components get added.
     pub bundle: B,
 }
+
+impl<B: Bundle> EffectBundle<B> {
+    /// Creates a new effect from a bundle, with the rest of the settings left as their defaults.
+    /// The effect is named after the bundle's type.
+    pub fn new(bundle: B) -> Self {
+        Self {
+            name: type_name::<B>(),
+            id: None,
+            category: None,
+            mode: None,
+            priority: EffectPriority::default(),
+            resolver: None,
+            #[cfg(feature = "stacks")]
+            stacks: None,
+            tags: EffectTags::default(),
+            polarity: EffectPolarity::default(),
+            bundle,
+        }
+    }
+}
+
+impl<B: Bundle> EffectBundle<B> {
+    /// Returns the mode of the effect, which only includes the registered mode once it has been resolved using `resolve_mode`.
+    pub(crate) fn resolved_mode(&self) -> EffectMode {
+        self.mode.unwrap_or_default()
+    }
+}
+
+impl<B: Bundle + Default> Default for EffectBundle<B> {
+    fn default() -> Self {
+        Self::new(B::default())
+    }
+}
+
+/// Returns the default name of an effect with the bundle `B`.
+pub(crate) fn type_name<B: Bundle>() -> Name {
+    Name::new(ShortName::of::<B>().to_string())
+}
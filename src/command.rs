@@ -1,12 +1,27 @@
 use crate::bundle::EffectBundle;
-use crate::registry::{EffectMergeFn, EffectMergeRegistry};
-use crate::{EffectMode, EffectedBy, Effecting};
+use crate::component::next_effect_order;
+use crate::registry::{EffectMergeRegistry, EffectReplaceFn, KeepEffect, MergeEntry, resolve_mode};
+use crate::relation::stack_count;
+use crate::resolver::EffectResolver;
+use crate::{
+    AlchemySettings, EffectCategory, EffectFilter, EffectId, EffectImmunity, EffectJustApplied,
+    EffectLimitPolicy, EffectMode, EffectOrder, EffectPolarity, EffectPriority, EffectSources,
+    EffectTags, EffectedBy, Effecting, MaxEffects, StackLimitPolicy, StackLimits,
+};
+use bevy_ecs::bundle::NoBundleEffect;
+use bevy_ecs::component::{ComponentId, Mutable};
 use bevy_ecs::entity_disabling::Disabled;
 use bevy_ecs::prelude::*;
 use bevy_ecs::ptr::MovingPtr;
 use bevy_ecs::spawn::SpawnableList;
 use bevy_log::warn_once;
+use bevy_reflect::Reflect;
 use std::any::TypeId;
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::marker::PhantomData;
+#[cfg(feature = "timers")]
+use std::time::Duration;
 
 /// Applies an effect to a target entity.
 /// This *might* spawn a new entity, depending on what effects are already applied to the target.
@@ -18,132 +33,1220 @@ pub struct AddEffectCommand<B: Bundle> {
     pub target: Entity,
     /// The effect to apply.
     pub bundle: EffectBundle<B>,
+    /// An empty entity that was reserved for the effect, such as by [`EffectSpawner::spawn`].
+    /// If the effect spawns a new entity, this is used instead. Otherwise, it is despawned.
+    pub entity: Option<Entity>,
+}
+
+/// Makes room for a new effect on the target, based on its [`MaxEffects`].
+/// Returns false if the new effect shouldn't be applied.
+fn make_room_for_effect(world: &mut World, target: Entity, priority: EffectPriority) -> bool {
+    let Some(evicted) = effects_to_evict(world, target, priority) else {
+        return false;
+    };
+
+    for effect in evicted {
+        world.despawn(effect);
+    }
+    true
+}
+
+/// Returns the effects that need to be evicted to make room for a new effect on the target, based on its [`MaxEffects`].
+/// Returns `None` if the new effect shouldn't be applied.
+pub(crate) fn effects_to_evict(
+    world: &World,
+    target: Entity,
+    priority: EffectPriority,
+) -> Option<Vec<Entity>> {
+    let Some(limit) = world.get::<MaxEffects>(target).copied() else {
+        return Some(Vec::new());
+    };
+
+    let mut effects = world
+        .get::<EffectedBy>(target)
+        .map(|e| e.collection().clone())
+        .unwrap_or_default();
+
+    if effects.len() < limit.max as usize {
+        return Some(Vec::new());
+    }
+
+    if limit.max == 0 {
+        return None;
+    }
+
+    let excess = effects.len() + 1 - limit.max as usize;
+    sort_oldest_first(world, &mut effects);
+
+    match limit.policy {
+        EffectLimitPolicy::Reject => None,
+        EffectLimitPolicy::EvictOldest => Some(effects.into_iter().take(excess).collect()),
+        EffectLimitPolicy::EvictLowestPriority => {
+            // A stable sort, so the oldest effect is evicted first when priorities tie.
+            let mut effects: Vec<(EffectPriority, Entity)> = effects
+                .into_iter()
+                .map(|effect| {
+                    let priority = world.get::<EffectPriority>(effect).copied();
+                    (priority.unwrap_or_default(), effect)
+                })
+                .collect();
+            effects.sort_by_key(|(priority, _)| *priority);
+
+            let evicted = &effects[..excess];
+            if evicted.iter().any(|(other, _)| *other > priority) {
+                return None;
+            }
+
+            Some(evicted.iter().map(|(_, effect)| *effect).collect())
+        }
+    }
+}
+
+/// Sorts effects by their [`EffectOrder`], so that reapplied effects count as the most recently applied.
+/// Effects without an order are kept first, in the order they were applied.
+fn sort_oldest_first(world: &World, effects: &mut [Entity]) {
+    effects.sort_by_key(|effect| world.get::<EffectOrder>(*effect).copied());
+}
+
+/// Returns the target's [`StackLimits`] limit and policy for an effect, if it has one.
+pub(crate) fn stack_limit(
+    world: &World,
+    target: Entity,
+    name: &Name,
+) -> Option<(u8, StackLimitPolicy)> {
+    let limits = world.get::<StackLimits>(target)?;
+    Some((limits.get(name)?, limits.policy))
+}
+
+/// Makes room for a new [`Stack`](EffectMode::Stack) effect on the target, based on its [`StackLimits`].
+/// Returns false if the new effect shouldn't be applied.
+fn make_room_for_stack(world: &mut World, target: Entity, name: &Name) -> bool {
+    let Some(evicted) = stacks_to_evict(world, target, name) else {
+        return false;
+    };
+
+    for effect in evicted {
+        world.despawn(effect);
+    }
+    true
+}
+
+/// Returns the stacks that need to be evicted to make room for a new [`Stack`](EffectMode::Stack) effect on the target,
+/// based on its [`StackLimits`]. Returns `None` if the new effect shouldn't be applied.
+pub(crate) fn stacks_to_evict(world: &World, target: Entity, name: &Name) -> Option<Vec<Entity>> {
+    let Some((max, policy)) = stack_limit(world, target, name) else {
+        return Some(Vec::new());
+    };
+
+    let stacks: Vec<Entity> = world
+        .get::<EffectedBy>(target)
+        .map(|effected_by| {
+            effected_by
+                .iter()
+                .filter(|effect| world.get::<Name>(*effect) == Some(name))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if stacks.len() < max as usize {
+        return Some(Vec::new());
+    }
+
+    if max == 0 {
+        return None;
+    }
+
+    match policy {
+        StackLimitPolicy::Reject => None,
+        StackLimitPolicy::Cap => {
+            let excess = stacks.len() + 1 - max as usize;
+            Some(stacks.into_iter().take(excess).collect())
+        }
+    }
+}
+
+/// Clamps the stacks of a merged effect to `max`.
+#[cfg(feature = "stacks")]
+pub(crate) fn cap_stacks(world: &mut World, effect: Entity, max: u8) {
+    let mut entity = world.entity_mut(effect);
+
+    if let Some(mut stacks) = entity.get_mut::<crate::EffectStacks>()
+        && stacks.0 > max
+    {
+        stacks.0 = max;
+    }
+
+    if let Some(mut stacks) = entity.get_mut::<crate::EffectStacksU32>()
+        && stacks.0 > max as u32
+    {
+        stacks.0 = max as u32;
+    }
+}
+
+/// Returns the [`EffectStacks`](crate::EffectStacks) that should be added to an effect,
+/// either from [`EffectBundle::stacks`] or automatically for merge-mode effects.
+#[cfg(feature = "stacks")]
+pub(crate) fn initial_stacks<B: Bundle>(
+    world: &World,
+    bundle: &EffectBundle<B>,
+) -> Option<crate::EffectStacks> {
+    bundle.stacks.or_else(|| {
+        let tracked = bundle.resolved_mode() == EffectMode::Merge
+            && world
+                .get_resource::<AlchemySettings>()
+                .is_none_or(|settings| settings.track_merge_stacks);
+
+        tracked.then(crate::EffectStacks::default)
+    })
+}
+
+/// Inserts the components of an effect, alongside its `relation` (normally [`Effecting`]).
+pub(crate) fn insert_effect<B: Bundle>(
+    mut entity: EntityWorldMut,
+    bundle: EffectBundle<B>,
+    relation: impl Bundle,
+) {
+    let mode = bundle.resolved_mode();
+
+    // Inserted before the bundle, so that a manually added `EffectStacks` isn't overwritten.
+    #[cfg(feature = "stacks")]
+    if let Some(stacks) = initial_stacks(entity.world(), &bundle) {
+        entity.insert(stacks);
+    }
+
+    if let Some(id) = bundle.id {
+        entity.insert(id);
+    }
+
+    if let Some(category) = bundle.category {
+        entity.insert(category);
+    }
+
+    if let Some(resolver) = bundle.resolver {
+        entity.insert(resolver);
+    }
+
+    entity.insert((
+        relation,
+        bundle.name,
+        mode,
+        bundle.priority,
+        bundle.tags,
+        bundle.polarity,
+        bundle.bundle,
+    ));
+}
+
+/// Finds the effect on the target that a new effect collides with.
+/// This is an effect with the same mode and the same category, ID or name (in that order of precedence).
+///
+/// Effects with different modes never collide, even if they share a name.
+/// This is almost always a mistake, so a warning is logged in debug builds.
+///
+/// If the name is empty, the existing effect must also have all the `components` of the new effect's bundle,
+/// so that unnamed effects don't collide with unrelated ones. The existing effect can have extra components,
+/// so an unnamed effect can still collide with a larger effect that shares all of its components.
+pub(crate) fn find_existing_effect(
+    world: &World,
+    target: Entity,
+    mode: EffectMode,
+    category: Option<&EffectCategory>,
+    id: Option<&EffectId>,
+    name: &Name,
+    components: &[ComponentId],
+) -> Option<Entity> {
+    let effected_by = world.get::<EffectedBy>(target)?;
+
+    effected_by.iter().find(|entity| {
+        let same = match (category, id) {
+            (Some(category), _) => world.get::<EffectCategory>(*entity) == Some(category),
+            (None, Some(id)) => world.get::<EffectId>(*entity) == Some(id),
+            (None, None) if name.is_empty() => {
+                world.get::<Name>(*entity) == Some(name)
+                    && components
+                        .iter()
+                        .all(|component| world.entity(*entity).contains_id(*component))
+            }
+            (None, None) => world.get::<Name>(*entity) == Some(name),
+        };
+
+        if !same {
+            return false;
+        }
+
+        let Some(other_mode) = world.get::<EffectMode>(*entity) else {
+            return false;
+        };
+
+        if *other_mode != mode {
+            #[cfg(debug_assertions)]
+            warn_once!(
+                "Effect `{name}` was applied with `EffectMode::{mode:?}`, but an effect with the same name uses `EffectMode::{other_mode:?}`. \
+                Effects with different modes are never matched, so both will be kept."
+            );
+            return false;
+        }
+
+        true
+    })
+}
+
+/// Returns the registered merge functions for the `existing` effect's components that are also `incoming`.
+/// If there is no [`EffectMergeRegistry`], this warns (or panics in [strict](AlchemySettings::strict) mode) and returns `None`.
+pub(crate) fn merge_entries(
+    world: &World,
+    name: &Name,
+    incoming: &[ComponentId],
+    existing: Entity,
+) -> Option<Vec<(TypeId, MergeEntry)>> {
+    let Some(registry) = world.get_resource::<EffectMergeRegistry>() else {
+        crate::validate::warn_or_panic!(
+            world,
+            "No `EffectMergeRegistry` found, so effect `{name}` was inserted instead of merged. Did you forget to add the `AlchemyPlugin`?"
+        );
+        return None;
+    };
+
+    let entries = world
+        .entity(existing)
+        .archetype()
+        .components()
+        .iter()
+        .filter(|component_id| incoming.contains(component_id))
+        .filter_map(|component_id| {
+            let id = world.components().get_info(*component_id)?.type_id()?;
+            registry.merges.get(&id).map(|entry| (id, *entry))
+        })
+        .collect();
+
+    Some(entries)
+}
+
+/// Merges the `outgoing` effect's components into the `new` effect, using the given merge functions.
+pub(crate) fn merge_components(
+    world: &mut World,
+    entries: &[MergeEntry],
+    new: Entity,
+    outgoing: Entity,
+) {
+    for entry in entries {
+        (entry.merge)(world.entity_mut(new), outgoing);
+    }
+}
+
+/// Returns the components in the bundle `B`, which are used to match effects with an empty name.
+/// Named effects are matched by name alone, so this is empty for them.
+fn bundle_components<B: Bundle>(world: &mut World, name: &Name) -> Vec<ComponentId> {
+    if !name.is_empty() {
+        return Vec::new();
+    }
+
+    B::component_ids(&mut world.components_registrator()).collect()
+}
+
+/// Returns true if the existing effect has a higher priority than the incoming one.
+/// Only [`Insert`](EffectMode::Insert) and [`Select`](EffectMode::Select) effects respect priority.
+pub(crate) fn outranked(
+    world: &World,
+    existing: Entity,
+    mode: EffectMode,
+    priority: EffectPriority,
+) -> bool {
+    matches!(mode, EffectMode::Insert | EffectMode::Select)
+        && world
+            .get::<EffectPriority>(existing)
+            .is_some_and(|old| *old > priority)
+}
+
+/// Finds the first registered [replacement function](crate::EffectReplaceFn)
+/// for one of the `incoming` components that the existing effect also has.
+pub(crate) fn find_replacement(
+    world: &World,
+    incoming: &[ComponentId],
+    existing: Entity,
+) -> Option<EffectReplaceFn> {
+    let registry = world.get_resource::<EffectMergeRegistry>()?;
+
+    incoming.iter().find_map(|component_id| {
+        if !world.entity(existing).contains_id(*component_id) {
+            return None;
+        }
+
+        let id = world.components().get_info(*component_id)?.type_id()?;
+        registry.replacements.get(&id).copied()
+    })
 }
 
 impl<B: Bundle> AddEffectCommand<B> {
+    /// Spawns the effect, if there is room for it on the target.
+    fn spawn_limited(self, world: &mut World) {
+        if make_room_for_effect(world, self.target, self.bundle.priority) {
+            self.spawn(world);
+        }
+    }
+
     fn spawn(self, world: &mut World) -> Entity {
-        let entity = world.spawn_empty();
+        let order = next_effect_order(world);
+        let entity = match self.entity.and_then(|e| world.get_entity_mut(e).ok()) {
+            Some(mut entity) => {
+                entity.insert((EffectJustApplied, order));
+                entity
+            }
+            None => world.spawn((EffectJustApplied, order)),
+        };
+
         let id = entity.id();
         self.insert(entity);
         id
     }
 
-    fn insert(self, mut entity: EntityWorldMut) {
-        entity.insert((
-            Effecting(self.target),
-            self.bundle.name,
-            self.bundle.mode,
-            self.bundle.bundle,
-        ));
+    fn insert(self, entity: EntityWorldMut) {
+        insert_effect(entity, self.bundle, Effecting(self.target));
+    }
+
+    /// Inserts into the existing entity, and then merges the old effect into it using [`EffectMergeRegistry`].
+    /// Only registered components that are part of the incoming bundle will be merged.
+    /// ## Steps
+    /// 1. Copy registered components to a new temporary, disabled entity.
+    /// 2. Insert new components into the existing entity.
+    /// 3. Merge the old components (temp entity) with the new ones (existing entity).
+    /// 4. Despawn temp entity.
+    ///
+    /// If the existing entity has no registered components (or there is no registry), this is the same as [`insert`](Self::insert).
+    /// If it only has one, that component is copied directly, rather than using an entity cloner.
+    fn merge(self, world: &mut World, existing_entity: Entity) {
+        // Find the existing components that need merging.
+        // Components missing from the incoming bundle won't be overwritten, so they don't need merging.
+        #[cfg_attr(not(feature = "stacks"), allow(unused_mut))]
+        let mut incoming = world.register_bundle::<B>().explicit_components().to_vec();
+
+        // Stacks from `EffectBundle::stacks` (or tracked automatically) aren't part of the bundle, but are still inserted.
+        #[cfg(feature = "stacks")]
+        if initial_stacks(world, &self.bundle).is_some() {
+            incoming.push(world.register_component::<crate::EffectStacks>());
+        }
+
+        let Some(entries) = merge_entries(world, &self.bundle.name, &incoming, existing_entity)
+        else {
+            self.insert(world.entity_mut(existing_entity));
+            return;
+        };
+        let (allow, entries): (Vec<TypeId>, Vec<MergeEntry>) = entries.into_iter().unzip();
+
+        let new_effect = existing_entity;
+
+        // Fast path: nothing to merge.
+        if entries.is_empty() {
+            self.insert(world.entity_mut(new_effect));
+            return;
+        }
+
+        // Copy existing mergeable components to a temporary entity.
+        let old_effect = world.spawn(Disabled).id();
+
+        if let [entry] = entries.as_slice() {
+            // Fast path: a typed copy is much cheaper than setting up an entity cloner.
+            (entry.copy)(world, existing_entity, old_effect);
+        } else {
+            // Reflect-only components can't be copied by the cloner, so they are copied separately.
+            let allow: Vec<TypeId> = allow
+                .into_iter()
+                .zip(&entries)
+                .filter(|(_, entry)| !entry.reflect)
+                .map(|(id, _)| id)
+                .collect();
+
+            if !allow.is_empty() {
+                world
+                    .entity_mut(existing_entity)
+                    .clone_with_opt_in(old_effect, |builder| {
+                        builder.without_required_components(|builder| {
+                            builder.allow_by_ids(allow);
+                        });
+                    });
+            }
+
+            for entry in entries.iter().filter(|entry| entry.reflect) {
+                (entry.copy)(world, existing_entity, old_effect);
+            }
+        }
+
+        self.insert(world.entity_mut(new_effect));
+
+        // Call merge function on those copied components.
+        merge_components(world, &entries, new_effect, old_effect);
+
+        world.despawn(old_effect);
+    }
+
+    /// Spawns the new effect, and then despawns either it or the existing effect using [`EffectMergeRegistry`].
+    /// The first registered [replacement function](crate::EffectReplaceFn) that both effects have a component for decides.
+    /// Returns the surviving entity.
+    fn select(self, world: &mut World, existing_entity: Entity) -> Entity {
+        #[cfg_attr(not(feature = "stacks"), allow(unused_mut))]
+        let mut incoming = world.register_bundle::<B>().explicit_components().to_vec();
+
+        // Stacks from `EffectBundle::stacks` (or tracked automatically) aren't part of the bundle, but are still inserted.
+        #[cfg(feature = "stacks")]
+        if initial_stacks(world, &self.bundle).is_some() {
+            incoming.push(world.register_component::<crate::EffectStacks>());
+        }
+        let new_effect = self.spawn(world);
+
+        let replace = find_replacement(world, &incoming, existing_entity);
+
+        let keep = replace.map_or(KeepEffect::Incoming, |replace| {
+            replace(world, existing_entity, new_effect)
+        });
+
+        match keep {
+            KeepEffect::Old => {
+                world.despawn(new_effect);
+                existing_entity
+            }
+            KeepEffect::Incoming => {
+                world.despawn(existing_entity);
+                new_effect
+            }
+        }
+    }
+}
+
+impl<B: Bundle> Command for AddEffectCommand<B> {
+    fn apply(mut self, world: &mut World) {
+        resolve_mode(world, &mut self.bundle);
+        let reserved = self.entity;
+
+        self.apply_effect(world);
+
+        // The reserved entity wasn't used, because the effect was merged into an existing one or wasn't applied.
+        if let Some(reserved) = reserved
+            && world
+                .get_entity(reserved)
+                .is_ok_and(|entity| !entity.contains::<Effecting>())
+        {
+            world.despawn(reserved);
+        }
+    }
+}
+
+impl<B: Bundle> AddEffectCommand<B> {
+    fn apply_effect(self, world: &mut World) {
+        crate::validate::validate_effect(world, &self.bundle);
+
+        if let Some(immunity) = world.get::<EffectImmunity>(self.target)
+            && immunity.blocks(&self.bundle.name, &self.bundle.tags, self.bundle.polarity)
+        {
+            return;
+        }
+
+        // Custom resolvers work on spawned entities, so the effect is spawned unbound and then bound.
+        if self.bundle.resolver.is_some() {
+            let effect = match self.entity.and_then(|e| world.get_entity_mut(e).ok()) {
+                Some(mut entity) => entity.insert(Disabled).id(),
+                None => world.spawn(Disabled).id(),
+            };
+
+            insert_effect(world.entity_mut(effect), self.bundle, ());
+            BindEffectCommand {
+                target: self.target,
+                effect,
+            }
+            .apply(world);
+            return;
+        }
+
+        if self.bundle.resolved_mode() == EffectMode::Stack {
+            if make_room_for_stack(world, self.target, &self.bundle.name) {
+                self.spawn_limited(world);
+            }
+            return;
+        }
+
+        let components = bundle_components::<B>(world, &self.bundle.name);
+
+        let Some(old_entity) = find_existing_effect(
+            world,
+            self.target,
+            self.bundle.resolved_mode(),
+            self.bundle.category.as_ref(),
+            self.bundle.id.as_ref(),
+            &self.bundle.name,
+            &components,
+        ) else {
+            self.spawn_limited(world);
+            return;
+        };
+
+        // Lower priority effects can't overwrite higher priority ones.
+        if outranked(
+            world,
+            old_entity,
+            self.bundle.resolved_mode(),
+            self.bundle.priority,
+        ) {
+            return;
+        }
+
+        #[cfg(feature = "stacks")]
+        let stack_limit = stack_limit(world, self.target, &self.bundle.name);
+
+        // Merging would increase the stacks past the limit.
+        #[cfg(feature = "stacks")]
+        if self.bundle.resolved_mode() == EffectMode::Merge
+            && let Some((max, StackLimitPolicy::Reject)) = stack_limit
+            && stack_count(world, old_entity) >= max as u32
+        {
+            return;
+        }
+
+        match self.bundle.resolved_mode() {
+            EffectMode::Stack => unreachable!(),
+            EffectMode::Insert => self.insert(world.entity_mut(old_entity)),
+            EffectMode::Merge => {
+                self.merge(world, old_entity);
+
+                #[cfg(feature = "stacks")]
+                if let Some((max, _)) = stack_limit {
+                    cap_stacks(world, old_entity, max);
+                }
+            }
+            EffectMode::Select => {
+                self.select(world, old_entity);
+                return;
+            }
+        }
+
+        let mark_reapplied = world
+            .get_resource::<AlchemySettings>()
+            .is_none_or(|settings| settings.mark_reapplied);
+
+        let order = next_effect_order(world);
+        if let Ok(mut entity) = world.get_entity_mut(old_entity) {
+            entity.insert(order);
+
+            if mark_reapplied {
+                entity.insert(EffectJustApplied);
+            }
+        }
+    }
+}
+
+/// Applies the same effect to many target entities, such as for area-of-effect abilities.
+///
+/// This is normally used via [`apply_effect_to`](AlchemyCommandsExt::apply_effect_to).
+pub struct AddEffectToTargetsCommand<B: Bundle + Clone> {
+    /// The entities to apply the effect to.
+    pub targets: Vec<Entity>,
+    /// The effect to apply, which is cloned for each target.
+    pub bundle: EffectBundle<B>,
+}
+
+impl<B: Bundle + Clone> Command for AddEffectToTargetsCommand<B> {
+    fn apply(self, world: &mut World) {
+        let Some((last, rest)) = self.targets.split_last() else {
+            return;
+        };
+
+        for target in rest {
+            AddEffectCommand {
+                target: *target,
+                bundle: self.bundle.clone(),
+                entity: None,
+            }
+            .apply(world);
+        }
+
+        AddEffectCommand {
+            target: *last,
+            bundle: self.bundle,
+            entity: None,
+        }
+        .apply(world);
+    }
+}
+
+/// Applies many effects to a target entity at once.
+/// [Stack-mode](EffectMode::Stack) effects are spawned together as a single batch,
+/// which is much faster than queuing an [`AddEffectCommand`] for each one.
+///
+/// Effects using other modes are applied one at a time, same as [`AddEffectCommand`].
+///
+/// This is normally used via [`with_stacked_effects`](EffectCommandsExt::with_stacked_effects).
+pub struct AddStackedEffectsCommand<B: Bundle> {
+    /// The entity to apply the effects to.
+    pub target: Entity,
+    /// The effects to apply.
+    pub bundles: Vec<EffectBundle<B>>,
+}
+
+impl<B: Bundle<Effect: NoBundleEffect>> Command for AddStackedEffectsCommand<B> {
+    fn apply(self, world: &mut World) {
+        // Limits are checked per effect, so batching isn't possible.
+        let target = world.get_entity(self.target).ok();
+        if target.is_some_and(|target| {
+            target.contains::<MaxEffects>() || target.contains::<StackLimits>()
+        }) {
+            for bundle in self.bundles {
+                AddEffectCommand {
+                    target: self.target,
+                    bundle,
+                    entity: None,
+                }
+                .apply(world);
+            }
+            return;
+        }
+
+        let immunity = world.get::<EffectImmunity>(self.target).cloned();
+        let mut stacked = Vec::with_capacity(self.bundles.len());
+        let mut ids = Vec::with_capacity(self.bundles.len());
+
+        for mut bundle in self.bundles {
+            resolve_mode(world, &mut bundle);
+
+            if let Some(immunity) = &immunity
+                && immunity.blocks(&bundle.name, &bundle.tags, bundle.polarity)
+            {
+                continue;
+            }
+
+            // Effects with initial stacks are rare enough that they aren't worth batching.
+            #[cfg(feature = "stacks")]
+            let individual = bundle.stacks.is_some();
+            #[cfg(not(feature = "stacks"))]
+            let individual = false;
+
+            if bundle.resolved_mode() != EffectMode::Stack
+                || bundle.resolver.is_some()
+                || individual
+            {
+                AddEffectCommand {
+                    target: self.target,
+                    bundle,
+                    entity: None,
+                }
+                .apply(world);
+                continue;
+            }
+
+            // Batched effects are all stack-mode effects with the same components, so validating one covers the rest.
+            if stacked.is_empty() {
+                crate::validate::validate_effect(world, &bundle);
+            }
+
+            ids.push((bundle.id, bundle.category));
+            stacked.push((
+                EffectJustApplied,
+                Effecting(self.target),
+                bundle.name,
+                EffectMode::Stack,
+                bundle.priority,
+                bundle.tags,
+                bundle.polarity,
+                bundle.bundle,
+            ));
+        }
+
+        let entities: Vec<Entity> = world.spawn_batch(stacked).collect();
+
+        for (entity, (id, category)) in entities.into_iter().zip(ids) {
+            let order = next_effect_order(world);
+            let mut entity = world.entity_mut(entity);
+            entity.insert(order);
+
+            if let Some(id) = id {
+                entity.insert(id);
+            }
+
+            if let Some(category) = category {
+                entity.insert(category);
+            }
+        }
+    }
+}
+
+/// Applies an effect to a target entity, but only if the predicate returns `true`.
+/// The predicate is run against the target when the command is applied,
+/// so the check and the application happen atomically.
+///
+/// This is normally used via [`with_effect_if`](EffectCommandsExt::with_effect_if).
+pub struct AddEffectIfCommand<B: Bundle, F: FnOnce(&EntityWorldMut) -> bool> {
+    /// The entity to apply the effect to.
+    pub target: Entity,
+    /// The effect to apply.
+    pub bundle: EffectBundle<B>,
+    /// Decides whether the effect should be applied, given the target entity.
+    /// The rest of the world (such as resources) can be accessed using [`EntityWorldMut::world`].
+    pub predicate: F,
+}
+
+impl<B: Bundle, F: FnOnce(&EntityWorldMut) -> bool + Send + 'static> Command
+    for AddEffectIfCommand<B, F>
+{
+    fn apply(self, world: &mut World) {
+        let Ok(target) = world.get_entity_mut(self.target) else {
+            return;
+        };
+
+        if !(self.predicate)(&target) {
+            return;
+        }
+
+        AddEffectCommand {
+            target: self.target,
+            bundle: self.bundle,
+            entity: None,
+        }
+        .apply(world);
+    }
+}
+
+/// Binds an unbound effect to a target entity, as if it was applied to it.
+/// Unbound effects are normally spawned using [`spawn_effect`](AlchemyCommandsExt::spawn_effect).
+///
+/// Collisions with effects that are already applied to the target are handled when binding, based on the [`EffectMode`]:
+/// - [`Stack`](EffectMode::Stack): The effect is bound alongside existing ones.
+/// - [`Insert`](EffectMode::Insert): The effect is bound, and the existing one is despawned.
+/// - [`Merge`](EffectMode::Merge): The effect is bound, the existing one is merged into it using [`EffectMergeRegistry`], and then despawned.
+/// - [`Select`](EffectMode::Select): Either the effect or the existing one is despawned, same as when applying normally.
+///
+/// If the effect has an [`EffectResolver`], it is used instead of the mode's built-in one.
+///
+/// Unlike [`AddEffectCommand`], the existing effect is always replaced by the bound one,
+/// so components that only the existing effect had are lost.
+///
+/// If the effect isn't applied (such as because of [`EffectImmunity`] or [`MaxEffects`]), it is despawned.
+/// Effects that are already bound to a target are ignored.
+///
+/// This is normally used via [`bind_effect`](EffectCommandsExt::bind_effect).
+pub struct BindEffectCommand {
+    /// The entity to bind the effect to.
+    pub target: Entity,
+    /// The unbound effect.
+    pub effect: Entity,
+}
+
+impl BindEffectCommand {
+    fn bind(&self, world: &mut World) {
+        let order = next_effect_order(world);
+        world.entity_mut(self.effect).remove::<Disabled>().insert((
+            Effecting(self.target),
+            EffectJustApplied,
+            order,
+        ));
+    }
+}
+
+impl Command for BindEffectCommand {
+    fn apply(self, world: &mut World) {
+        let Ok(effect) = world.get_entity(self.effect) else {
+            return;
+        };
+
+        if effect.contains::<Effecting>() {
+            return;
+        }
+
+        let (Some(name), Some(mode)) = (effect.get::<Name>(), effect.get::<EffectMode>()) else {
+            return;
+        };
+
+        let name = name.clone();
+        let mode = *mode;
+        let priority = effect.get::<EffectPriority>().copied().unwrap_or_default();
+        let tags = effect.get::<EffectTags>().cloned().unwrap_or_default();
+        let polarity = effect.get::<EffectPolarity>().copied().unwrap_or_default();
+
+        if let Some(immunity) = world.get::<EffectImmunity>(self.target)
+            && immunity.blocks(&name, &tags, polarity)
+        {
+            world.despawn(self.effect);
+            return;
+        }
+
+        let resolver = world
+            .get::<EffectResolver>(self.effect)
+            .copied()
+            .or(mode.resolver());
+
+        let collision = resolver.and_then(|resolver| {
+            let existing = (resolver.find)(world, self.target, self.effect)?;
+            Some((resolver, existing))
+        });
+
+        let Some((resolver, existing)) = collision else {
+            let room = mode != EffectMode::Stack || make_room_for_stack(world, self.target, &name);
+
+            if room && make_room_for_effect(world, self.target, priority) {
+                self.bind(world);
+            } else {
+                world.despawn(self.effect);
+            }
+            return;
+        };
+
+        // Lower priority effects can't overwrite higher priority ones.
+        if outranked(world, existing, mode, priority) {
+            world.despawn(self.effect);
+            return;
+        }
+
+        #[cfg(feature = "stacks")]
+        let stack_limit = stack_limit(world, self.target, &name);
+
+        // Merging would increase the stacks past the limit.
+        #[cfg(feature = "stacks")]
+        if mode == EffectMode::Merge
+            && let Some((max, StackLimitPolicy::Reject)) = stack_limit
+            && stack_count(world, existing) >= max as u32
+        {
+            world.despawn(self.effect);
+            return;
+        }
+
+        self.bind(world);
+
+        if (resolver.resolve)(world, existing, self.effect) == KeepEffect::Old {
+            world.despawn(self.effect);
+            return;
+        }
+
+        #[cfg(feature = "stacks")]
+        if mode == EffectMode::Merge
+            && let Some((max, _)) = stack_limit
+        {
+            cap_stacks(world, self.effect, max);
+        }
+
+        world.despawn(existing);
+    }
+}
+
+/// Moves an effect that is already applied to one target over to a new target.
+/// Collisions with effects on the new target are handled the same as [`BindEffectCommand`],
+/// so the effect might be merged into (or replace) an existing effect with the same name.
+///
+/// The effect keeps its components, other than being marked as [`EffectJustApplied`].
+/// If it can't be applied to the new target (such as because of [`EffectImmunity`]), it is despawned.
+///
+/// This is normally used via [`retarget_effect`](AlchemyCommandsExt::retarget_effect).
+pub struct RetargetEffectCommand {
+    /// The effect to move.
+    pub effect: Entity,
+    /// The entity to move the effect to.
+    pub target: Entity,
+}
+
+impl Command for RetargetEffectCommand {
+    fn apply(self, world: &mut World) {
+        let Ok(mut effect) = world.get_entity_mut(self.effect) else {
+            return;
+        };
+
+        match effect.get::<Effecting>() {
+            Some(effecting) if effecting.0 == self.target => return,
+            Some(_) => {
+                effect.remove::<Effecting>();
+            }
+            None => {}
+        }
+
+        BindEffectCommand {
+            target: self.target,
+            effect: self.effect,
+        }
+        .apply(world);
+    }
+}
+
+/// Copies an effect that is applied to one target onto another target, such as for spreading an infection.
+/// Collisions with effects on the new target are handled the same as [`BindEffectCommand`],
+/// so the copy might be merged into (or replace) an existing effect with the same name.
+///
+/// The copy keeps the effect's current state (such as its stacks and timers), other than being marked as [`EffectJustApplied`].
+/// Components that can't be cloned (because they don't implement [`Clone`] or reflect [`Component`]) are skipped.
+/// The original effect is unchanged.
+///
+/// This is normally used via [`copy_effect_from`](EffectCommandsExt::copy_effect_from).
+pub struct CopyEffectCommand {
+    /// The effect to copy.
+    pub effect: Entity,
+    /// The entity to apply the copy to.
+    pub target: Entity,
+}
+
+impl Command for CopyEffectCommand {
+    fn apply(self, world: &mut World) {
+        let Ok(mut effect) = world.get_entity_mut(self.effect) else {
+            return;
+        };
+
+        let copy = effect.clone_and_spawn_with_opt_out(|builder| {
+            builder.deny::<(Effecting, EffectJustApplied)>();
+        });
+        world.entity_mut(copy).insert(Disabled);
+
+        BindEffectCommand {
+            target: self.target,
+            effect: copy,
+        }
+        .apply(world);
+    }
+}
+
+/// Mutates a component on the effects with the given name that are applied to a target entity.
+/// Effects without the component are skipped.
+///
+/// This is normally used via [`modify_effect`](EffectCommandsExt::modify_effect).
+pub struct ModifyEffectCommand<T: Component<Mutability = Mutable>, F: FnMut(&mut T)> {
+    /// The entity whose effects get modified.
+    pub target: Entity,
+    /// The name of the effects to modify.
+    pub name: Name,
+    /// The function that is run on each matching component.
+    pub f: F,
+    marker: PhantomData<fn(T)>,
+}
+
+impl<T: Component<Mutability = Mutable>, F: FnMut(&mut T)> ModifyEffectCommand<T, F> {
+    /// Creates a new command that modifies the effects named `name` on the `target`.
+    pub fn new(target: Entity, name: impl Into<Cow<'static, str>>, f: F) -> Self {
+        Self {
+            target,
+            name: Name::new(name),
+            f,
+            marker: PhantomData,
+        }
     }
+}
 
-    /// Inserts into the existing entity, and then merges the old effect into it using [`EffectMergeRegistry`].
-    /// Only registered components that implement `Clone` will be merged.
-    /// ## Steps
-    /// 1. Copy unregistered components to a new temporary, disabled entity.
-    /// 2. Insert new components into the existing entity.
-    /// 3. Merge the old components (temp entity) with the new ones (existing entity).
-    /// 4. Despawn temp entity.
-    fn merge(self, world: &mut World, existing_entity: Entity) {
-        if !world.contains_resource::<EffectMergeRegistry>() {
-            warn_once!(
-                "No `EffectComponentMergeRegistry` found. Did you forget to add the `AlchemyPlugin`?"
-            );
+impl<T: Component<Mutability = Mutable>, F: FnMut(&mut T) + Send + 'static> Command
+    for ModifyEffectCommand<T, F>
+{
+    fn apply(mut self, world: &mut World) {
+        let Some(effected_by) = world
+            .get::<EffectedBy>(self.target)
+            .map(|e| e.collection().clone())
+        else {
             return;
+        };
+
+        for entity in effected_by {
+            if world.get::<Name>(entity) != Some(&self.name) {
+                continue;
+            }
+
+            if let Some(mut component) = world.get_mut::<T>(entity) {
+                (self.f)(&mut component);
+            }
         }
+    }
+}
 
-        // Copy existing mergeable components to a temporary entity.
-        let new_effect = existing_entity;
-        let old_effect = {
-            let registry = world.resource::<EffectMergeRegistry>();
-            let allow: Vec<TypeId> = registry.merges.keys().copied().collect();
-
-            let temp = world.spawn(Disabled).id();
-            world
-                .entity_mut(existing_entity)
-                .clone_with_opt_in(temp, |builder| {
-                    builder.without_required_components(|builder| {
-                        builder.allow_by_ids(allow);
-                    });
-                });
+/// Despawns all effects on a target entity that match a [filter](EffectFilter).
+///
+/// This is normally used via [`clear_effects`](EffectCommandsExt::clear_effects)
+/// or [`remove_effect`](EffectCommandsExt::remove_effect).
+pub struct ClearEffectsCommand {
+    /// The entity to remove effects from.
+    pub target: Entity,
+    /// Selects which effects get removed.
+    pub filter: EffectFilter,
+}
 
-            temp
-        };
+impl Command for ClearEffectsCommand {
+    fn apply(self, world: &mut World) {
+        clear_effects(world, self.target, &self.filter);
+    }
+}
 
-        self.insert(world.entity_mut(new_effect));
+/// Despawns all effects on many target entities that match a [filter](EffectFilter), such as for an area-of-effect cleanse.
+/// Targets without any matching effects are skipped.
+///
+/// This is normally used via [`clear_effects_from`](AlchemyCommandsExt::clear_effects_from)
+/// or [`remove_effect_from`](AlchemyCommandsExt::remove_effect_from).
+pub struct ClearEffectsFromTargetsCommand {
+    /// The entities to remove effects from.
+    pub targets: Vec<Entity>,
+    /// Selects which effects get removed.
+    pub filter: EffectFilter,
+}
 
-        // Call merge function on those copied components.
-        {
-            let old = world.entity(old_effect);
-            let archetype = old.archetype();
+impl Command for ClearEffectsFromTargetsCommand {
+    fn apply(self, world: &mut World) {
+        for target in self.targets {
+            clear_effects(world, target, &self.filter);
+        }
+    }
+}
 
-            let registry = world.resource::<EffectMergeRegistry>();
+/// Despawns all effects on the target that match the filter.
+fn clear_effects(world: &mut World, target: Entity, filter: &EffectFilter) {
+    let Some(effected_by) = world.get::<EffectedBy>(target) else {
+        return;
+    };
 
-            let merge_functions: Vec<EffectMergeFn> = archetype
-                .components()
-                .iter()
-                .filter_map(|component_id| {
-                    world
-                        .components()
-                        .get_info(*component_id)
-                        .and_then(|info| info.type_id())
-                        .and_then(|id| registry.merges.get(&id).copied())
-                })
-                .collect();
+    let matches: Vec<Entity> = effected_by
+        .iter()
+        .filter(|entity| filter.matches_entity(world.entity(*entity)))
+        .collect();
+
+    for entity in matches {
+        world.despawn(entity);
+    }
+}
+
+/// The order used to select which effects get [dispelled](EffectCommandsExt::dispel).
+/// Ties are broken by the order the effects were applied ([`EffectOrder`]), oldest first.
+#[derive(Reflect, Eq, PartialEq, Debug, Copy, Clone)]
+#[reflect(PartialEq, Debug, Clone)]
+pub enum DispelOrder {
+    /// The effects that were applied first.
+    Oldest,
+    /// The effects that were applied last.
+    Newest,
+    /// The effects with the most stacks. Effects without stacks count as a single stack.
+    MostStacks,
+    /// The effects with the least remaining [`Lifetime`](crate::Lifetime). Effects without a lifetime are dispelled last.
+    #[cfg(feature = "timers")]
+    SoonestExpiry,
+}
+
+/// Despawns up to `count` effects on a target entity that match a [filter](EffectFilter),
+/// selected using a [`DispelOrder`], such as for an ability that cleanses two debuffs.
+///
+/// This is normally used via [`dispel`](EffectCommandsExt::dispel).
+pub struct DispelEffectsCommand {
+    /// The entity to remove effects from.
+    pub target: Entity,
+    /// Selects which effects can be removed.
+    pub filter: EffectFilter,
+    /// The maximum number of effects to remove.
+    pub count: usize,
+    /// The order used to select which effects are removed.
+    pub order: DispelOrder,
+}
+
+impl Command for DispelEffectsCommand {
+    fn apply(self, world: &mut World) {
+        let Some(effected_by) = world.get::<EffectedBy>(self.target) else {
+            return;
+        };
+
+        let mut matches: Vec<Entity> = effected_by
+            .iter()
+            .filter(|entity| self.filter.matches_entity(world.entity(*entity)))
+            .collect();
 
-            for merge in merge_functions {
-                merge(world.entity_mut(new_effect), old_effect);
+        // The other sorts are stable, so ties are broken by the order.
+        sort_oldest_first(world, &mut matches);
+
+        match self.order {
+            DispelOrder::Oldest => {}
+            DispelOrder::Newest => matches.reverse(),
+            DispelOrder::MostStacks => {
+                matches.sort_by_key(|entity| Reverse(stack_count(world, *entity)));
             }
+            #[cfg(feature = "timers")]
+            DispelOrder::SoonestExpiry => matches.sort_by_key(|entity| {
+                let lifetime = world.get::<crate::Lifetime>(*entity);
+                (lifetime.is_none(), lifetime.map(|l| l.timer.remaining()))
+            }),
         }
 
-        world.despawn(old_effect);
+        for entity in matches.into_iter().take(self.count) {
+            world.despawn(entity);
+        }
     }
 }
 
-impl<B: Bundle> Command for AddEffectCommand<B> {
+/// Removes a source from the [`EffectSources`] of every effect on a target entity,
+/// despawning the effects that have no sources left.
+///
+/// This is normally used via [`release_effect`](EffectCommandsExt::release_effect).
+pub struct ReleaseEffectCommand {
+    /// The entity whose effects get released.
+    pub target: Entity,
+    /// The source that no longer keeps the effects alive.
+    pub source: Entity,
+}
+
+impl Command for ReleaseEffectCommand {
     fn apply(self, world: &mut World) {
-        if self.bundle.mode == EffectMode::Stack {
-            self.spawn(world);
+        let Some(effected_by) = world
+            .get::<EffectedBy>(self.target)
+            .map(|e| e.collection().clone())
+        else {
             return;
+        };
+
+        for effect in effected_by {
+            let Some(mut sources) = world.get_mut::<EffectSources>(effect) else {
+                continue;
+            };
+
+            if sources.remove(self.source) && sources.0.is_empty() {
+                world.despawn(effect);
+            }
         }
+    }
+}
+
+/// Removes stacks from the effects on a target entity with the given name, such as for an ability that consumes 2 stacks of combo.
+/// Effects are despawned once they have no stacks left.
+///
+/// Stacks are counted the same as [`EffectReader::stacks_of`](crate::EffectReader::stacks_of),
+/// so effects without [`EffectStacks`](crate::EffectStacks) (or [`EffectStacksU32`](crate::EffectStacksU32))
+/// count as a single stack, and are despawned entirely. Effects are consumed in the order they were applied.
+/// If there are fewer stacks than `amount`, all of them are consumed.
+///
+/// This is normally used via [`consume_stacks`](EffectCommandsExt::consume_stacks).
+#[cfg(feature = "stacks")]
+pub struct ConsumeStacksCommand {
+    /// The entity whose effects get consumed.
+    pub target: Entity,
+    /// The name of the effects to consume.
+    pub name: Name,
+    /// The number of stacks to remove.
+    pub amount: u8,
+}
+
+#[cfg(feature = "stacks")]
+impl Command for ConsumeStacksCommand {
+    fn apply(self, world: &mut World) {
+        use crate::component::consume_stacks;
+        use crate::{EffectStacks, EffectStacksU32};
 
         let Some(effected_by) = world
             .get::<EffectedBy>(self.target)
             .map(|e| e.collection().clone())
         else {
-            self.spawn(world);
             return;
         };
 
-        // Find previous entity that is:
-        // 1. effecting the same target,
-        // 2. and has the same name (ID).
-        let old_entity = effected_by.iter().find_map(|entity| {
-            let other_mode = world.get::<EffectMode>(*entity)?;
+        let mut remaining = self.amount;
 
-            // Todo Think more about.
-            if self.bundle.mode != *other_mode {
-                return None;
+        for effect in effected_by {
+            if remaining == 0 {
+                break;
             }
 
-            let name = world.get::<Name>(*entity)?;
-
-            if name == &self.bundle.name {
-                return Some(*entity);
+            let entity = world.entity(effect);
+            if entity.get::<Name>() != Some(&self.name) {
+                continue;
             }
 
-            None
-        });
-
-        let Some(old_entity) = old_entity else {
-            self.spawn(world);
-            return;
-        };
+            let consumed = if entity.contains::<EffectStacksU32>() {
+                consume_stacks::<EffectStacksU32, u32>(world, effect, remaining.into()) as u8
+            } else if entity.contains::<EffectStacks>() {
+                consume_stacks::<EffectStacks, u8>(world, effect, remaining)
+            } else {
+                world.despawn(effect);
+                1
+            };
 
-        match self.bundle.mode {
-            EffectMode::Stack => unreachable!(),
-            EffectMode::Insert => self.insert(world.entity_mut(old_entity)),
-            EffectMode::Merge => self.merge(world, old_entity),
+            remaining -= consumed;
         }
     }
 }
@@ -152,7 +1255,11 @@ impl<B: Bundle> Command for AddEffectCommand<B> {
 impl<B: Bundle> SpawnableList<Effecting> for EffectBundle<B> {
     fn spawn(this: MovingPtr<'_, Self>, world: &mut World, target: Entity) {
         let bundle = this.read();
-        world.commands().queue(AddEffectCommand { target, bundle });
+        world.commands().queue(AddEffectCommand {
+            target,
+            bundle,
+            entity: None,
+        });
     }
 
     fn size_hint(&self) -> usize {
@@ -177,13 +1284,24 @@ impl<'a> EffectSpawner<'a> {
     ///
     /// This is normally used during [`with_effects`](EffectCommandsExt::with_effects).
     ///
+    /// Returns an entity that is reserved for the effect, which can be used to manipulate a group of effects later.
+    ///
+    /// The entity is only used if a new effect is spawned, which is always the case for [`Stack`](EffectMode::Stack) effects
+    /// (unless they are blocked). For other modes, it is only used if there is no existing effect to collide with,
+    /// or if a [`Select`](EffectMode::Select) effect replaces the existing one.
+    /// Otherwise, the effect is folded into the existing effect (or isn't applied at all) and the reserved entity is despawned,
+    /// so the returned ID no longer refers to an entity. The existing effect can instead be found using its [`EffectJustApplied`] marker.
+    ///
     /// # Example
     #[doc = include_str!("../docs/with_effects_example.md")]
-    pub fn spawn<B: Bundle>(&mut self, bundle: EffectBundle<B>) {
+    pub fn spawn<B: Bundle>(&mut self, bundle: EffectBundle<B>) -> Entity {
+        let entity = self.commands.spawn_empty().id();
         self.commands.queue(AddEffectCommand {
             target: self.target,
             bundle,
+            entity: Some(entity),
         });
+        entity
     }
 }
 
@@ -194,10 +1312,33 @@ pub trait EffectCommandsExt {
     ///
     /// For applying multiple effects, see [`with_effects`](Self::with_effects).
     ///
+    /// # Timing
+    /// Like other commands, the effect is only applied at the next sync point (such as the end of the schedule),
+    /// so a query in the same system won't see it. Systems that need the effect immediately should use
+    /// [`EffectWorldExt::with_effect`] or [`AlchemyWorldExt::apply_effect`] with exclusive world access.
+    ///
     /// # Example
     #[doc = include_str!("../docs/with_effect_example.md")]
     fn with_effect<B: Bundle>(&mut self, bundle: EffectBundle<B>) -> &mut Self;
 
+    /// Applies many effects to this entity as a single command.
+    /// [Stack-mode](EffectMode::Stack) effects are spawned as a batch,
+    /// making this much faster than calling [`with_effect`](Self::with_effect) for each effect.
+    fn with_stacked_effects<B: Bundle<Effect: NoBundleEffect>>(
+        &mut self,
+        bundles: impl IntoIterator<Item = EffectBundle<B>>,
+    ) -> &mut Self;
+
+    /// Applies an effect to this entity, but only if the predicate returns `true`.
+    /// The predicate runs when the command is applied, against the current state of this entity.
+    ///
+    /// The rest of the world (such as resources) can be accessed using [`EntityWorldMut::world`].
+    fn with_effect_if<B: Bundle>(
+        &mut self,
+        bundle: EffectBundle<B>,
+        predicate: impl FnOnce(&EntityWorldMut) -> bool + Send + 'static,
+    ) -> &mut Self;
+
     /// Applies effects to this entity by taking a function that operates on a [`EffectSpawner`].
     ///
     /// For applying a single effect, see [`with_effect`](Self::with_effect).
@@ -205,12 +1346,138 @@ pub trait EffectCommandsExt {
     /// # Example
     #[doc = include_str!("../docs/with_effects_example.md")]
     fn with_effects(&mut self, f: impl FnOnce(&mut EffectSpawner)) -> &mut Self;
+
+    /// Runs a function on a component of the already applied effects with the given name.
+    /// Effects that don't have the component are skipped, and nothing happens if no effect matches.
+    ///
+    /// Unlike reapplying an effect, this does not run any [merge functions](crate::EffectMergeFn).
+    fn modify_effect<T: Component<Mutability = Mutable>>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        f: impl FnMut(&mut T) + Send + 'static,
+    ) -> &mut Self;
+
+    /// Removes all effects with the given name from this entity.
+    ///
+    /// For removing effects by tag, see [`clear_effects`](Self::clear_effects).
+    fn remove_effect(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.clear_effects(EffectFilter::name(name))
+    }
+
+    /// Adds time to the [`Lifetime`](crate::Lifetime) of the already applied effects with the given name,
+    /// such as for a hit that extends a rage buff by 2 seconds.
+    /// If `max` is set, the remaining time isn't extended past it.
+    ///
+    /// Unlike reapplying an effect, this does not run any [merge functions](crate::EffectMergeFn).
+    /// See [`Lifetime::extend`](crate::Lifetime::extend) for more details.
+    #[cfg(feature = "timers")]
+    fn extend_lifetime(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        amount: Duration,
+        max: Option<Duration>,
+    ) -> &mut Self {
+        self.modify_effect::<crate::Lifetime>(name, move |lifetime| lifetime.extend(amount, max))
+    }
+
+    /// Removes all effects that match the [filter](EffectFilter) from this entity.
+    fn clear_effects(&mut self, filter: EffectFilter) -> &mut Self;
+
+    /// Removes up to `count` effects that match the [filter](EffectFilter) from this entity, selected using the [`DispelOrder`].
+    ///
+    /// Unlike [`clear_effects`](Self::clear_effects), this only removes some of the matching effects,
+    /// such as for an ability that cleanses the two oldest debuffs.
+    fn dispel(&mut self, filter: EffectFilter, count: usize, order: DispelOrder) -> &mut Self;
+
+    /// Removes the source from the [`EffectSources`] of this entity's effects,
+    /// despawning the effects once their last source is released.
+    ///
+    /// See [`ReleaseEffectCommand`] for more details.
+    fn release_effect(&mut self, source: Entity) -> &mut Self;
+
+    /// Removes `amount` stacks from the effects with the given name, despawning them once they have no stacks left.
+    /// Nothing happens if no effect matches.
+    ///
+    /// Unlike [`remove_effect`](Self::remove_effect), this only removes the effect entirely if it runs out of stacks.
+    /// See [`ConsumeStacksCommand`] for more details.
+    #[cfg(feature = "stacks")]
+    fn consume_stacks(&mut self, name: impl Into<Cow<'static, str>>, amount: u8) -> &mut Self;
+
+    /// Applies a bundle to this entity as an effect that lasts for the given duration.
+    ///
+    /// The effect uses [`EffectMode::Insert`], so reapplying it refreshes the duration rather than stacking,
+    /// and is named after the bundle's type (such as `Poison` or `(Poison, Magnitude)`).
+    /// For anything else, use [`with_effect`](Self::with_effect) with a [`Lifetime`](crate::Lifetime).
+    #[cfg(feature = "timers")]
+    fn apply_for<B: Bundle>(&mut self, bundle: B, duration: Duration) -> &mut Self {
+        use crate::EffectTimer;
+
+        self.with_effect(EffectBundle {
+            name: crate::bundle::type_name::<B>(),
+            mode: Some(EffectMode::Insert),
+            ..EffectBundle::new((crate::Lifetime::new(duration), bundle))
+        })
+    }
+
+    /// Binds an unbound effect to this entity, handling collisions with existing effects the same as [`with_effect`](Self::with_effect).
+    /// Unbound effects are spawned using [`spawn_effect`](AlchemyCommandsExt::spawn_effect).
+    ///
+    /// See [`BindEffectCommand`] for how collisions are handled.
+    fn bind_effect(&mut self, effect: Entity) -> &mut Self;
+
+    /// Copies an effect from another target onto this entity, keeping its current state (such as its stacks and timers).
+    /// This is useful for spreading effects, such as an infection that jumps to nearby enemies.
+    ///
+    /// See [`CopyEffectCommand`] for how collisions with this entity's effects are handled.
+    fn copy_effect_from(&mut self, effect: Entity) -> &mut Self;
+
+    /// Applies an effect that was loaded from an [`EffectAsset`](crate::EffectAsset) to this entity.
+    ///
+    /// See [`AddEffectAssetCommand`](crate::AddEffectAssetCommand) for more details.
+    #[cfg(feature = "assets")]
+    fn with_effect_asset(&mut self, handle: bevy_asset::Handle<crate::EffectAsset>) -> &mut Self;
+
+    /// Updates the matching effect on this entity to match an [`EffectSnapshot`](crate::EffectSnapshot),
+    /// such as for replicating effects to clients. If there's no matching effect, a new one is spawned.
+    ///
+    /// See [`ApplyEffectSnapshotCommand`](crate::ApplyEffectSnapshotCommand) for more details.
+    fn apply_snapshot(&mut self, snapshot: crate::EffectSnapshot) -> &mut Self;
 }
 
 impl EffectCommandsExt for EntityCommands<'_> {
     fn with_effect<B: Bundle>(&mut self, bundle: EffectBundle<B>) -> &mut Self {
         let target = self.id();
-        self.commands().queue(AddEffectCommand { target, bundle });
+        self.commands().queue(AddEffectCommand {
+            target,
+            bundle,
+            entity: None,
+        });
+        self
+    }
+
+    fn with_stacked_effects<B: Bundle<Effect: NoBundleEffect>>(
+        &mut self,
+        bundles: impl IntoIterator<Item = EffectBundle<B>>,
+    ) -> &mut Self {
+        let target = self.id();
+        self.commands().queue(AddStackedEffectsCommand {
+            target,
+            bundles: bundles.into_iter().collect(),
+        });
+        self
+    }
+
+    fn with_effect_if<B: Bundle>(
+        &mut self,
+        bundle: EffectBundle<B>,
+        predicate: impl FnOnce(&EntityWorldMut) -> bool + Send + 'static,
+    ) -> &mut Self {
+        let target = self.id();
+        self.commands().queue(AddEffectIfCommand {
+            target,
+            bundle,
+            predicate,
+        });
         self
     }
 
@@ -221,4 +1488,276 @@ impl EffectCommandsExt for EntityCommands<'_> {
         });
         self
     }
+
+    fn modify_effect<T: Component<Mutability = Mutable>>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        f: impl FnMut(&mut T) + Send + 'static,
+    ) -> &mut Self {
+        let target = self.id();
+        self.commands()
+            .queue(ModifyEffectCommand::new(target, name, f));
+        self
+    }
+
+    fn clear_effects(&mut self, filter: EffectFilter) -> &mut Self {
+        let target = self.id();
+        self.commands()
+            .queue(ClearEffectsCommand { target, filter });
+        self
+    }
+
+    fn dispel(&mut self, filter: EffectFilter, count: usize, order: DispelOrder) -> &mut Self {
+        let target = self.id();
+        self.commands().queue(DispelEffectsCommand {
+            target,
+            filter,
+            count,
+            order,
+        });
+        self
+    }
+
+    fn release_effect(&mut self, source: Entity) -> &mut Self {
+        let target = self.id();
+        self.commands()
+            .queue(ReleaseEffectCommand { target, source });
+        self
+    }
+
+    #[cfg(feature = "stacks")]
+    fn consume_stacks(&mut self, name: impl Into<Cow<'static, str>>, amount: u8) -> &mut Self {
+        let target = self.id();
+        self.commands().queue(ConsumeStacksCommand {
+            target,
+            name: Name::new(name),
+            amount,
+        });
+        self
+    }
+
+    fn bind_effect(&mut self, effect: Entity) -> &mut Self {
+        let target = self.id();
+        self.commands().queue(BindEffectCommand { target, effect });
+        self
+    }
+
+    fn copy_effect_from(&mut self, effect: Entity) -> &mut Self {
+        let target = self.id();
+        self.commands().queue(CopyEffectCommand { effect, target });
+        self
+    }
+
+    #[cfg(feature = "assets")]
+    fn with_effect_asset(&mut self, handle: bevy_asset::Handle<crate::EffectAsset>) -> &mut Self {
+        let target = self.id();
+        self.commands()
+            .queue(crate::AddEffectAssetCommand { target, handle });
+        self
+    }
+
+    fn apply_snapshot(&mut self, snapshot: crate::EffectSnapshot) -> &mut Self {
+        let target = self.id();
+        self.commands()
+            .queue(crate::ApplyEffectSnapshotCommand { target, snapshot });
+        self
+    }
+}
+
+/// An extension trait for adding effect methods to [`Commands`].
+pub trait AlchemyCommandsExt {
+    /// Applies the same effect to each of the target entities.
+    /// This *might* spawn new entities, depending on what effects are already applied to each target.
+    ///
+    /// The bundle will be cloned for each target, which is why it must implement [`Clone`].
+    /// All applications are queued as a single command.
+    fn apply_effect_to<B: Bundle + Clone>(
+        &mut self,
+        targets: impl IntoIterator<Item = Entity>,
+        bundle: EffectBundle<B>,
+    ) -> &mut Self;
+
+    /// Spawns an effect that isn't applied to any target yet, which can later be bound using [`bind_effect`](EffectCommandsExt::bind_effect).
+    /// This is useful for building effects ahead of time, such as for pooling.
+    ///
+    /// An unbound effect has all the components of the effect bundle, but has no [`Effecting`] relationship.
+    /// It is also [`Disabled`], so it is ignored by systems (including timers) until it is bound.
+    /// Components that depend on a target (such as [`EffectJustApplied`] or `EffectAge`) are added when binding.
+    fn spawn_effect<B: Bundle>(&mut self, bundle: EffectBundle<B>) -> EntityCommands<'_>;
+
+    /// Moves an effect to a new target, such as for reflecting a debuff back at its caster.
+    ///
+    /// See [`RetargetEffectCommand`] for how collisions with the new target's effects are handled.
+    fn retarget_effect(&mut self, effect: Entity, new_target: Entity) -> &mut Self;
+
+    /// Removes all effects that match the [filter](EffectFilter) from each of the target entities, such as for an area-of-effect cleanse.
+    /// Targets without any matching effects are skipped.
+    ///
+    /// All removals are queued as a single command.
+    fn clear_effects_from(
+        &mut self,
+        targets: impl IntoIterator<Item = Entity>,
+        filter: EffectFilter,
+    ) -> &mut Self;
+
+    /// Removes all effects with the given name from each of the target entities.
+    ///
+    /// For removing effects by tag, see [`clear_effects_from`](Self::clear_effects_from).
+    fn remove_effect_from(
+        &mut self,
+        targets: impl IntoIterator<Item = Entity>,
+        name: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        self.clear_effects_from(targets, EffectFilter::name(name))
+    }
+
+    /// Applies an effect to the target of another effect.
+    /// This is useful for chaining effects from a system that iterates over effects,
+    /// such as applying a "weakened" effect when poison expires.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_alchemy::*;
+    /// # #[derive(Component)]
+    /// # struct Poison;
+    /// # #[derive(Component)]
+    /// # struct Weakened;
+    /// fn weaken_when_poisoned(
+    ///     mut commands: Commands,
+    ///     effects: Query<&Effecting, Added<Poison>>,
+    /// ) {
+    ///     for effecting in &effects {
+    ///         commands.apply_effect_to_target(effecting, EffectBundle::new(Weakened));
+    ///     }
+    /// }
+    /// ```
+    fn apply_effect_to_target<B: Bundle>(
+        &mut self,
+        effecting: &Effecting,
+        bundle: EffectBundle<B>,
+    ) -> &mut Self;
+
+    /// Applies an effect to each [source](EffectSources) of another effect, such as for retaliating against whoever applied it.
+    ///
+    /// The bundle will be cloned for each source, which is why it must implement [`Clone`].
+    /// All applications are queued as a single command.
+    fn apply_effect_to_sources<B: Bundle + Clone>(
+        &mut self,
+        sources: &EffectSources,
+        bundle: EffectBundle<B>,
+    ) -> &mut Self;
+}
+
+impl AlchemyCommandsExt for Commands<'_, '_> {
+    fn apply_effect_to<B: Bundle + Clone>(
+        &mut self,
+        targets: impl IntoIterator<Item = Entity>,
+        bundle: EffectBundle<B>,
+    ) -> &mut Self {
+        self.queue(AddEffectToTargetsCommand {
+            targets: targets.into_iter().collect(),
+            bundle,
+        });
+        self
+    }
+
+    fn spawn_effect<B: Bundle>(&mut self, bundle: EffectBundle<B>) -> EntityCommands<'_> {
+        let mut entity = self.spawn(Disabled);
+        entity.queue(|entity: EntityWorldMut| {
+            let mut bundle = bundle;
+            resolve_mode(entity.world(), &mut bundle);
+            insert_effect(entity, bundle, ());
+        });
+        entity
+    }
+
+    fn retarget_effect(&mut self, effect: Entity, new_target: Entity) -> &mut Self {
+        self.queue(RetargetEffectCommand {
+            effect,
+            target: new_target,
+        });
+        self
+    }
+
+    fn clear_effects_from(
+        &mut self,
+        targets: impl IntoIterator<Item = Entity>,
+        filter: EffectFilter,
+    ) -> &mut Self {
+        self.queue(ClearEffectsFromTargetsCommand {
+            targets: targets.into_iter().collect(),
+            filter,
+        });
+        self
+    }
+
+    fn apply_effect_to_target<B: Bundle>(
+        &mut self,
+        effecting: &Effecting,
+        bundle: EffectBundle<B>,
+    ) -> &mut Self {
+        self.queue(AddEffectCommand {
+            target: effecting.0,
+            bundle,
+            entity: None,
+        });
+        self
+    }
+
+    fn apply_effect_to_sources<B: Bundle + Clone>(
+        &mut self,
+        sources: &EffectSources,
+        bundle: EffectBundle<B>,
+    ) -> &mut Self {
+        self.apply_effect_to(sources.0.iter().copied(), bundle)
+    }
+}
+
+/// An extension trait for adding effect methods to [`EntityWorldMut`].
+/// Unlike [`EffectCommandsExt`], effects are applied immediately, rather than being queued as commands.
+/// This is useful in exclusive systems, or when setting up a [`World`] directly.
+pub trait EffectWorldExt {
+    /// Applies an effect to this entity immediately.
+    /// This *might* spawn a new entity, depending on what effects are already applied to it.
+    ///
+    /// See [`EffectCommandsExt::with_effect`] for the deferred version.
+    fn with_effect<B: Bundle>(&mut self, bundle: EffectBundle<B>) -> &mut Self;
+}
+
+impl EffectWorldExt for EntityWorldMut<'_> {
+    fn with_effect<B: Bundle>(&mut self, bundle: EffectBundle<B>) -> &mut Self {
+        let target = self.id();
+        self.world_scope(|world| {
+            AddEffectCommand {
+                target,
+                bundle,
+                entity: None,
+            }
+            .apply(world);
+        });
+        self
+    }
+}
+
+/// An extension trait for adding effect methods to [`World`].
+/// Unlike [`AlchemyCommandsExt`], effects are applied immediately, rather than being queued as commands.
+pub trait AlchemyWorldExt {
+    /// Applies an effect to the target immediately, so it can be read straight away.
+    /// This *might* spawn a new entity, depending on what effects are already applied to the target.
+    ///
+    /// See [`EffectCommandsExt::with_effect`] for the deferred version.
+    fn apply_effect<B: Bundle>(&mut self, target: Entity, bundle: EffectBundle<B>) -> &mut Self;
+}
+
+impl AlchemyWorldExt for World {
+    fn apply_effect<B: Bundle>(&mut self, target: Entity, bundle: EffectBundle<B>) -> &mut Self {
+        AddEffectCommand {
+            target,
+            bundle,
+            entity: None,
+        }
+        .apply(self);
+        self
+    }
 }
@@ -1,5 +1,33 @@
+mod applied;
+mod category;
+#[cfg(feature = "timers")]
+mod clock;
+mod id;
+mod limit;
+mod magnitude;
+mod order;
+mod polarity;
+mod priority;
+mod sources;
+#[cfg(feature = "stacks")]
 mod stack;
+mod tags;
+#[cfg(feature = "timers")]
 mod timer;
 
+pub use applied::*;
+pub use category::*;
+#[cfg(feature = "timers")]
+pub use clock::*;
+pub use id::*;
+pub use limit::*;
+pub use magnitude::*;
+pub use order::*;
+pub use polarity::*;
+pub use priority::*;
+pub use sources::*;
+#[cfg(feature = "stacks")]
 pub use stack::*;
+pub use tags::*;
+#[cfg(feature = "timers")]
 pub use timer::*;
@@ -0,0 +1,33 @@
+use crate::ReflectComponent;
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+
+pub(crate) struct AppliedPlugin;
+
+impl Plugin for AppliedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, remove_just_applied);
+    }
+}
+
+/// A marker that is added to an effect when it gets applied, and removed at the end of the frame (during `Last`).
+/// This can be used to run one-time logic when an effect is first applied, such as an initial burst of damage.
+///
+/// When an effect is [inserted](crate::EffectMode::Insert) or [merged](crate::EffectMode::Merge)
+/// into an existing effect, the marker is re-added, unless disabled using
+/// [`AlchemySettings::mark_reapplied`](crate::AlchemySettings::mark_reapplied).
+///
+/// Only systems that run after the effect is applied (during the same frame) will see the marker.
+/// To detect new effect *entities* regardless of system order, an `Added<Effecting>` filter can be used instead.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[component(storage = "SparseSet")]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectJustApplied;
+
+fn remove_just_applied(mut commands: Commands, query: Query<Entity, With<EffectJustApplied>>) {
+    for entity in &query {
+        commands.entity(entity).remove::<EffectJustApplied>();
+    }
+}
@@ -0,0 +1,21 @@
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_reflect::Reflect;
+use std::borrow::Cow;
+
+/// An opt-in category that is used instead of an effect's [`Name`](bevy_ecs::prelude::Name) or [`EffectId`](crate::EffectId)
+/// to decide whether two effects are the same.
+///
+/// This allows different effects to interact, such as every fire effect [merging](crate::EffectMode::Merge) into one.
+/// Effects are only matched by category if they both have the same category, so effects without one still use strict name matching.
+///
+/// It is normally set using [`EffectBundle::category`](crate::EffectBundle::category).
+#[derive(Component, Reflect, Eq, PartialEq, Hash, Debug, Clone)]
+#[reflect(Component, PartialEq, Debug, Clone)]
+pub struct EffectCategory(pub Cow<'static, str>);
+
+impl EffectCategory {
+    /// Creates a new category.
+    pub fn new(category: impl Into<Cow<'static, str>>) -> Self {
+        Self(category.into())
+    }
+}
@@ -0,0 +1,93 @@
+use super::timer::{
+    despawn_finished_lifetimes, despawn_finished_tick_counts, tick_activation_delay, tick_age,
+    tick_delay, tick_lifetime,
+};
+use crate::{AlchemySet, ReflectComponent};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::{Component, With, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+use bevy_time::Time;
+use std::marker::PhantomData;
+
+/// Ticks the timers of effects with an [`EffectClock<C>`] using `Time<C>`, instead of the default `Time`,
+/// such as for a "bullet time" clock that runs separately from the game's time.
+/// This allows multiple independent effect-time domains.
+///
+/// The `Time<C>` resource is initialized if it doesn't exist, but it must be advanced by the user.
+/// Timers are ticked during [`AlchemySet::TickTimers`], and expired effects are despawned as usual.
+pub struct EffectClockPlugin<C: Default + Send + Sync + 'static> {
+    _phantom: PhantomData<C>,
+}
+
+impl<C: Default + Send + Sync + 'static> Plugin for EffectClockPlugin<C> {
+    fn build(&self, app: &mut App) {
+        type Filter<C> = With<EffectClock<C>>;
+
+        app.init_resource::<Time<C>>().add_systems(
+            PreUpdate,
+            (
+                tick_lifetime::<C, Filter<C>>.before(despawn_finished_lifetimes),
+                tick_delay::<C, Filter<C>>.before(despawn_finished_tick_counts),
+                tick_age::<C, Filter<C>>,
+                tick_activation_delay::<C, Filter<C>>,
+            )
+                .in_set(AlchemySet::TickTimers),
+        );
+    }
+}
+
+impl<C: Default + Send + Sync + 'static> EffectClockPlugin<C> {
+    /// Creates a new plugin that ticks effect timers using `Time<C>`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Default + Send + Sync + 'static> Default for EffectClockPlugin<C> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Makes an effect's timers ([`Lifetime`](crate::Lifetime), [`Delay`](crate::Delay), [`EffectAge`](crate::EffectAge)
+/// and [`ActivationDelay`](crate::ActivationDelay)) tick using `Time<C>`,
+/// instead of the default `Time`. This requires the [`EffectClockPlugin<C>`].
+#[derive(Component)]
+#[require(CustomEffectClock)]
+pub struct EffectClock<C: Send + Sync + 'static> {
+    _phantom: PhantomData<C>,
+}
+
+impl<C: Send + Sync + 'static> EffectClock<C> {
+    /// Creates a new marker that makes the effect's timers tick using `Time<C>`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Send + Sync + 'static> Default for EffectClock<C> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: Send + Sync + 'static> Clone for EffectClock<C> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+/// Marks an effect whose timers aren't ticked by the default `Time`, because it has an [`EffectClock`].
+/// This is added automatically.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct CustomEffectClock;
+
+/// Effects that are ticked using the default `Time`.
+pub(super) type DefaultClock = Without<CustomEffectClock>;
@@ -0,0 +1,19 @@
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_reflect::Reflect;
+use std::any::TypeId;
+
+/// A typed identity for an effect, which is used instead of its [`Name`](bevy_ecs::prelude::Name)
+/// to decide whether two effects are the same.
+///
+/// This avoids typos in effect names, and is faster to compare than strings.
+/// It is normally set using [`EffectBundle::id`](crate::EffectBundle::id).
+#[derive(Component, Reflect, Eq, PartialEq, Hash, Debug, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Clone)]
+pub struct EffectId(pub TypeId);
+
+impl EffectId {
+    /// Creates an ID from a Rust type, which is normally the effect's main component or a marker type.
+    pub fn of<T: 'static>() -> Self {
+        Self(TypeId::of::<T>())
+    }
+}
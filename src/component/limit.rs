@@ -0,0 +1,110 @@
+use bevy_ecs::prelude::{Component, Name, ReflectComponent};
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+use std::collections::HashMap;
+
+/// Limits the total number of effects that can be applied to this entity at once.
+///
+/// Reapplying an existing effect using [`Insert`](crate::EffectMode::Insert),
+/// [`Merge`](crate::EffectMode::Merge) or [`Select`](crate::EffectMode::Select)
+/// doesn't increase the number of effects, so it isn't limited.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Clone)]
+pub struct MaxEffects {
+    /// The maximum number of effects.
+    pub max: u16,
+    /// Controls what happens when a new effect is applied while at the limit.
+    pub policy: EffectLimitPolicy,
+}
+
+impl MaxEffects {
+    /// Creates a new limit with the default policy ([`Reject`](EffectLimitPolicy::Reject)).
+    pub fn new(max: u16) -> Self {
+        Self {
+            max,
+            policy: EffectLimitPolicy::default(),
+        }
+    }
+
+    /// A builder that overwrites the current policy with a new value.
+    pub fn with_policy(mut self, policy: EffectLimitPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+/// Controls what happens when a new effect is applied to an entity that is at its [`MaxEffects`] limit.
+#[derive(Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(PartialEq, Debug, Default, Clone)]
+pub enum EffectLimitPolicy {
+    /// The new effect won't be applied.
+    #[default]
+    Reject,
+    /// The oldest effect (the one with the lowest [`EffectOrder`](crate::EffectOrder)) is despawned to make room for the new one.
+    /// Reapplied effects count as new, so they are evicted last.
+    EvictOldest,
+    /// The effect with the lowest [`EffectPriority`](crate::EffectPriority) is despawned to make room for the new one,
+    /// with the oldest effect being evicted first if there is a tie.
+    ///
+    /// If every existing effect has a higher priority than the new one, the new effect won't be applied.
+    EvictLowestPriority,
+}
+
+/// Limits the number of stacks of individual effects that can be applied to this entity, keyed by effect [`Name`].
+/// Effects without a limit can be stacked freely.
+///
+/// For [`Stack`](crate::EffectMode::Stack) effects, this limits the number of effects with the same name.
+/// For [`Merge`](crate::EffectMode::Merge) effects, this limits their [`EffectStacks`](crate::EffectStacks).
+/// [`Insert`](crate::EffectMode::Insert) and [`Select`](crate::EffectMode::Select) effects only ever have one stack,
+/// so they aren't limited.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// // At most 3 stacks of poison, but unlimited regeneration.
+/// let limits = StackLimits::default().with_limit("Poison", 3);
+/// assert_eq!(limits.get(&Name::new("Poison")), Some(3));
+/// assert_eq!(limits.get(&Name::new("Regen")), None);
+/// ```
+#[derive(Component, Reflect, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct StackLimits {
+    /// The maximum number of stacks for each effect name.
+    pub limits: HashMap<Name, u8>,
+    /// Controls what happens when an effect is applied while at its limit.
+    pub policy: StackLimitPolicy,
+}
+
+impl StackLimits {
+    /// A builder that sets the maximum number of stacks for an effect.
+    pub fn with_limit(mut self, name: impl Into<Name>, max: u8) -> Self {
+        self.limits.insert(name.into(), max);
+        self
+    }
+
+    /// A builder that overwrites the current policy with a new value.
+    pub fn with_policy(mut self, policy: StackLimitPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the maximum number of stacks for an effect, if it is limited.
+    pub fn get(&self, name: &Name) -> Option<u8> {
+        self.limits.get(name).copied()
+    }
+}
+
+/// Controls what happens when an effect is applied to an entity that is at its [`StackLimits`] limit for that effect.
+#[derive(Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(PartialEq, Debug, Default, Clone)]
+pub enum StackLimitPolicy {
+    /// The new effect won't be applied.
+    #[default]
+    Reject,
+    /// The new effect is still applied, but the number of stacks is capped at the limit.
+    ///
+    /// For [`Stack`](crate::EffectMode::Stack) effects, the oldest effect with the same name is despawned to make room.
+    /// For [`Merge`](crate::EffectMode::Merge) effects, the effect is merged as normal, and then its stacks are clamped.
+    Cap,
+}
@@ -0,0 +1,87 @@
+use crate::{EffectMergeRegistry, KeepEffect};
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::ReflectComponent;
+use bevy_ecs::prelude::{Component, Entity, EntityWorldMut, World};
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+
+pub(crate) struct MagnitudePlugin;
+
+impl Plugin for MagnitudePlugin {
+    fn build(&self, app: &mut App) {
+        app.world_mut()
+            .get_resource_or_init::<EffectMergeRegistry>()
+            .register::<Magnitude>(merge_effect_magnitude)
+            .register_replacement::<Magnitude>(keep_stronger_magnitude);
+    }
+}
+
+/// The strength of an effect, such as the percentage of a damage buff.
+/// When [merged](crate::EffectMode::Merge), the values are combined based on the [`MagnitudeMergeMode`].
+#[derive(Component, Reflect, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(Component, Default, PartialEq, Debug, Clone)]
+pub struct Magnitude {
+    /// The strength of the effect.
+    pub value: f32,
+    /// Controls the merge behaviour when an effect is [merged](crate::EffectMode::Merge).
+    pub mode: MagnitudeMergeMode,
+}
+
+impl Magnitude {
+    /// Creates a new magnitude with the default merge mode ([`Sum`](MagnitudeMergeMode::Sum)).
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            ..Self::default()
+        }
+    }
+
+    /// A builder that overwrites the current merge mode with a new value.
+    pub fn with_mode(mut self, mode: MagnitudeMergeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Merges the old magnitude (outgoing) into the new one (self).
+    /// Behaviour depends on the new magnitude's [`MagnitudeMergeMode`].
+    pub fn merge(&mut self, outgoing: &Self) {
+        match self.mode {
+            MagnitudeMergeMode::Sum => self.value += outgoing.value,
+            MagnitudeMergeMode::Max => self.value = self.value.max(outgoing.value),
+            MagnitudeMergeMode::Average => self.value = (self.value + outgoing.value) / 2.0,
+        }
+    }
+}
+
+/// Controls the merge behaviour of a [`Magnitude`] when its effect is [merged](crate::EffectMode::Merge).
+#[derive(Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(PartialEq, Debug, Default, Clone)]
+pub enum MagnitudeMergeMode {
+    /// The magnitudes will be added together.
+    #[default]
+    Sum,
+    /// The larger magnitude will be used.
+    Max,
+    /// The average of the old and new magnitude will be used.
+    /// Note that this is *not* the average of every application, as each merge only sees the previous result.
+    Average,
+}
+
+/// A [merge function](crate::EffectMergeFn) for the [`Magnitude`] component.
+pub fn merge_effect_magnitude(mut new: EntityWorldMut, outgoing: Entity) {
+    let outgoing = *new.world().get::<Magnitude>(outgoing).unwrap();
+    new.get_mut::<Magnitude>().unwrap().merge(&outgoing);
+}
+
+/// A [replacement function](crate::EffectReplaceFn) for the [`Magnitude`] component,
+/// which keeps the effect with the larger magnitude. The incoming effect wins ties.
+pub fn keep_stronger_magnitude(world: &World, old: Entity, incoming: Entity) -> KeepEffect {
+    let old = world.get::<Magnitude>(old).unwrap();
+    let incoming = world.get::<Magnitude>(incoming).unwrap();
+
+    if old.value > incoming.value {
+        KeepEffect::Old
+    } else {
+        KeepEffect::Incoming
+    }
+}
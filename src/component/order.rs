@@ -0,0 +1,24 @@
+use bevy_ecs::prelude::{Component, ReflectComponent, Resource, World};
+use bevy_reflect::Reflect;
+
+/// The order in which effects were applied, which increases every time an effect is applied.
+/// This can be used to sort effects by how recently they were applied, such as for a "newest first" UI.
+///
+/// When an effect is [inserted](crate::EffectMode::Insert) or [merged](crate::EffectMode::Merge)
+/// into an existing effect, it is given a new order, so it counts as the most recently applied effect.
+///
+/// This is added automatically when an effect is applied or [bound](crate::EffectCommandsExt::bind_effect).
+#[derive(Component, Reflect, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Copy, Clone)]
+#[reflect(Component, PartialEq, Hash, Debug, Clone)]
+pub struct EffectOrder(pub u64);
+
+/// The last [`EffectOrder`] that was given to an effect.
+#[derive(Resource, Default)]
+pub(crate) struct EffectOrderCounter(u64);
+
+/// Returns the order for the next applied effect.
+pub(crate) fn next_effect_order(world: &mut World) -> EffectOrder {
+    let mut counter = world.get_resource_or_init::<EffectOrderCounter>();
+    counter.0 += 1;
+    EffectOrder(counter.0)
+}
@@ -0,0 +1,22 @@
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+
+/// Whether an effect is beneficial or harmful to its target, such as for tinting buff icons green and debuffs red.
+///
+/// Polarity can be used to [filter](crate::EffectFilter::Polarity) effects, for example when
+/// [clearing effects](crate::EffectCommandsExt::clear_effects) or granting [immunity](crate::EffectImmunity).
+/// For more detailed categorization, use [`EffectTags`](crate::EffectTags).
+///
+/// It is normally set using [`EffectBundle::polarity`](crate::EffectBundle::polarity).
+#[derive(Component, Reflect, Eq, PartialEq, Hash, Debug, Default, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub enum EffectPolarity {
+    /// A beneficial effect.
+    Buff,
+    /// A harmful effect.
+    Debuff,
+    /// An effect that is neither beneficial nor harmful.
+    #[default]
+    Neutral,
+}
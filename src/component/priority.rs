@@ -0,0 +1,13 @@
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+
+/// The priority of an effect, which is used to decide which effect wins a conflict.
+/// It is normally set using [`EffectBundle::priority`](crate::EffectBundle::priority).
+///
+/// When an effect is reapplied using [`Insert`](crate::EffectMode::Insert) or [`Select`](crate::EffectMode::Select),
+/// it is only applied if its priority is greater than or equal to the existing effect's priority.
+/// Priority is also used when evicting effects using [`EffectLimitPolicy::EvictLowestPriority`](crate::EffectLimitPolicy::EvictLowestPriority).
+#[derive(Component, Reflect, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Copy, Clone)]
+#[reflect(Component, PartialEq, Hash, Debug, Default, Clone)]
+pub struct EffectPriority(pub i32);
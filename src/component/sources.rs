@@ -0,0 +1,155 @@
+use crate::EffectMergeRegistry;
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::ReflectComponent;
+use bevy_ecs::prelude::{Component, Entity, EntityWorldMut};
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+
+pub(crate) struct SourcesPlugin;
+
+impl Plugin for SourcesPlugin {
+    fn build(&self, app: &mut App) {
+        app.world_mut()
+            .get_resource_or_init::<EffectMergeRegistry>()
+            .register::<EffectSources>(merge_effect_sources)
+            .register::<StackContributions>(merge_stack_contributions);
+    }
+}
+
+/// The entities that are keeping an effect alive, such as several totems that each grant the same regeneration aura.
+///
+/// When [merged](crate::EffectMode::Merge), the sources are combined, with each unique source only counted once.
+/// Sources are removed using [`release_effect`](crate::EffectCommandsExt::release_effect),
+/// and the effect is despawned once its last source is released.
+///
+/// Unlike [`EffectStacks`](crate::EffectStacks), this is keyed by the source's identity,
+/// so a source that applies the effect repeatedly only needs to release it once.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// fn totem_aura(mut commands: Commands, totem: Entity, target: Entity) {
+///     commands.entity(target).with_effect(EffectBundle {
+///         name: Name::new("Regen"),
+///         mode: Some(EffectMode::Merge),
+///         bundle: EffectSources::new(totem),
+///         ..Default::default()
+///     });
+/// }
+///
+/// fn totem_out_of_range(mut commands: Commands, totem: Entity, target: Entity) {
+///     commands.entity(target).release_effect(totem);
+/// }
+/// ```
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectSources(pub Vec<Entity>);
+
+impl EffectSources {
+    /// Creates a new set of sources, containing a single source.
+    pub fn new(source: Entity) -> Self {
+        Self(vec![source])
+    }
+
+    /// Returns true if the entity is one of the sources.
+    pub fn contains(&self, source: Entity) -> bool {
+        self.0.contains(&source)
+    }
+
+    /// Adds a source, if it isn't already present.
+    pub fn add(&mut self, source: Entity) {
+        if !self.contains(source) {
+            self.0.push(source);
+        }
+    }
+
+    /// Removes a source, returning true if it was present.
+    pub fn remove(&mut self, source: Entity) -> bool {
+        let len = self.0.len();
+        self.0.retain(|other| *other != source);
+        self.0.len() != len
+    }
+
+    /// Merges the old sources (outgoing) into the new ones (self), keeping each unique source.
+    pub fn merge(&mut self, outgoing: &Self) {
+        for source in &outgoing.0 {
+            self.add(*source);
+        }
+    }
+}
+
+/// A [merge function](crate::EffectMergeFn) for the [`EffectSources`] component.
+pub fn merge_effect_sources(mut new: EntityWorldMut, outgoing: Entity) {
+    let outgoing = new.world().get::<EffectSources>(outgoing).unwrap().clone();
+    new.get_mut::<EffectSources>().unwrap().merge(&outgoing);
+}
+
+/// The entities that applied each stack of a [merge-mode](crate::EffectMode::Merge) effect, oldest first,
+/// such as for attributing the killing poison tick to the player who applied it.
+///
+/// When merged, the new contributions are appended to the old ones (rather than replacing them),
+/// so the effect keeps per-stack metadata while still being a single entity.
+/// Unlike [`EffectSources`], a source that applies the effect repeatedly is listed once per application.
+///
+/// Contributions aren't removed when stacks are [consumed](crate::EffectCommandsExt::consume_stacks) or capped.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// fn apply_poison(mut commands: Commands, attacker: Entity, target: Entity) {
+///     commands.entity(target).with_effect(EffectBundle {
+///         name: Name::new("Poison"),
+///         mode: Some(EffectMode::Merge),
+///         bundle: StackContributions::new(attacker),
+///         ..Default::default()
+///     });
+/// }
+///
+/// fn killing_blow(effects: Query<(&Effecting, &StackContributions)>) {
+///     for (target, contributions) in &effects {
+///         if let Some(attacker) = contributions.latest() {
+///             // Credit `attacker` if `target` dies...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct StackContributions(pub Vec<Entity>);
+
+impl StackContributions {
+    /// Creates a new list of contributions, containing a single source.
+    pub fn new(source: Entity) -> Self {
+        Self(vec![source])
+    }
+
+    /// Returns the source that contributed most recently.
+    pub fn latest(&self) -> Option<Entity> {
+        self.0.last().copied()
+    }
+
+    /// Returns the number of contributions from the given source.
+    pub fn count_from(&self, source: Entity) -> usize {
+        self.0.iter().filter(|other| **other == source).count()
+    }
+
+    /// Merges the old contributions (outgoing) into the new ones (self), keeping the old ones first.
+    pub fn merge(&mut self, outgoing: &Self) {
+        let new = std::mem::replace(&mut self.0, outgoing.0.clone());
+        self.0.extend(new);
+    }
+}
+
+/// A [merge function](crate::EffectMergeFn) for the [`StackContributions`] component, which appends the new contributions.
+pub fn merge_stack_contributions(mut new: EntityWorldMut, outgoing: Entity) {
+    let outgoing = new
+        .world()
+        .get::<StackContributions>(outgoing)
+        .unwrap()
+        .clone();
+    new.get_mut::<StackContributions>()
+        .unwrap()
+        .merge(&outgoing);
+}
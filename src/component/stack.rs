@@ -1,10 +1,13 @@
 use crate::EffectMergeRegistry;
 use bevy_app::{App, Plugin};
+use bevy_ecs::component::Mutable;
 use bevy_ecs::prelude::ReflectComponent;
-use bevy_ecs::prelude::{Component, Entity, EntityWorldMut};
+use bevy_ecs::prelude::{
+    Component, Entity, EntityEvent, EntityWorldMut, RelationshipTarget, World,
+};
 use bevy_reflect::Reflect;
 use bevy_reflect::prelude::ReflectDefault;
-use std::ops::{Add, AddAssign, Deref, DerefMut};
+use std::ops::{Add, AddAssign, Deref, DerefMut, Mul, MulAssign, Sub, SubAssign};
 
 pub(crate) struct StackPlugin;
 
@@ -12,77 +15,308 @@ impl Plugin for StackPlugin {
     fn build(&self, app: &mut App) {
         app.world_mut()
             .get_resource_or_init::<EffectMergeRegistry>()
-            .register::<EffectStacks>(merge_effect_stacks);
+            .register::<EffectStacks>(merge_effect_stacks)
+            .register::<EffectStacksU32>(merge_effect_stacks_u32);
     }
 }
 
 /// Tracks the number of times a [merge-mode](crate::EffectMode::Merge) effect has been applied to an entity.
+///
+/// This is added to merge-mode effects automatically,
+/// unless disabled using [`AlchemySettings::track_merge_stacks`](crate::AlchemySettings::track_merge_stacks).
+/// To apply more than one stack at once, set [`EffectBundle::stacks`](crate::EffectBundle::stacks).
+///
+/// Stacks are capped at 255 ([`u8::MAX`]). Adding stacks saturates at this limit, rather than overflowing,
+/// and subtracting stacks saturates at zero.
+/// For effects that need more stacks, use [`EffectStacksU32`] instead.
 #[derive(Component, Reflect, Eq, PartialEq, Ord, PartialOrd, Debug, Copy, Clone)]
 #[reflect(Component, Default, PartialEq, Debug, Clone)]
 pub struct EffectStacks(pub u8);
 
-impl Default for EffectStacks {
-    fn default() -> Self {
-        Self(1)
-    }
+/// Tracks the number of times a [merge-mode](crate::EffectMode::Merge) effect has been applied to an entity,
+/// for games that need more than the 255 stacks supported by [`EffectStacks`].
+///
+/// Unlike [`EffectStacks`], this isn't added automatically, so it must be included in the effect's bundle.
+/// Stacks are capped at [`u32::MAX`]. Adding stacks saturates at this limit, rather than overflowing.
+#[derive(Component, Reflect, Eq, PartialEq, Ord, PartialOrd, Debug, Copy, Clone)]
+#[reflect(Component, Default, PartialEq, Debug, Clone)]
+pub struct EffectStacksU32(pub u32);
+
+/// Controls the merge behaviour of [`EffectStacks`] (and [`EffectStacksU32`]) when an effect is [merged](crate::EffectMode::Merge).
+///
+/// This is an optional component, which should be included in the effect's bundle.
+/// Effects without it use [`Sum`](StackMergeMode::Sum).
+///
+/// For other behaviour, a custom [merge function](crate::EffectMergeFn) can be registered for the stack component,
+/// which replaces [`merge_effect_stacks`].
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub enum StackMergeMode {
+    /// The stack counts will be added together.
+    #[default]
+    Sum,
+    /// The larger stack count will be used.
+    Max,
+    /// The new effect's stack count will be used, ignoring the old one.
+    /// Combined with the default stack count of one, reapplying refreshes the effect without adding a stack.
+    Replace,
+    /// The old effect's stack count will be used, ignoring the new one.
+    Keep,
+    /// The stack counts will be multiplied together, such as for a compounding slow.
+    ///
+    /// This grows quickly, so it saturates at the maximum stack count (255 for [`EffectStacks`]),
+    /// rather than overflowing. Consider using [`EffectStacksU32`] instead.
+    Product,
 }
 
-impl Deref for EffectStacks {
-    type Target = u8;
+/// A meta-effect that multiplies the stacks gained by other effects on the same target when they are [merged](crate::EffectMode::Merge).
+/// For example, a curse that doubles the stacks gained by poison effects while it is active.
+///
+/// This is added to the modifying effect's bundle, and only affects effects matched by its [filter](crate::EffectFilter).
+/// The incoming effect's stacks are multiplied before [`merge_effect_stacks`] combines them with the old stacks,
+/// so it only applies when an effect is reapplied (not when it is first spawned).
+/// Multiple matching modifiers are multiplied together.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Clone)]
+#[reflect(Component, PartialEq, Debug, Clone)]
+pub struct StackGainModifier {
+    /// The effects whose stack gain is modified.
+    pub filter: crate::EffectFilter,
+    /// The amount to multiply the stack gain by.
+    pub multiplier: u8,
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+impl StackGainModifier {
+    /// Creates a new modifier, which multiplies the stack gain of matching effects.
+    pub fn new(filter: crate::EffectFilter, multiplier: u8) -> Self {
+        Self { filter, multiplier }
     }
 }
 
-impl DerefMut for EffectStacks {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
+/// Returns the combined multiplier from the [`StackGainModifier`]s on the effect's target that match the effect.
+pub(crate) fn stack_gain_multiplier(world: &World, effect: Entity) -> u8 {
+    let Some(target) = world.get::<crate::Effecting>(effect) else {
+        return 1;
+    };
+    let Some(effects) = world.get::<crate::EffectedBy>(target.0) else {
+        return 1;
+    };
+
+    effects
+        .iter()
+        .filter(|other| *other != effect)
+        .filter_map(|other| world.get::<StackGainModifier>(other))
+        .filter(|modifier| modifier.filter.matches_entity(world.entity(effect)))
+        .fold(1, |multiplier, modifier| {
+            multiplier.saturating_mul(modifier.multiplier)
+        })
 }
 
-impl Add for EffectStacks {
-    type Output = Self;
+macro_rules! impl_effect_stacks {
+    ($ident:ident, $count:ty) => {
+        impl Default for $ident {
+            fn default() -> Self {
+                Self(1)
+            }
+        }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
-    }
+        impl Deref for $ident {
+            type Target = $count;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl DerefMut for $ident {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl Add for $ident {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl AddAssign for $ident {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 = self.0.saturating_add(rhs.0)
+            }
+        }
+
+        impl Add<$count> for $ident {
+            type Output = Self;
+
+            fn add(self, rhs: $count) -> Self::Output {
+                Self(self.0.saturating_add(rhs))
+            }
+        }
+
+        impl AddAssign<$count> for $ident {
+            fn add_assign(&mut self, rhs: $count) {
+                self.0 = self.0.saturating_add(rhs)
+            }
+        }
+
+        impl Sub for $ident {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl SubAssign for $ident {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 = self.0.saturating_sub(rhs.0)
+            }
+        }
+
+        impl Sub<$count> for $ident {
+            type Output = Self;
+
+            fn sub(self, rhs: $count) -> Self::Output {
+                Self(self.0.saturating_sub(rhs))
+            }
+        }
+
+        impl SubAssign<$count> for $ident {
+            fn sub_assign(&mut self, rhs: $count) {
+                self.0 = self.0.saturating_sub(rhs)
+            }
+        }
+
+        impl Mul for $ident {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self(self.0.saturating_mul(rhs.0))
+            }
+        }
+
+        impl MulAssign for $ident {
+            fn mul_assign(&mut self, rhs: Self) {
+                self.0 = self.0.saturating_mul(rhs.0)
+            }
+        }
+
+        impl Mul<$count> for $ident {
+            type Output = Self;
+
+            fn mul(self, rhs: $count) -> Self::Output {
+                Self(self.0.saturating_mul(rhs))
+            }
+        }
+
+        impl MulAssign<$count> for $ident {
+            fn mul_assign(&mut self, rhs: $count) {
+                self.0 = self.0.saturating_mul(rhs)
+            }
+        }
+
+        impl From<$count> for $ident {
+            fn from(value: $count) -> Self {
+                $ident(value)
+            }
+        }
+
+        impl From<$ident> for $count {
+            fn from(value: $ident) -> Self {
+                value.0
+            }
+        }
+    };
 }
 
-impl AddAssign for EffectStacks {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0
-    }
+impl_effect_stacks!(EffectStacks, u8);
+impl_effect_stacks!(EffectStacksU32, u32);
+
+/// An event that is triggered on an effect when its [`EffectStacks`] count changes,
+/// such as when it gets [merged](merge_effect_stacks).
+///
+/// For [`EffectStacksU32`], this is triggered as `StacksChanged<u32>`.
+#[derive(EntityEvent, Eq, PartialEq, Debug, Copy, Clone)]
+pub struct StacksChanged<T: Send + Sync + 'static = u8> {
+    /// The effect whose stack count changed.
+    #[event_target]
+    pub effect: Entity,
+    /// The stack count before the change.
+    pub old: T,
+    /// The stack count after the change.
+    pub new: T,
+}
+
+/// A [merge function](crate::EffectMergeFn) for the [`EffectStacks`] component.
+/// Behaviour depends on the effect's [`StackMergeMode`].
+/// Triggers [`StacksChanged`] if the stack count changes.
+pub fn merge_effect_stacks(new: EntityWorldMut, outgoing: Entity) {
+    merge_stacks::<EffectStacks, u8>(new, outgoing);
+}
+
+/// A [merge function](crate::EffectMergeFn) for the [`EffectStacksU32`] component.
+/// Behaviour depends on the effect's [`StackMergeMode`].
+/// Triggers [`StacksChanged<u32>`](StacksChanged) if the stack count changes.
+pub fn merge_effect_stacks_u32(new: EntityWorldMut, outgoing: Entity) {
+    merge_stacks::<EffectStacksU32, u32>(new, outgoing);
 }
 
-impl Add<u8> for EffectStacks {
-    type Output = Self;
+fn merge_stacks<S, C>(mut new: EntityWorldMut, outgoing: Entity)
+where
+    S: Component<Mutability = Mutable> + Copy + Into<C> + From<C> + AddAssign<C> + MulAssign<C>,
+    C: Ord + Copy + From<u8> + Send + Sync + 'static,
+{
+    let old: C = (*new.world().get::<S>(outgoing).unwrap()).into();
+    let mode = new.get::<StackMergeMode>().copied().unwrap_or_default();
+    let multiplier = stack_gain_multiplier(new.world(), new.id());
 
-    fn add(self, rhs: u8) -> Self::Output {
-        Self(self.0 + rhs)
+    let mut stacks = new.get_mut::<S>().unwrap();
+    if multiplier != 1 {
+        *stacks *= C::from(multiplier);
     }
-}
 
-impl AddAssign<u8> for EffectStacks {
-    fn add_assign(&mut self, rhs: u8) {
-        self.0 += rhs
+    match mode {
+        StackMergeMode::Sum => *stacks += old,
+        StackMergeMode::Max => *stacks = S::from(old.max((*stacks).into())),
+        StackMergeMode::Replace => {}
+        StackMergeMode::Keep => *stacks = S::from(old),
+        StackMergeMode::Product => *stacks *= old,
     }
-}
+    let changed: C = (*stacks).into();
 
-impl From<u8> for EffectStacks {
-    fn from(value: u8) -> Self {
-        EffectStacks(value)
+    if old != changed {
+        new.trigger(|effect| StacksChanged {
+            effect,
+            old,
+            new: changed,
+        });
     }
 }
 
-impl From<EffectStacks> for u8 {
-    fn from(value: EffectStacks) -> Self {
-        value.0
+/// Removes up to `amount` stacks from an effect, despawning it if no stacks remain.
+/// Triggers [`StacksChanged`] if the stack count changes. Returns the number of stacks removed.
+pub(crate) fn consume_stacks<S, C>(world: &mut World, effect: Entity, amount: C) -> C
+where
+    S: Component<Mutability = Mutable> + Copy + Into<C> + SubAssign<C>,
+    C: Ord + Default + Copy + Send + Sync + 'static,
+{
+    let mut entity = world.entity_mut(effect);
+    let mut stacks = entity.get_mut::<S>().unwrap();
+
+    let old: C = (*stacks).into();
+    let consumed = old.min(amount);
+    *stacks -= consumed;
+    let new: C = (*stacks).into();
+
+    if old != new {
+        entity.trigger(|effect| StacksChanged { effect, old, new });
     }
-}
 
-/// A [merge function](crate::EffectMergeFn) for the [`EffectStacks`] component.
-pub fn merge_effect_stacks(mut new: EntityWorldMut, outgoing: Entity) {
-    let outgoing = *new.world().get::<EffectStacks>(outgoing).unwrap();
-    *new.get_mut::<EffectStacks>().unwrap() += outgoing.0;
+    if new == C::default() {
+        world.despawn(effect);
+    }
+
+    consumed
 }
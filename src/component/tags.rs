@@ -0,0 +1,39 @@
+use bevy_ecs::prelude::{Component, ReflectComponent};
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+use std::borrow::Cow;
+
+/// A set of categories (such as `"Buff"`, `"Debuff"` or `"Magic"`) that an effect belongs to.
+///
+/// Tags can be used to [filter](crate::EffectFilter) effects, for example when
+/// [clearing effects](crate::EffectCommandsExt::clear_effects) or granting [immunity](crate::EffectImmunity).
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectTags(pub Vec<Cow<'static, str>>);
+
+impl EffectTags {
+    /// Creates a new set of tags.
+    pub fn new<T: Into<Cow<'static, str>>>(tags: impl IntoIterator<Item = T>) -> Self {
+        Self(tags.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns true if this set contains the tag.
+    pub fn contains(&self, tag: &str) -> bool {
+        self.0.iter().any(|t| t == tag)
+    }
+
+    /// A builder that adds a tag to the set, if it isn't already present.
+    pub fn with(mut self, tag: impl Into<Cow<'static, str>>) -> Self {
+        let tag = tag.into();
+        if !self.contains(&tag) {
+            self.0.push(tag);
+        }
+        self
+    }
+}
+
+impl<T: Into<Cow<'static, str>>> FromIterator<T> for EffectTags {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
@@ -1,23 +1,53 @@
-use crate::ReflectComponent;
-use crate::registry::EffectMergeRegistry;
+use super::clock::DefaultClock;
+use crate::registry::{EffectExpireFn, EffectExpireRegistry, EffectMergeRegistry};
+use crate::{AlchemySet, Effecting, ReflectComponent};
 use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::change_detection::DetectChanges;
 use bevy_ecs::component::Mutable;
-use bevy_ecs::prelude::{Commands, Component, Entity, Query, Res};
+use bevy_ecs::prelude::{
+    Commands, Component, Entity, Has, Insert, On, Query, Ref, Res, With, Without, World,
+};
+use bevy_ecs::query::QueryFilter;
 use bevy_ecs::schedule::IntoScheduleConfigs;
 use bevy_ecs::world::EntityWorldMut;
 use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
 use bevy_time::{Time, Timer, TimerMode};
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 pub(crate) struct TimerPlugin;
 
 impl Plugin for TimerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, (despawn_finished_lifetimes, tick_delay).chain());
+        app.init_resource::<EffectExpireRegistry>().add_systems(
+            PreUpdate,
+            (
+                (
+                    tick_lifetime::<(), DefaultClock>,
+                    tick_delay::<(), DefaultClock>,
+                    tick_age::<(), DefaultClock>,
+                    tick_activation_delay::<(), DefaultClock>,
+                )
+                    .in_set(AlchemySet::TickTimers),
+                // Only lifetimes need to be ticked before checking if they've finished.
+                // Delays and ages touch disjoint components, so they can run in parallel with despawning.
+                despawn_finished_lifetimes
+                    .after(tick_lifetime::<(), DefaultClock>)
+                    .in_set(AlchemySet::DespawnExpired),
+                despawn_finished_tick_counts
+                    .after(tick_delay::<(), DefaultClock>)
+                    .in_set(AlchemySet::DespawnExpired),
+            ),
+        );
         app.world_mut()
             .get_resource_or_init::<EffectMergeRegistry>()
             .register::<Lifetime>(merge_effect_timer::<Lifetime>)
-            .register::<Delay>(merge_effect_timer::<Delay>);
+            .register::<Delay>(merge_effect_timer::<Delay>)
+            .register::<EffectAge>(merge_effect_age)
+            .register::<ActivationDelay>(merge_activation_delay);
+        app.add_observer(deactivate_on_insert);
     }
 }
 
@@ -64,8 +94,9 @@ pub trait EffectTimer: Sized {
             TimerMergeMode::Fraction => {
                 let fraction = incoming.get_timer().fraction();
                 let duration = self.get_timer().duration().as_secs_f32();
-                self.get_timer_mut()
-                    .set_elapsed(Duration::from_secs_f32(fraction * duration));
+                let elapsed =
+                    Duration::try_from_secs_f32(fraction * duration).unwrap_or(Duration::MAX);
+                self.get_timer_mut().set_elapsed(elapsed);
             }
             TimerMergeMode::Max => {
                 let old = incoming.get_timer().remaining_secs();
@@ -76,9 +107,20 @@ pub trait EffectTimer: Sized {
                 }
             }
             TimerMergeMode::Sum => {
-                let duration = incoming.get_timer().duration() + self.get_timer().duration();
+                let duration = incoming
+                    .get_timer()
+                    .duration()
+                    .saturating_add(self.get_timer().duration());
                 self.get_timer_mut().set_duration(duration);
             }
+            TimerMergeMode::Extend => {
+                let mut timer = incoming.get_timer().clone();
+                let duration = timer
+                    .duration()
+                    .saturating_add(self.get_timer().remaining());
+                timer.set_duration(duration);
+                *self.get_timer_mut() = timer;
+            }
         }
     }
 }
@@ -130,6 +172,86 @@ pub struct Lifetime {
 
 impl_effect_timer!(Lifetime, TimerMode::Once);
 
+impl Lifetime {
+    /// Creates a lifetime that never finishes, so the effect is never despawned automatically.
+    /// The elapsed time is still tracked, which can be used to show how long the effect has been active.
+    pub fn permanent() -> Self {
+        Self::new(Duration::MAX)
+    }
+
+    /// Returns true if this lifetime was created using [`permanent`](Self::permanent).
+    pub fn is_permanent(&self) -> bool {
+        self.timer.duration() == Duration::MAX
+    }
+
+    /// Creates a new lifetime with a random duration within `seconds ± variance`, in seconds.
+    /// This prevents effects that are applied at the same time from all expiring on the same frame.
+    ///
+    /// Like [`DelayJitter`], this doesn't depend on `rand`. Instead, each call advances a global deterministic generator,
+    /// so the same sequence of durations is picked every time the program runs.
+    /// For a fixed duration (such as in tests), use [`from_seconds_varied_seeded`](Self::from_seconds_varied_seeded).
+    pub fn from_seconds_varied(seconds: f32, variance: f32) -> Self {
+        static SEED: AtomicU64 = AtomicU64::new(0);
+        let seed = SEED.fetch_add(SPLIT_MIX_INCREMENT, Ordering::Relaxed);
+        Self::from_seconds_varied_seeded(seconds, variance, seed)
+    }
+
+    /// Creates a new lifetime with a random duration within `seconds ± variance`, in seconds,
+    /// which is picked using the given `seed`. The same seed always picks the same duration.
+    pub fn from_seconds_varied_seeded(seconds: f32, variance: f32, seed: u64) -> Self {
+        let base = Duration::from_secs_f32(seconds.max(0.0));
+        let variance = Duration::from_secs_f32(variance.abs());
+        Self::new(random_duration(
+            base.saturating_sub(variance),
+            base.saturating_add(variance),
+            seed,
+        ))
+    }
+
+    /// Adds time to the lifetime, by increasing its duration without changing the elapsed time.
+    /// If `max` is set, the remaining time isn't extended past it (but is never reduced).
+    ///
+    /// Permanent lifetimes are unaffected.
+    pub fn extend(&mut self, amount: Duration, max: Option<Duration>) {
+        if self.is_permanent() {
+            return;
+        }
+
+        let remaining = self.timer.remaining();
+        let mut extended = remaining.saturating_add(amount);
+        if let Some(max) = max {
+            extended = extended.min(max.max(remaining));
+        }
+
+        let duration = self.timer.duration().saturating_add(extended - remaining);
+        self.timer.set_duration(duration);
+    }
+
+    /// Advances the lifetime by `delta`, the same as the timer systems do each frame.
+    ///
+    /// This doesn't depend on the ECS, so it can be used to predict a lifetime between network updates,
+    /// such as for a smooth countdown on a client. Use [`reconcile`](Self::reconcile) when the authoritative value arrives.
+    pub fn predict(&mut self, delta: Duration) {
+        self.timer.tick(delta);
+    }
+
+    /// Sets the remaining time to an authoritative value (such as from an [`EffectSnapshot`](crate::EffectSnapshot)),
+    /// correcting any drift from [predicting](Self::predict) it.
+    ///
+    /// The elapsed time is changed, rather than the duration, so the [fraction](Timer::fraction) elapsed stays meaningful.
+    /// If the remaining time is longer than the duration (or the lifetime is permanent), the duration is changed to match it.
+    /// A lifetime that had finished will tick again, and the paused state is kept.
+    pub fn reconcile(&mut self, remaining: Duration) {
+        if self.is_permanent() || self.timer.duration() < remaining {
+            self.timer.set_duration(remaining);
+        }
+
+        let elapsed = self.timer.duration() - remaining;
+        self.timer.reset();
+        self.timer.set_elapsed(elapsed);
+    }
+}
+
 impl Default for Lifetime {
     fn default() -> Self {
         Self {
@@ -139,6 +261,108 @@ impl Default for Lifetime {
     }
 }
 
+/// Converts a delay into a lifetime, such as for an effect that transitions from waiting to counting down.
+/// The elapsed time, duration, paused state and [`TimerMergeMode`] are kept, but any [jitter](Delay::jitter) is dropped.
+impl From<Delay> for Lifetime {
+    fn from(delay: Delay) -> Self {
+        convert_timer(&delay.timer, delay.mode)
+    }
+}
+
+/// Opts an effect into lingering for one frame after its [`Lifetime`] finishes, instead of being despawned immediately.
+///
+/// While lingering, the effect is marked with [`EffectExpiring`] and its [`Delay`] stops ticking.
+/// It is then despawned the following frame.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct LingerOnExpire;
+
+/// A marker added to a [lingering](LingerOnExpire) effect when its [`Lifetime`] finishes.
+/// The effect will be despawned the following frame, unless its lifetime is refreshed first (such as by reapplying it),
+/// in which case the marker is removed.
+///
+/// This allows systems to react to an effect ending, such as by playing an animation.
+/// Systems that apply the effect's gameplay contribution should use a `Without<EffectExpiring>` filter.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[component(storage = "SparseSet")]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectExpiring;
+
+/// Delays an effect from becoming active after it is applied, such as a buff that activates after a two-second warmup.
+///
+/// While the timer is ticking, the effect is marked with [`EffectInactive`], and becomes active once the timer finishes.
+/// Its [`Delay`] doesn't tick while inactive, and by default neither does its [`Lifetime`],
+/// so the lifetime only starts counting down once the effect is active.
+/// This can be changed using [`with_lifetime_ticking`](Self::with_lifetime_ticking).
+///
+/// When [merged](crate::EffectMode::Merge), the old activation progress is kept, so reapplying doesn't restart the warmup.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct ActivationDelay {
+    /// Tracks the time until the effect becomes active.
+    pub timer: Timer,
+    /// Whether the effect's [`Lifetime`] ticks while it is inactive.
+    pub tick_lifetime: bool,
+}
+
+impl ActivationDelay {
+    /// Creates a new activation delay from a duration.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            tick_lifetime: false,
+        }
+    }
+
+    /// Creates a new activation delay from a duration, in seconds.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(seconds))
+    }
+
+    /// A builder that makes the effect's [`Lifetime`] tick while it is inactive,
+    /// so the warmup counts towards the effect's duration.
+    pub fn with_lifetime_ticking(mut self) -> Self {
+        self.tick_lifetime = true;
+        self
+    }
+}
+
+/// A [merge function](crate::EffectMergeFn) for [`ActivationDelay`], which keeps the old activation progress.
+pub fn merge_activation_delay(mut new: EntityWorldMut, outgoing: Entity) {
+    let outgoing = new
+        .world()
+        .get::<ActivationDelay>(outgoing)
+        .unwrap()
+        .timer
+        .clone();
+    let finished = outgoing.is_finished();
+    new.get_mut::<ActivationDelay>().unwrap().timer = outgoing;
+
+    // The incoming delay marked the effect as inactive when it was inserted.
+    if finished {
+        new.remove::<EffectInactive>();
+    }
+}
+
+/// A marker added to an effect while its [`ActivationDelay`] is ticking.
+///
+/// Systems that apply the effect's gameplay contribution should use a `Without<EffectInactive>` filter.
+/// [Registered apply functions](crate::EffectApplyRegistry) aren't run for inactive effects.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[component(storage = "SparseSet")]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectInactive;
+
+/// A marker that pauses the timers ([`Lifetime`], [`Delay`] and [`EffectAge`]) of every effect applied to this entity,
+/// such as while it is in stasis. Removing the marker resumes them.
+///
+/// Individual timers can also be [paused](Timer::pause) directly.
+/// A timer only ticks if neither it nor its target is paused,
+/// so removing this marker won't resume timers that were paused individually.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectsPaused;
+
 /// A repeating timer used for the delay between effect applications.
 #[derive(Component, Reflect, Eq, PartialEq, Debug, Clone)]
 #[reflect(Component, PartialEq, Debug, Clone)]
@@ -147,6 +371,8 @@ pub struct Delay {
     pub timer: Timer,
     /// Controls the merge behaviour when an effect is [merged](crate::EffectMode::Merge).
     pub mode: TimerMergeMode,
+    /// Randomizes the interval each time the timer repeats. See [`with_jitter`](Self::with_jitter).
+    pub jitter: Option<DelayJitter>,
 }
 
 impl_effect_timer!(Delay, TimerMode::Repeating);
@@ -154,11 +380,80 @@ impl_effect_timer!(Delay, TimerMode::Repeating);
 impl Delay {
     /// Makes the timer [almost finished](Timer::almost_finish), leaving 1ns of remaining time.
     /// This allows effects to trigger immediately when applied.
+    ///
+    /// The effect still triggers on the first tick (during the next [`AlchemySet::TickTimers`]).
+    /// To trigger on the same frame the effect is applied, use [`from_seconds_immediate`](Self::from_seconds_immediate).
     #[doc(alias = "trigger_on_start", alias = "almost_finish")]
     pub fn trigger_immediately(mut self) -> Self {
         self.timer.almost_finish();
         self
     }
+
+    /// Creates a new delay that is already [finished](Timer::is_finished), in seconds.
+    /// This allows effects to trigger on the same frame they are applied, rather than after the first tick.
+    /// The delay then repeats as normal.
+    ///
+    /// When [merged](crate::EffectMode::Merge), whether the effect triggers again depends on the [`TimerMergeMode`].
+    /// [`Replace`](TimerMergeMode::Replace), [`Fraction`](TimerMergeMode::Fraction) and [`Sum`](TimerMergeMode::Sum)
+    /// keep the new timer's finished state, so the effect triggers again on every reapplication.
+    /// [`Keep`](TimerMergeMode::Keep) and [`Extend`](TimerMergeMode::Extend) use the old timer, so they don't.
+    /// [`Max`](TimerMergeMode::Max) uses whichever timer has more time remaining, which is the old one unless it has also finished,
+    /// so it normally doesn't either.
+    #[doc(alias = "burst")]
+    pub fn from_seconds_immediate(seconds: f32) -> Self {
+        let mut delay = Self::from_seconds(seconds);
+        delay.timer.finish();
+        delay
+    }
+
+    /// Pre-elapses the timer by `offset`, wrapping around if it is longer than the delay's duration.
+    /// This shifts when the effect triggers, so that effects applied at the same time don't all trigger on the same frame.
+    ///
+    /// For example, a random offset between zero and the duration spreads triggers out evenly.
+    ///
+    /// When [merged](crate::EffectMode::Merge), whether the offset is kept depends on the [`TimerMergeMode`].
+    /// [`Replace`](TimerMergeMode::Replace) and [`Sum`](TimerMergeMode::Sum) keep the new timer's elapsed time, so use the new offset.
+    /// [`Keep`](TimerMergeMode::Keep), [`Fraction`](TimerMergeMode::Fraction) and [`Extend`](TimerMergeMode::Extend)
+    /// continue from the old timer, so the new offset is ignored.
+    /// [`Max`](TimerMergeMode::Max) uses whichever timer has more time remaining, so the new offset is only used if the new timer has more.
+    #[doc(alias = "phase", alias = "stagger")]
+    pub fn with_offset(mut self, offset: Duration) -> Self {
+        let duration = self.timer.duration().as_nanos();
+
+        if duration > 0 {
+            let offset = (offset.as_nanos() % duration) as u64;
+            self.timer.set_elapsed(Duration::from_nanos(offset));
+        }
+
+        self
+    }
+
+    /// Returns the number of times the delay triggered during the last tick.
+    ///
+    /// On long frames (such as a hitch), a delay can trigger more than once, so systems that apply the effect
+    /// (such as damage over time) should apply it this many times, rather than checking [`is_finished`](Timer::is_finished).
+    /// If the effect has a [`TickCount`], this never exceeds the remaining ticks.
+    #[doc(alias = "times_finished_this_tick")]
+    pub fn ticks_this_frame(&self) -> u32 {
+        self.timer.times_finished_this_tick()
+    }
+
+    /// Randomizes the interval each time the delay repeats, picking a new duration within `range`.
+    /// The first interval uses the delay's original duration.
+    ///
+    /// For example, a delay with a jitter of `0.8..=1.2` seconds triggers roughly once per second,
+    /// but never at perfectly even intervals. See [`DelayJitter`] for how the randomness is generated.
+    pub fn with_jitter(mut self, range: RangeInclusive<Duration>) -> Self {
+        self.jitter = Some(DelayJitter::new(*range.start(), *range.end()));
+        self
+    }
+
+    /// Creates a new delay, in seconds, which is pre-elapsed by `offset` seconds.
+    /// See [`with_offset`](Self::with_offset) for more details.
+    pub fn from_seconds_offset(seconds: f32, offset: f32) -> Self {
+        Self::from_seconds(seconds)
+            .with_offset(Duration::try_from_secs_f32(offset).unwrap_or_default())
+    }
 }
 
 impl Default for Delay {
@@ -166,8 +461,242 @@ impl Default for Delay {
         Self {
             timer: Timer::default(),
             mode: TimerMergeMode::Fraction,
+            jitter: None,
+        }
+    }
+}
+
+/// Converts a lifetime into a delay, which repeats with the lifetime's duration.
+/// The elapsed time, paused state and [`TimerMergeMode`] are kept.
+impl From<Lifetime> for Delay {
+    fn from(lifetime: Lifetime) -> Self {
+        convert_timer(&lifetime.timer, lifetime.mode)
+    }
+}
+
+/// Copies a timer into a different kind of [`EffectTimer`], switching it to that kind's [`TimerMode`].
+fn convert_timer<T: EffectTimer>(timer: &Timer, mode: TimerMergeMode) -> T {
+    let mut converted = T::new(timer.duration()).with_mode(mode);
+    let timer_mode = converted.get_timer().mode();
+
+    let converted_timer = converted.get_timer_mut();
+    *converted_timer = timer.clone();
+    converted_timer.set_mode(timer_mode);
+
+    converted
+}
+
+/// Limits the number of times an effect's [`Delay`] can trigger, after which the effect is despawned.
+///
+/// This is useful for effects that are designed around a number of hits (such as "deal damage 5 times"),
+/// rather than a duration. The final tick is still visible for the frame it happens on,
+/// and the effect is despawned at the start of the next frame.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Clone)]
+pub struct TickCount {
+    /// The number of times the delay can still trigger.
+    pub remaining: u32,
+}
+
+impl TickCount {
+    /// Creates a new tick count, which allows the delay to trigger `ticks` times.
+    pub fn new(ticks: u32) -> Self {
+        Self { remaining: ticks }
+    }
+}
+
+/// Scales the strength of an effect based on how much of its [`Lifetime`] has elapsed,
+/// such as a curse that deals more damage the longer it has been active.
+///
+/// This doesn't do anything on its own. Systems that apply the effect should multiply its contribution by [`multiplier`](Self::multiplier).
+/// With the `immediate_stats` feature, this is used by `Decaying<T>`.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct LifetimeScaling {
+    /// Maps the fraction of the lifetime that has elapsed to a multiplier.
+    pub curve: ScalingCurve,
+}
+
+impl LifetimeScaling {
+    /// Creates a new scaling that uses the given curve.
+    pub fn new(curve: ScalingCurve) -> Self {
+        Self { curve }
+    }
+
+    /// Returns the multiplier for the lifetime's current [fraction](Timer::fraction) elapsed.
+    pub fn multiplier(&self, lifetime: &Lifetime) -> f32 {
+        self.curve.evaluate(lifetime.timer.fraction())
+    }
+}
+
+/// A curve that maps the fraction of an effect's [`Lifetime`] that has elapsed (between zero and one) to a multiplier.
+/// See [`LifetimeScaling`].
+#[derive(Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(PartialEq, Debug, Default, Clone)]
+pub enum ScalingCurve {
+    /// Starts at full strength and decreases linearly to zero (`1 - t`).
+    #[default]
+    LinearDown,
+    /// Starts at zero and increases linearly to full strength (`t`).
+    LinearUp,
+    /// Starts at zero and increases slowly at first, then quickly (`t²`).
+    EaseIn,
+    /// Starts at zero and increases quickly at first, then slowly (`1 - (1 - t)²`).
+    EaseOut,
+}
+
+impl ScalingCurve {
+    /// Returns the multiplier for the given fraction elapsed, which is clamped between zero and one.
+    pub fn evaluate(&self, fraction: f32) -> f32 {
+        let t = fraction.clamp(0.0, 1.0);
+
+        match self {
+            ScalingCurve::LinearDown => 1.0 - t,
+            ScalingCurve::LinearUp => t,
+            ScalingCurve::EaseIn => t * t,
+            ScalingCurve::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+        }
+    }
+}
+
+/// Returns an effect's contribution to a stat, scaled by both its number of stacks and its [`Lifetime`].
+/// This is `value * stacks * multiplier`, where the multiplier comes from the [`LifetimeScaling`]
+/// (defaulting to [`LinearDown`](ScalingCurve::LinearDown), which decays to zero).
+///
+/// Stacks of a [merged](crate::EffectMode::Merge) effect share a single lifetime, so they all decay together.
+/// For stacks that decay independently, use [stack-mode](crate::EffectMode::Stack) effects instead,
+/// and sum the contribution of each effect (with `stacks` set to one), since each has its own lifetime.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component)]
+/// struct Speed(f32);
+///
+/// /// Each stack adds 10 speed, which decays over the effect's lifetime.
+/// #[derive(Component)]
+/// struct Haste;
+///
+/// fn apply_haste(
+///     effects: Query<(&Effecting, &Lifetime, Option<&EffectStacks>, Option<&LifetimeScaling>), With<Haste>>,
+///     mut targets: Query<&mut Speed>,
+/// ) {
+///     for (target, lifetime, stacks, scaling) in &effects {
+///         let Ok(mut speed) = targets.get_mut(target.0) else {
+///             continue;
+///         };
+///
+///         let stacks = stacks.map_or(1, |stacks| stacks.0.into());
+///         speed.0 += scaled_by_stacks_and_lifetime(10.0, stacks, lifetime, scaling);
+///     }
+/// }
+/// ```
+pub fn scaled_by_stacks_and_lifetime(
+    value: f32,
+    stacks: u32,
+    lifetime: &Lifetime,
+    scaling: Option<&LifetimeScaling>,
+) -> f32 {
+    let scaling = scaling.copied().unwrap_or_default();
+    value * stacks as f32 * scaling.multiplier(lifetime)
+}
+
+/// Randomizes the interval of a [`Delay`] each time it repeats, so that ticks don't land at perfectly even intervals.
+///
+/// This doesn't depend on `rand` or an RNG resource. Instead, each jitter has a small deterministic generator,
+/// which is mixed with the effect's entity so that effects applied at the same time don't share a sequence.
+/// Games that need control over the randomness can set the timer's [duration](Timer::set_duration) directly.
+#[derive(Reflect, Eq, PartialEq, Debug, Clone)]
+#[reflect(PartialEq, Debug, Clone)]
+pub struct DelayJitter {
+    /// The shortest possible interval.
+    pub min: Duration,
+    /// The longest possible interval.
+    pub max: Duration,
+    state: u64,
+}
+
+impl DelayJitter {
+    /// Creates a new jitter, which picks intervals between `min` and `max` (inclusive).
+    pub fn new(min: Duration, max: Duration) -> Self {
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+            state: 0,
         }
     }
+
+    /// Picks the next interval, advancing the generator.
+    fn next_interval(&mut self, entity: Entity) -> Duration {
+        // Seeded by the entity.
+        self.state = self.state.wrapping_add(SPLIT_MIX_INCREMENT);
+        random_duration(self.min, self.max, self.state ^ entity.to_bits())
+    }
+}
+
+/// The amount a SplitMix64 generator's state is advanced by each step.
+const SPLIT_MIX_INCREMENT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Picks a duration between `min` and `max` (inclusive), by mixing the `seed` using SplitMix64.
+fn random_duration(min: Duration, max: Duration, seed: u64) -> Duration {
+    let mut z = seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    let span = u64::try_from((max - min).as_nanos()).unwrap_or(u64::MAX);
+    min + Duration::from_nanos(z % span.saturating_add(1))
+}
+
+/// Tracks how long an effect has existed for. This is added to every effect, and ticked during [`AlchemySet::TickTimers`].
+///
+/// Unlike [`Lifetime`], this never causes the effect to be despawned.
+/// Reapplying an effect using [`Insert`](crate::EffectMode::Insert) keeps the current age,
+/// unless a new `EffectAge` is included in the effect's bundle.
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectAge {
+    /// The time since the effect was first applied.
+    pub elapsed: Duration,
+    /// Controls the merge behaviour when an effect is [merged](crate::EffectMode::Merge).
+    pub mode: AgeMergeMode,
+}
+
+impl EffectAge {
+    /// Creates a new age with the given merge mode.
+    pub fn with_mode(mode: AgeMergeMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+
+    /// Merges the old age (outgoing) into the new one (self).
+    /// Behaviour depends on the new age's [`AgeMergeMode`].
+    pub fn merge(&mut self, outgoing: &Self) {
+        self.elapsed = match self.mode {
+            AgeMergeMode::Keep => outgoing.elapsed,
+            AgeMergeMode::Reset => Duration::ZERO,
+        };
+    }
+}
+
+/// Controls the merge behaviour of an [`EffectAge`] when its effect is [merged](crate::EffectMode::Merge).
+#[derive(Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(PartialEq, Debug, Default, Clone)]
+pub enum AgeMergeMode {
+    /// The old effect's age will be kept, so the age continues to accumulate.
+    #[default]
+    Keep,
+    /// The age will be reset back to zero.
+    Reset,
+}
+
+/// A [merge function](crate::EffectMergeFn) for the [`EffectAge`] component.
+pub fn merge_effect_age(mut new: EntityWorldMut, outgoing: Entity) {
+    let outgoing = *new.world().get::<EffectAge>(outgoing).unwrap();
+    new.get_mut::<EffectAge>().unwrap().merge(&outgoing);
 }
 
 /// Controls the merge behaviour of a timer when its effect is [merged](crate::EffectMode::Merge).
@@ -184,25 +713,216 @@ pub enum TimerMergeMode {
     /// The timer with the larger time remaining will be used.
     Max,
     /// The timers' durations will be added together.
+    ///
+    /// Elapsed time is taken from the new timer, so any progress made by the old timer is lost.
+    /// Use [`Extend`](TimerMergeMode::Extend) to keep it.
     Sum,
+    /// The old timer is kept, and its duration is extended by the new timer's remaining time.
+    ///
+    /// Unlike [`Sum`](TimerMergeMode::Sum), the old timer's elapsed time is preserved,
+    /// so the time remaining is the sum of both timers' remaining time.
+    Extend,
+}
+
+/// Returns true if the effect's target is [paused](EffectsPaused).
+fn target_paused(paused: &Query<(), With<EffectsPaused>>, effecting: Option<&Effecting>) -> bool {
+    effecting.is_some_and(|effecting| paused.contains(effecting.0))
+}
+
+pub(super) fn tick_lifetime<C: Default + Send + Sync + 'static, F: QueryFilter>(
+    time: Res<Time<C>>,
+    mut query: Query<(Entity, &mut Lifetime, Option<&Effecting>), F>,
+    inactive: Query<Option<&ActivationDelay>, With<EffectInactive>>,
+    paused: Query<(), With<EffectsPaused>>,
+) {
+    for (entity, mut lifetime, effecting) in &mut query {
+        if target_paused(&paused, effecting) {
+            continue;
+        }
+
+        if let Ok(activation) = inactive.get(entity)
+            && activation.is_none_or(|activation| !activation.tick_lifetime)
+        {
+            continue;
+        }
+
+        lifetime.predict(time.delta());
+    }
 }
 
 pub(super) fn despawn_finished_lifetimes(
     mut commands: Commands,
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut Lifetime)>,
+    query: Query<(Entity, &Lifetime, Has<LingerOnExpire>, Has<EffectExpiring>)>,
+) {
+    for (entity, lifetime, linger, expiring) in &query {
+        if lifetime.is_permanent() || !lifetime.timer.is_finished() {
+            // The lifetime was refreshed while lingering, such as by reapplying the effect.
+            if expiring {
+                commands.entity(entity).try_remove::<EffectExpiring>();
+            }
+            continue;
+        }
+
+        // The effect may have already been despawned this frame, such as by a cleanse.
+        if linger && !expiring {
+            commands.entity(entity).try_insert(EffectExpiring);
+        } else {
+            commands.queue(move |world: &mut World| despawn_expired(world, entity));
+        }
+    }
+}
+
+pub(super) fn despawn_finished_tick_counts(
+    mut commands: Commands,
+    query: Query<(Entity, Ref<TickCount>)>,
+) {
+    for (entity, tick_count) in &query {
+        // Effects that ran out of ticks this frame are kept until the next one, so that the final tick is visible.
+        if tick_count.remaining == 0 && !tick_count.is_changed() {
+            commands.queue(move |world: &mut World| despawn_expired(world, entity));
+        }
+    }
+}
+
+/// Runs the registered [`EffectExpireFn`]s for an expired effect, and then despawns it.
+/// This is run as a command, so that the expire functions have full world access.
+fn despawn_expired(world: &mut World, effect: Entity) {
+    let Ok(entity) = world.get_entity(effect) else {
+        return;
+    };
+
+    let target = entity.get::<Effecting>().map(|target| target.0);
+
+    let expires: Vec<EffectExpireFn> = world
+        .get_resource::<EffectExpireRegistry>()
+        .map(|registry| {
+            registry
+                .expires
+                .iter()
+                .filter(|(id, _)| entity.contains_type_id(**id))
+                .map(|(_, f)| *f)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some(target) = target {
+        for expire in expires {
+            let Ok(target) = world.get_entity_mut(target) else {
+                break;
+            };
+
+            expire(target, effect);
+        }
+    }
+
+    if let Ok(entity) = world.get_entity_mut(effect) {
+        entity.despawn();
+    }
+}
+
+/// Delays don't tick while their effect is [expiring](EffectExpiring) or [inactive](EffectInactive).
+type DelayFilter = (Without<EffectExpiring>, Without<EffectInactive>);
+
+pub(super) fn tick_delay<C: Default + Send + Sync + 'static, F: QueryFilter>(
+    time: Res<Time<C>>,
+    mut query: Query<(Entity, &mut Delay, Option<&Effecting>), (DelayFilter, F)>,
+    mut tick_counts: Query<&mut TickCount>,
+    paused: Query<(), With<EffectsPaused>>,
+) {
+    for (entity, mut delay, effecting) in &mut query {
+        if target_paused(&paused, effecting) {
+            continue;
+        }
+
+        let mut tick_count = tick_counts.get_mut(entity).ok();
+
+        // The effect has run out of ticks, and will be despawned next frame.
+        if tick_count
+            .as_ref()
+            .is_some_and(|count| count.remaining == 0)
+        {
+            continue;
+        }
+
+        // Don't tick past the remaining ticks, so that long frames can't trigger the delay too many times.
+        let mut delta = time.delta();
+        if let Some(tick_count) = &tick_count
+            && !delay.timer.duration().is_zero()
+        {
+            let max = delay.timer.remaining().saturating_add(
+                delay
+                    .timer
+                    .duration()
+                    .saturating_mul(tick_count.remaining - 1),
+            );
+            delta = delta.min(max);
+        }
+
+        delay.timer.tick(delta);
+
+        let finished = delay.timer.times_finished_this_tick();
+        if finished > 0
+            && let Some(tick_count) = &mut tick_count
+        {
+            tick_count.remaining = tick_count.remaining.saturating_sub(finished);
+        }
+
+        if delay.timer.just_finished() {
+            let delay = delay.as_mut();
+            if let Some(jitter) = &mut delay.jitter {
+                delay.timer.set_duration(jitter.next_interval(entity));
+            }
+        }
+    }
+}
+
+/// Only inactive effects have activation delays that still need ticking.
+type ActivationFilter<F> = (With<EffectInactive>, F);
+
+pub(super) fn tick_activation_delay<C: Default + Send + Sync + 'static, F: QueryFilter>(
+    mut commands: Commands,
+    time: Res<Time<C>>,
+    mut query: Query<(Entity, &mut ActivationDelay, Option<&Effecting>), ActivationFilter<F>>,
+    paused: Query<(), With<EffectsPaused>>,
 ) {
-    for (entity, mut lifetime) in &mut query {
-        lifetime.timer.tick(time.delta());
+    for (entity, mut activation, effecting) in &mut query {
+        if target_paused(&paused, effecting) {
+            continue;
+        }
+
+        activation.timer.tick(time.delta());
 
-        if lifetime.timer.is_finished() {
-            commands.entity(entity).despawn();
+        if activation.timer.is_finished() {
+            commands.entity(entity).remove::<EffectInactive>();
         }
     }
 }
 
-pub(super) fn tick_delay(time: Res<Time>, mut query: Query<&mut Delay>) {
-    for mut delay in &mut query {
-        delay.timer.tick(time.delta());
+/// Marks effects as [inactive](EffectInactive) when their [`ActivationDelay`] is inserted, unless it has already finished.
+/// When merging, the old progress is restored afterward by [`merge_activation_delay`].
+fn deactivate_on_insert(
+    insert: On<Insert, ActivationDelay>,
+    mut commands: Commands,
+    query: Query<&ActivationDelay>,
+) {
+    if query
+        .get(insert.entity)
+        .is_ok_and(|activation| !activation.timer.is_finished())
+    {
+        commands.entity(insert.entity).insert(EffectInactive);
+    }
+}
+
+pub(super) fn tick_age<C: Default + Send + Sync + 'static, F: QueryFilter>(
+    time: Res<Time<C>>,
+    mut query: Query<(&mut EffectAge, Option<&Effecting>), F>,
+    paused: Query<(), With<EffectsPaused>>,
+) {
+    for (mut age, effecting) in &mut query {
+        if target_paused(&paused, effecting) {
+            continue;
+        }
+
+        age.elapsed += time.delta();
     }
 }
@@ -0,0 +1,71 @@
+use crate::{AlchemySet, EffectConditionFn, EffectConditionRegistry, Effecting};
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+
+pub(crate) struct ConditionPlugin;
+
+impl Plugin for ConditionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EffectConditionRegistry>().add_systems(
+            Update,
+            despawn_unmet_conditions.before(AlchemySet::ApplyEffects),
+        );
+    }
+}
+
+/// Maintains an effect only while a condition holds, such as regeneration while standing in a healing zone.
+/// The condition is checked every frame (before [`AlchemySet::ApplyEffects`]), and the effect is despawned once it returns `false`.
+///
+/// This is useful for one-off conditions. For conditions shared by every effect with a component,
+/// register an [`EffectConditionFn`] in the [`EffectConditionRegistry`] instead.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component)]
+/// struct InHealingZone;
+///
+/// #[derive(Component)]
+/// struct Regeneration(f32);
+///
+/// fn enter_healing_zone(mut commands: Commands, target: Entity) {
+///     commands.entity(target).with_effect(EffectBundle::new((
+///         Regeneration(5.0),
+///         ConditionalEffect(|world, target, _| world.get::<InHealingZone>(target).is_some()),
+///     )));
+/// }
+/// ```
+#[derive(Component, Debug, Copy, Clone)]
+pub struct ConditionalEffect(pub EffectConditionFn);
+
+/// Despawns every effect with a [`ConditionalEffect`] or registered [`EffectConditionFn`] that no longer holds.
+fn despawn_unmet_conditions(
+    world: &mut World,
+    effects: &mut QueryState<(Entity, &Effecting, Option<&ConditionalEffect>)>,
+) {
+    let registry = world.resource::<EffectConditionRegistry>();
+
+    let unmet: Vec<Entity> = effects
+        .iter(world)
+        .filter(|(effect, target, conditional)| {
+            let effect_ref = world.entity(*effect);
+
+            let held = conditional.is_none_or(|condition| (condition.0)(world, target.0, *effect))
+                && registry
+                    .conditions
+                    .iter()
+                    .filter(|(id, _)| effect_ref.contains_type_id(**id))
+                    .all(|(_, condition)| condition(world, target.0, *effect));
+
+            !held
+        })
+        .map(|(effect, _, _)| effect)
+        .collect();
+
+    for effect in unmet {
+        if let Ok(entity) = world.get_entity_mut(effect) {
+            entity.despawn();
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! A generic damage-over-time effect, which damages any [`Damageable`] health component.
+
+use crate::{AlchemySet, Delay, EffectExpiring, EffectInactive, Effecting};
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::component::Mutable;
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+use std::marker::PhantomData;
+
+/// A health component that can be damaged by [`DamageOverTime`] effects.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// impl Damageable for Health {
+///     fn damage(&mut self, amount: f32) {
+///         self.0 -= amount;
+///     }
+/// }
+/// ```
+pub trait Damageable: Component<Mutability = Mutable> {
+    /// Deals `amount` damage.
+    fn damage(&mut self, amount: f32);
+}
+
+/// Deals damage to the target's `T` health every time the effect's [`Delay`] triggers.
+/// This is applied by the [`DamageOverTimePlugin<T>`].
+///
+/// **Effects must also have a [`Delay`]**, otherwise no damage is dealt.
+/// Damage isn't dealt while the effect is [inactive](EffectInactive) or [expiring](EffectExpiring).
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// fn apply_poison(mut commands: Commands, target: Entity) {
+///     commands.entity(target).with_effect(EffectBundle::new((
+///         Lifetime::from_seconds(3.0),
+///         Delay::from_seconds(1.0),
+///         DamageOverTime::new(5.0),
+///     )));
+/// }
+/// ```
+#[derive(Component, Reflect, PartialEq, Debug, Default, Copy, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct DamageOverTime {
+    /// The damage dealt each time the effect's [`Delay`] triggers.
+    pub per_tick: f32,
+    /// Whether the damage is multiplied by the effect's [`EffectStacks`](crate::EffectStacks). Requires the `stacks` feature.
+    pub scale_with_stacks: bool,
+}
+
+impl DamageOverTime {
+    /// Creates a new effect, which deals `per_tick` damage each time its [`Delay`] triggers.
+    pub fn new(per_tick: f32) -> Self {
+        Self {
+            per_tick,
+            scale_with_stacks: false,
+        }
+    }
+
+    /// A builder that multiplies the damage by the effect's [`EffectStacks`](crate::EffectStacks).
+    pub fn with_stack_scaling(mut self) -> Self {
+        self.scale_with_stacks = true;
+        self
+    }
+}
+
+/// Applies [`DamageOverTime`] effects to the target's `T` health.
+/// The damage is dealt during [`AlchemySet::ApplyEffects`].
+pub struct DamageOverTimePlugin<T: Damageable> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Damageable> Plugin for DamageOverTimePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            deal_damage_over_time::<T>.in_set(AlchemySet::ApplyEffects),
+        );
+    }
+}
+
+impl<T: Damageable> DamageOverTimePlugin<T> {
+    /// Creates a new plugin that deals damage to the target's `T` health.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Damageable> Default for DamageOverTimePlugin<T> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Excludes effects that shouldn't deal damage.
+type DamageFilter = (Without<EffectInactive>, Without<EffectExpiring>);
+
+#[cfg(feature = "stacks")]
+type Stacks<'a> = (
+    Option<&'a crate::EffectStacks>,
+    Option<&'a crate::EffectStacksU32>,
+);
+#[cfg(not(feature = "stacks"))]
+type Stacks = ();
+
+/// Deals [`DamageOverTime`] to `T` for each time the effect's delay triggered this frame.
+fn deal_damage_over_time<T: Damageable>(
+    effects: Query<(&Effecting, &Delay, &DamageOverTime, Stacks), DamageFilter>,
+    mut targets: Query<&mut T>,
+) {
+    for (target, delay, damage, stacks) in &effects {
+        let ticks = delay.ticks_this_frame();
+        if ticks == 0 {
+            continue;
+        }
+
+        let Ok(mut health) = targets.get_mut(target.0) else {
+            continue;
+        };
+
+        let amount = damage.per_tick * ticks as f32;
+
+        #[cfg(feature = "stacks")]
+        let amount = match stacks {
+            _ if !damage.scale_with_stacks => amount,
+            (_, Some(stacks)) => amount * stacks.0 as f32,
+            (Some(stacks), None) => amount * stacks.0 as f32,
+            (None, None) => amount,
+        };
+        #[cfg(not(feature = "stacks"))]
+        let () = stacks;
+
+        health.damage(amount);
+    }
+}
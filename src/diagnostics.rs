@@ -0,0 +1,90 @@
+use crate::{EffectedBy, Effecting};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_diagnostic::{
+    DEFAULT_MAX_HISTORY_LENGTH, Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic,
+};
+use bevy_ecs::prelude::*;
+use bevy_time::Time;
+
+/// Adds diagnostics for the number of active effects, which can help find effects that are leaking or being spammed.
+/// The diagnostics are updated at the end of each frame, during `PostUpdate`.
+///
+/// This is not included in the [`AlchemyPlugin`](crate::AlchemyPlugin), and requires the `diagnostics` feature.
+pub struct AlchemyDiagnosticsPlugin {
+    /// The total number of values to keep.
+    pub max_history_length: usize,
+}
+
+impl Default for AlchemyDiagnosticsPlugin {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_HISTORY_LENGTH)
+    }
+}
+
+impl AlchemyDiagnosticsPlugin {
+    /// Creates a new `AlchemyDiagnosticsPlugin` with the specified `max_history_length`.
+    pub fn new(max_history_length: usize) -> Self {
+        Self { max_history_length }
+    }
+
+    /// The number of effects that are currently applied to a target.
+    pub const EFFECT_COUNT: DiagnosticPath = DiagnosticPath::const_new("alchemy/effect_count");
+
+    /// The number of entities that have at least one effect applied to them.
+    pub const TARGET_COUNT: DiagnosticPath = DiagnosticPath::const_new("alchemy/target_count");
+
+    /// The largest number of effects applied to a single target.
+    pub const MAX_EFFECTS_PER_TARGET: DiagnosticPath =
+        DiagnosticPath::const_new("alchemy/max_effects_per_target");
+
+    /// The number of effects that were removed from their target (normally by being despawned) per second.
+    pub const REMOVED_PER_SECOND: DiagnosticPath =
+        DiagnosticPath::const_new("alchemy/removed_per_second");
+}
+
+impl Plugin for AlchemyDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for path in [
+            Self::EFFECT_COUNT,
+            Self::TARGET_COUNT,
+            Self::MAX_EFFECTS_PER_TARGET,
+            Self::REMOVED_PER_SECOND,
+        ] {
+            app.register_diagnostic(
+                Diagnostic::new(path).with_max_history_length(self.max_history_length),
+            );
+        }
+
+        app.add_systems(PostUpdate, update_diagnostics);
+    }
+}
+
+fn update_diagnostics(
+    mut diagnostics: Diagnostics,
+    time: Option<Res<Time>>,
+    effects: Query<(), With<Effecting>>,
+    targets: Query<&EffectedBy>,
+    mut removed: RemovedComponents<Effecting>,
+) {
+    diagnostics.add_measurement(&AlchemyDiagnosticsPlugin::EFFECT_COUNT, || {
+        effects.count() as f64
+    });
+
+    diagnostics.add_measurement(&AlchemyDiagnosticsPlugin::TARGET_COUNT, || {
+        targets.count() as f64
+    });
+
+    diagnostics.add_measurement(&AlchemyDiagnosticsPlugin::MAX_EFFECTS_PER_TARGET, || {
+        targets.iter().map(EffectedBy::len).max().unwrap_or(0) as f64
+    });
+
+    let removed = removed.read().count();
+    let delta = time.map_or(0.0, |time| time.delta_secs_f64());
+
+    // Skip frames where no time has passed, rather than dividing by zero.
+    if delta > 0.0 {
+        diagnostics.add_measurement(&AlchemyDiagnosticsPlugin::REMOVED_PER_SECOND, || {
+            removed as f64 / delta
+        });
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{EffectMergeFn, EffectMergeRegistry, EffectMode, EffectModeRegistry};
+use bevy_app::App;
+use bevy_ecs::component::Mutable;
+use bevy_ecs::prelude::*;
+use bevy_reflect::GetTypeRegistration;
+use std::borrow::Cow;
+
+/// An effect component with built-in [merge](crate::EffectMode::Merge) behaviour,
+/// which can be registered using [`register_effect`](EffectAppExt::register_effect).
+///
+/// With the `derive` feature, this can be derived, which generates the merge logic from an `#[effect(...)]` attribute.
+/// The `merge` strategy can be set for the whole struct, or overwritten for individual fields:
+///
+/// | Strategy  | Behaviour                                             |
+/// |-----------|-------------------------------------------------------|
+/// | `replace` | The new value is used (default).                      |
+/// | `keep`    | The old value is kept.                                |
+/// | `sum`     | The values are added together (requires `AddAssign`). |
+/// | `max`     | The larger value is used (requires `PartialOrd`).     |
+/// | `min`     | The smaller value is used (requires `PartialOrd`).    |
+///
+/// Adding `#[effect(reflect)]` to the struct also registers the type for reflection.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component, Clone)]
+/// struct Poison {
+///     damage: f32,
+///     slow: f32,
+/// }
+///
+/// impl Effect for Poison {
+///     fn merge(&mut self, old: &Self) {
+///         self.damage += old.damage;
+///         self.slow = self.slow.max(old.slow);
+///     }
+/// }
+/// ```
+/// Which is equivalent to:
+/// ```rust ignore
+/// #[derive(Component, Clone, Effect)]
+/// #[effect(merge = "sum")]
+/// struct Poison {
+///     damage: f32,
+///     #[effect(merge = "max")]
+///     slow: f32,
+/// }
+/// ```
+pub trait Effect: Component<Mutability = Mutable> + Clone {
+    /// Merges the old effect into this one, which is the newly applied effect.
+    fn merge(&mut self, old: &Self);
+
+    /// Registers the effect with the app. This is called by [`register_effect`](EffectAppExt::register_effect).
+    ///
+    /// By default, this registers [`merge_effect`] in the [`EffectMergeRegistry`].
+    fn register(app: &mut App) {
+        app.world_mut()
+            .get_resource_or_init::<EffectMergeRegistry>()
+            .register::<Self>(merge_effect::<Self>);
+    }
+}
+
+/// A [merge function](crate::EffectMergeFn) for any [`Effect`], which uses [`Effect::merge`].
+pub fn merge_effect<T: Effect>(mut new: EntityWorldMut, outgoing: Entity) {
+    let Some(old) = new.world().get::<T>(outgoing).cloned() else {
+        return;
+    };
+
+    if let Some(mut effect) = new.get_mut::<T>() {
+        effect.merge(&old);
+    }
+}
+
+/// An extension trait for registering [`Effect`]s and merge functions.
+pub trait EffectAppExt {
+    /// Registers the effect's merge function (and reflection, if enabled) with the app.
+    fn register_effect<T: Effect>(&mut self) -> &mut Self;
+
+    /// Registers a [merge function](EffectMergeFn) for `T` in the [`EffectMergeRegistry`],
+    /// initializing the registry if it doesn't exist yet.
+    ///
+    /// This can be called while building plugins, regardless of whether the [`AlchemyPlugin`](crate::AlchemyPlugin) was added first.
+    /// See [`EffectMergeRegistry::register`] for more details.
+    fn register_effect_merge<T: Component + Clone>(&mut self, f: EffectMergeFn) -> &mut Self;
+
+    /// Registers the [`EffectMode`] used by every effect with the given name in the [`EffectModeRegistry`],
+    /// initializing the registry if it doesn't exist yet.
+    fn register_effect_mode(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        mode: EffectMode,
+    ) -> &mut Self;
+}
+
+impl EffectAppExt for App {
+    fn register_effect<T: Effect>(&mut self) -> &mut Self {
+        T::register(self);
+        self
+    }
+
+    fn register_effect_merge<T: Component + Clone>(&mut self, f: EffectMergeFn) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<EffectMergeRegistry>()
+            .register::<T>(f);
+        self
+    }
+
+    fn register_effect_mode(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        mode: EffectMode,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<EffectModeRegistry>()
+            .register(name, mode);
+        self
+    }
+}
+
+/// Items used by the code generated by `#[derive(Effect)]`.
+#[doc(hidden)]
+pub mod __macro_exports {
+    pub use bevy_app::App;
+
+    /// Registers an effect's merge function, and registers it for reflection.
+    pub fn register_reflect_effect<T: super::Effect + super::GetTypeRegistration>(app: &mut App) {
+        app.register_type::<T>();
+        app.world_mut()
+            .get_resource_or_init::<super::EffectMergeRegistry>()
+            .register::<T>(super::merge_effect::<T>);
+    }
+}
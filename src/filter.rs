@@ -0,0 +1,80 @@
+use crate::{EffectPolarity, EffectTags, ReflectComponent};
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+use std::borrow::Cow;
+
+/// Selects a group of effects, either by [name](Name), [tag](EffectTags) or [polarity](EffectPolarity).
+///
+/// Used when [clearing effects](crate::EffectCommandsExt::clear_effects) and by [`EffectImmunity`].
+#[derive(Reflect, Eq, PartialEq, Debug, Clone)]
+#[reflect(PartialEq, Debug, Clone)]
+pub enum EffectFilter {
+    /// Matches every effect.
+    All,
+    /// Matches effects with the given name.
+    Name(Name),
+    /// Matches effects that have the given tag.
+    Tag(Cow<'static, str>),
+    /// Matches effects with the given polarity, such as every debuff.
+    Polarity(EffectPolarity),
+}
+
+impl EffectFilter {
+    /// Creates a filter that matches effects with the given name.
+    pub fn name(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::Name(Name::new(name))
+    }
+
+    /// Creates a filter that matches effects that have the given tag.
+    pub fn tag(tag: impl Into<Cow<'static, str>>) -> Self {
+        Self::Tag(tag.into())
+    }
+
+    /// Returns true if an effect with the given name, tags and polarity is matched by this filter.
+    pub fn matches(&self, name: &Name, tags: &EffectTags, polarity: EffectPolarity) -> bool {
+        match self {
+            EffectFilter::All => true,
+            EffectFilter::Name(other) => other == name,
+            EffectFilter::Tag(tag) => tags.contains(tag),
+            EffectFilter::Polarity(other) => *other == polarity,
+        }
+    }
+
+    /// Returns true if the effect entity is matched by this filter.
+    pub fn matches_entity(&self, effect: EntityRef) -> bool {
+        match self {
+            EffectFilter::All => true,
+            EffectFilter::Name(name) => effect.get::<Name>() == Some(name),
+            EffectFilter::Tag(tag) => effect
+                .get::<EffectTags>()
+                .is_some_and(|tags| tags.contains(tag)),
+            EffectFilter::Polarity(polarity) => {
+                effect.get::<EffectPolarity>().copied().unwrap_or_default() == *polarity
+            }
+        }
+    }
+}
+
+/// Prevents any matching effect from being applied to this entity.
+///
+/// Effects that were applied before the immunity was added are not removed.
+/// To do that, use [`clear_effects`](crate::EffectCommandsExt::clear_effects).
+#[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(Component, PartialEq, Debug, Default, Clone)]
+pub struct EffectImmunity(pub Vec<EffectFilter>);
+
+impl EffectImmunity {
+    /// A builder that adds a new filter to the immunity.
+    pub fn with(mut self, filter: EffectFilter) -> Self {
+        self.0.push(filter);
+        self
+    }
+
+    /// Returns true if an effect with the given name, tags and polarity would be blocked.
+    pub fn blocks(&self, name: &Name, tags: &EffectTags, polarity: EffectPolarity) -> bool {
+        self.0
+            .iter()
+            .any(|filter| filter.matches(name, tags, polarity))
+    }
+}
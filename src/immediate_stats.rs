@@ -0,0 +1,182 @@
+//! Helpers for using effects with [Immediate Stats](https://github.com/AlephCubed/immediate_stats).
+
+use crate::AlchemySet;
+use ::immediate_stats::{
+    ImmediateStatsPlugin, Modifier, ResetComponentPlugin, Stat, StatContainer, StatSystems,
+};
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::component::Mutable;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::ScheduleSystem;
+use std::marker::PhantomData;
+
+/// Resets the `T` stat every frame, so that effects can re-apply their contribution to it.
+///
+/// This adds the [`ImmediateStatsPlugin`] and [`ResetComponentPlugin`] for `T`, if they haven't already been added.
+/// Systems that apply effects to `T` should be added using
+/// [`add_stat_effect_systems`](StatEffectAppExt::add_stat_effect_systems),
+/// which guarantees that they run after the reset.
+/// [Registered apply functions](crate::EffectApplyRegistry) are also run after the reset.
+///
+/// This also applies [`Decaying<T>`] effects (requires the `timers` feature).
+pub struct StatEffectPlugin<T: Component<Mutability = Mutable> + StatContainer> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Component<Mutability = Mutable> + StatContainer> Plugin for StatEffectPlugin<T> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ImmediateStatsPlugin>() {
+            app.add_plugins(ImmediateStatsPlugin);
+        }
+
+        if !app.is_plugin_added::<ResetComponentPlugin<T>>() {
+            app.add_plugins(ResetComponentPlugin::<T>::new());
+        }
+
+        // Registered apply functions should modify stats after they are reset.
+        app.configure_sets(Update, AlchemySet::ApplyEffects.in_set(StatSystems::Modify));
+
+        #[cfg(feature = "timers")]
+        app.add_stat_effect_systems(apply_decaying_stats::<T>);
+    }
+}
+
+impl<T: Component<Mutability = Mutable> + StatContainer> StatEffectPlugin<T> {
+    /// Creates a new plugin for the `T` stat.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Component<Mutability = Mutable> + StatContainer> Default for StatEffectPlugin<T> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// An extension trait for adding effect systems that modify stats.
+pub trait StatEffectAppExt {
+    /// Adds systems that apply effects to stats.
+    ///
+    /// These run every frame during `Update` in the [`Modify`](StatSystems::Modify) set,
+    /// which is after stats are [reset](StatSystems::Reset) and before they are [read](StatSystems::Read).
+    fn add_stat_effect_systems<M>(
+        &mut self,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self;
+}
+
+impl StatEffectAppExt for App {
+    fn add_stat_effect_systems<M>(
+        &mut self,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self {
+        self.add_systems(Update, systems.in_set(StatSystems::Modify))
+    }
+}
+
+/// Applies a modifier to the target's `T` stat, which is scaled by the fraction of the effect's [`Lifetime`](crate::Lifetime) remaining.
+/// This means that the strength of the effect decreases throughout its duration, such as a decaying speed boost.
+///
+/// If the effect has a [`LifetimeScaling`](crate::LifetimeScaling), its curve is used instead,
+/// which allows effects that grow stronger over time.
+///
+/// **Effects must also have a [`Lifetime`](crate::Lifetime)**, otherwise the modifier isn't applied.
+/// This is applied every frame by the [`StatEffectPlugin<T>`].
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// # use immediate_stats::*;
+/// #[derive(Component, StatContainer)]
+/// struct MovementSpeed(Stat);
+///
+/// fn apply_speed_boost(mut commands: Commands, target: Entity) {
+///     commands.entity(target).with_effect(EffectBundle::new((
+///         Lifetime::from_seconds(2.0),
+///         Decaying::new(Modifier::from_multiplier(2.0), |speed: &mut MovementSpeed| &mut speed.0),
+///     )));
+/// }
+/// ```
+#[cfg(feature = "timers")]
+#[derive(Component)]
+pub struct Decaying<T: Component<Mutability = Mutable> + StatContainer> {
+    /// The modifier at the start of the effect, before any decay.
+    pub modifier: Modifier,
+    /// Returns the stat within `T` that the modifier is applied to.
+    pub stat: fn(&mut T) -> &mut Stat,
+}
+
+#[cfg(feature = "timers")]
+impl<T: Component<Mutability = Mutable> + StatContainer> Decaying<T> {
+    /// Creates a new decaying modifier, which is applied to the stat returned by `stat`.
+    pub fn new(modifier: Modifier, stat: fn(&mut T) -> &mut Stat) -> Self {
+        Self { modifier, stat }
+    }
+
+    /// Returns the modifier after scaling, given the effect's lifetime and optional [`LifetimeScaling`](crate::LifetimeScaling).
+    pub fn scaled(
+        &self,
+        lifetime: &crate::Lifetime,
+        scaling: Option<&crate::LifetimeScaling>,
+    ) -> Modifier {
+        let scaling = scaling.copied().unwrap_or_default();
+        self.modifier.scaled(scaling.multiplier(lifetime))
+    }
+
+    /// Returns the modifier after scaling, given the effect's number of stacks, lifetime and optional [`LifetimeScaling`](crate::LifetimeScaling).
+    /// See [`scaled_by_stacks_and_lifetime`](crate::scaled_by_stacks_and_lifetime) for how stacks decay.
+    pub fn scaled_with_stacks(
+        &self,
+        stacks: u32,
+        lifetime: &crate::Lifetime,
+        scaling: Option<&crate::LifetimeScaling>,
+    ) -> Modifier {
+        self.modifier.scaled(crate::scaled_by_stacks_and_lifetime(
+            1.0, stacks, lifetime, scaling,
+        ))
+    }
+}
+
+#[cfg(feature = "timers")]
+impl<T: Component<Mutability = Mutable> + StatContainer> Clone for Decaying<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "timers")]
+impl<T: Component<Mutability = Mutable> + StatContainer> Copy for Decaying<T> {}
+
+#[cfg(feature = "timers")]
+type DecayingEffect<'a, T> = (
+    &'a crate::Effecting,
+    &'a crate::Lifetime,
+    &'a Decaying<T>,
+    Option<&'a crate::LifetimeScaling>,
+);
+
+/// Decaying stats aren't applied while the effect is [inactive](crate::EffectInactive) or [expiring](crate::EffectExpiring).
+#[cfg(feature = "timers")]
+type DecayingFilter = (
+    Without<crate::EffectInactive>,
+    Without<crate::EffectExpiring>,
+);
+
+/// Applies [`Decaying<T>`] effects to their targets.
+#[cfg(feature = "timers")]
+fn apply_decaying_stats<T: Component<Mutability = Mutable> + StatContainer>(
+    effects: Query<DecayingEffect<T>, DecayingFilter>,
+    mut targets: Query<&mut T>,
+) {
+    for (target, lifetime, decaying, scaling) in &effects {
+        let Ok(mut stats) = targets.get_mut(target.0) else {
+            continue;
+        };
+
+        (decaying.stat)(&mut stats).apply(decaying.scaled(lifetime, scaling));
+    }
+}
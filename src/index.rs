@@ -0,0 +1,153 @@
+use crate::Effecting;
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use std::marker::PhantomData;
+
+/// Maintains an [`EffectIndex<T>`] on each target that is effected by an effect with the `T` component.
+///
+/// This is not included in the [`AlchemyPlugin`](crate::AlchemyPlugin), and must be added for each indexed `T`.
+pub struct EffectIndexPlugin<T: Component> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Component> Plugin for EffectIndexPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(index_effect::<T>)
+            .add_observer(index_bound_effect::<T>)
+            .add_observer(unindex_effect::<T>)
+            .add_observer(unindex_unbound_effect::<T>);
+    }
+}
+
+impl<T: Component> EffectIndexPlugin<T> {
+    /// Creates a new plugin that indexes effects with the `T` component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Component> Default for EffectIndexPlugin<T> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Stores the effects with the `T` component that are effecting this entity.
+/// This allows effects of a particular kind to be found without walking the whole [`EffectedBy`](crate::EffectedBy) list.
+///
+/// Requires the [`EffectIndexPlugin<T>`]. The component is removed once there are no more matching effects.
+/// Effects are indexed when `T` is added or when they are bound to a target, so `T` should be part of the effect's bundle.
+#[derive(Component, Debug)]
+pub struct EffectIndex<T: Component> {
+    effects: Vec<Entity>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Component> EffectIndex<T> {
+    /// Returns an iterator over the indexed effects.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.effects.iter().copied()
+    }
+
+    /// Returns the number of indexed effects.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns true if there are no indexed effects.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Returns true if the effect is indexed.
+    pub fn contains(&self, effect: Entity) -> bool {
+        self.effects.contains(&effect)
+    }
+}
+
+// Changes are queued as commands, so that they stay in order when multiple effects are added or removed at once.
+fn index_effect<T: Component>(add: On<Add, T>, effects: Query<&Effecting>, mut commands: Commands) {
+    let effect = add.entity;
+    let Ok(target) = effects.get(effect).map(|target| target.0) else {
+        return;
+    };
+
+    commands.queue(move |world: &mut World| push_index::<T>(world, target, effect));
+}
+
+/// Indexes effects that already had `T` before being bound to a target.
+fn index_bound_effect<T: Component>(
+    add: On<Add, Effecting>,
+    effects: Query<&Effecting, With<T>>,
+    mut commands: Commands,
+) {
+    let effect = add.entity;
+    let Ok(target) = effects.get(effect).map(|target| target.0) else {
+        return;
+    };
+
+    commands.queue(move |world: &mut World| push_index::<T>(world, target, effect));
+}
+
+fn push_index<T: Component>(world: &mut World, target: Entity, effect: Entity) {
+    let Ok(mut target) = world.get_entity_mut(target) else {
+        return;
+    };
+
+    if let Some(mut index) = target.get_mut::<EffectIndex<T>>() {
+        // Effects spawned with both `T` and `Effecting` trigger both observers.
+        if !index.effects.contains(&effect) {
+            index.effects.push(effect);
+        }
+    } else {
+        target.insert(EffectIndex::<T> {
+            effects: vec![effect],
+            _phantom: PhantomData,
+        });
+    }
+}
+
+fn unindex_effect<T: Component>(
+    remove: On<Remove, T>,
+    effects: Query<&Effecting>,
+    mut commands: Commands,
+) {
+    let effect = remove.entity;
+    let Ok(target) = effects.get(effect).map(|target| target.0) else {
+        return;
+    };
+
+    commands.queue(move |world: &mut World| remove_index::<T>(world, target, effect));
+}
+
+/// Unindexes effects that are removed from a target without losing `T`, such as when they are retargeted.
+fn unindex_unbound_effect<T: Component>(
+    remove: On<Remove, Effecting>,
+    effects: Query<&Effecting, With<T>>,
+    mut commands: Commands,
+) {
+    let effect = remove.entity;
+    let Ok(target) = effects.get(effect).map(|target| target.0) else {
+        return;
+    };
+
+    commands.queue(move |world: &mut World| remove_index::<T>(world, target, effect));
+}
+
+fn remove_index<T: Component>(world: &mut World, target: Entity, effect: Entity) {
+    let Ok(mut target) = world.get_entity_mut(target) else {
+        return;
+    };
+
+    let Some(mut index) = target.get_mut::<EffectIndex<T>>() else {
+        return;
+    };
+
+    index.effects.retain(|e| *e != effect);
+
+    if index.effects.is_empty() {
+        target.remove::<EffectIndex<T>>();
+    }
+}
@@ -1,23 +1,78 @@
 #![doc = include_str!("../README.md")]
 
+mod apply;
+#[cfg(feature = "assets")]
+mod asset;
 mod bundle;
 mod command;
 mod component;
+mod condition;
+#[cfg(feature = "damage")]
+mod damage;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod effect;
+mod filter;
+#[cfg(feature = "immediate_stats")]
+mod immediate_stats;
+mod index;
+mod observer;
+mod preview;
+mod reader;
 mod registry;
 mod relation;
+mod replicate;
+mod report;
+mod resolver;
+mod settings;
+mod snapshot;
+#[cfg(feature = "test_utils")]
+mod test_utils;
+mod validate;
 
+use crate::apply::ApplyPlugin;
+#[cfg(feature = "assets")]
+pub use crate::asset::*;
+use crate::condition::ConditionPlugin;
+#[cfg(feature = "immediate_stats")]
+pub use crate::immediate_stats::*;
+#[cfg(feature = "derive")]
+pub use bevy_alchemy_macros::Effect;
 use bevy_app::{App, Plugin};
 use bevy_ecs::prelude::*;
 use bevy_reflect::Reflect;
 use bevy_reflect::prelude::ReflectDefault;
-
 pub use bundle::*;
 pub use command::*;
 pub use component::*;
+pub use condition::*;
+#[cfg(feature = "damage")]
+pub use damage::*;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::*;
+pub use effect::*;
+pub use filter::*;
+pub use index::*;
+pub use observer::*;
+pub use preview::*;
+pub use reader::*;
 pub use registry::*;
 pub use relation::*;
+pub use replicate::*;
+pub use report::*;
+pub use resolver::*;
+pub use settings::*;
+pub use snapshot::*;
+#[cfg(feature = "test_utils")]
+pub use test_utils::*;
 
 /// Setup required types and systems for `bevy_alchemy`.
+///
+/// - [`AlchemySet::TickTimers`] and [`AlchemySet::DespawnExpired`] run in `PreUpdate`.
+///   [`Lifetime`]s and [`Delay`]s are ticked before they are checked for despawning, but the sets themselves are not ordered,
+///   so systems that need to see ticked timers should run after [`AlchemySet::TickTimers`].
+///   To force every timer to be ticked before anything is despawned, the sets can be chained using
+///   `app.configure_sets(PreUpdate, (AlchemySet::TickTimers, AlchemySet::DespawnExpired).chain())`.
 pub struct AlchemyPlugin;
 
 impl Plugin for AlchemyPlugin {
@@ -25,17 +80,92 @@ impl Plugin for AlchemyPlugin {
         app.register_type::<EffectMode>()
             .register_type::<Effecting>()
             .register_type::<EffectedBy>()
-            .register_type::<Lifetime>()
+            .register_type::<Magnitude>()
+            .register_type::<MagnitudeMergeMode>()
+            .register_type::<KeepEffect>()
+            .register_type::<EffectId>()
+            .register_type::<EffectCategory>()
+            .register_type::<EffectTags>()
+            .register_type::<EffectPolarity>()
+            .register_type::<EffectFilter>()
+            .register_type::<EffectImmunity>()
+            .register_type::<DispelOrder>()
+            .register_type::<MaxEffects>()
+            .register_type::<EffectLimitPolicy>()
+            .register_type::<StackLimits>()
+            .register_type::<StackLimitPolicy>()
+            .register_type::<EffectPriority>()
+            .register_type::<EffectJustApplied>()
+            .register_type::<EffectOrder>()
+            .register_type::<EffectSources>()
+            .register_type::<StackContributions>()
+            .register_type::<EffectSnapshot>()
+            .register_type::<AlchemySettings>()
+            .init_resource::<EffectMergeRegistry>()
+            .init_resource::<EffectModeRegistry>()
+            .init_resource::<AlchemySettings>()
+            .add_plugins(AppliedPlugin)
+            .add_plugins(ApplyPlugin)
+            .add_plugins(ConditionPlugin)
+            .add_plugins(MagnitudePlugin)
+            .add_plugins(SourcesPlugin);
+
+        #[cfg(feature = "timers")]
+        app.register_type::<Lifetime>()
             .register_type::<Delay>()
+            .register_type::<DelayJitter>()
+            .register_type::<TickCount>()
+            .register_type::<LifetimeScaling>()
+            .register_type::<ScalingCurve>()
             .register_type::<TimerMergeMode>()
-            .init_resource::<EffectMergeRegistry>()
-            .add_plugins(TimerPlugin)
+            .register_type::<EffectAge>()
+            .register_type::<AgeMergeMode>()
+            .register_type::<LingerOnExpire>()
+            .register_type::<EffectExpiring>()
+            .register_type::<ActivationDelay>()
+            .register_type::<EffectInactive>()
+            .register_type::<EffectsPaused>()
+            .register_type::<CustomEffectClock>()
+            .add_plugins(TimerPlugin);
+
+        #[cfg(feature = "stacks")]
+        app.register_type::<EffectStacks>()
+            .register_type::<EffectStacksU32>()
+            .register_type::<StackMergeMode>()
+            .register_type::<StackGainModifier>()
             .add_plugins(StackPlugin);
+
+        #[cfg(feature = "damage")]
+        app.register_type::<DamageOverTime>();
     }
 }
 
+/// A [`SystemSet`] for ordering systems around `bevy_alchemy` operations.
+/// Recommend configuration can be added via the [`AlchemyPlugin`].
+///
+/// For example, a system that scales an effect by its [remaining lifetime](Lifetime)
+/// should run after [`TickTimers`](AlchemySet::TickTimers) and before [`DespawnExpired`](AlchemySet::DespawnExpired),
+/// so that it still gets applied on the frame that the effect expires.
+///
+/// [`TickTimers`](AlchemySet::TickTimers) and [`DespawnExpired`](AlchemySet::DespawnExpired) aren't ordered relative to each other,
+/// so a system in one set can run before or after the other set. See the [`AlchemyPlugin`] for how to chain them.
+#[derive(SystemSet, Debug, Clone, Eq, PartialEq, Hash)]
+pub enum AlchemySet {
+    /// Systems that tick effect timers ([`Lifetime`] and [`Delay`]).
+    TickTimers,
+    /// Systems that despawn effects whose [`Lifetime`] has finished.
+    DespawnExpired,
+    /// Systems that run the registered [apply functions](EffectApplyFn), during `Update`.
+    ApplyEffects,
+}
+
 /// Describes the logic used when multiple of the same effect are applied to an entity.
+///
+/// The mode should be consistent for every application of an effect.
+/// Effects with different modes are never matched, even if they share a name (a warning is logged in debug builds).
+/// To declare the mode once for every effect with a given name, use the [`EffectModeRegistry`].
 #[derive(Component, Reflect, Eq, PartialEq, Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "assets", derive(serde::Deserialize))]
 #[reflect(Component, PartialEq, Debug, Default, Clone)]
 pub enum EffectMode {
     /// Multiple of the same effect can exist at once, so when an effect is added, its components will be spawned as a new entity.
@@ -50,4 +180,11 @@ pub enum EffectMode {
     ///
     /// If there are no matching effects, the components will be spawned as a new entity.
     Merge,
+    /// When an effect is added, either it or the matching effect is kept, and the other is despawned.
+    /// This is decided using the replacement functions in the [`EffectMergeRegistry`],
+    /// such as keeping the effect with the larger [`Magnitude`].
+    /// If no replacement function applies, the new effect is kept.
+    ///
+    /// If there are no matching effects, the components will be spawned as a new entity.
+    Select,
 }
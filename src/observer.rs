@@ -0,0 +1,50 @@
+use crate::{EffectBundle, EffectCommandsExt};
+use bevy_ecs::prelude::*;
+
+/// Creates an observer that applies an effect to the target of an [`EntityEvent`],
+/// such as applying a bleed whenever an entity takes damage. The effect is created from the event using `f`.
+///
+/// This can be added globally using [`App::add_observer`](bevy_app::App::add_observer),
+/// or to a specific entity using [`EntityCommands::observe`].
+///
+/// # Ordering
+/// Observers can't change the world's structure directly, so the effect is applied using [`Commands`].
+/// - If the event is triggered using [`Commands::trigger`] (such as from a system), the effect is applied
+///   alongside the rest of the triggering system's commands. Systems that read the effect should be ordered
+///   after the triggering system, which inserts the required `ApplyDeferred`.
+/// - If the event is triggered using [`World::trigger`], the effect is queued on the world,
+///   so [`World::flush`] must be called before the effect can be read.
+///
+/// # Example
+/// ```rust
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(EntityEvent)]
+/// struct Damaged {
+///     entity: Entity,
+///     amount: i32,
+/// }
+///
+/// #[derive(Component, Default)]
+/// struct Bleed {
+///     damage: i32,
+/// }
+///
+/// App::new().add_observer(apply_effect_on(|damaged: &Damaged| {
+///     EffectBundle::new(Bleed {
+///         damage: damaged.amount / 10,
+///     })
+/// }));
+/// ```
+pub fn apply_effect_on<E, B, F>(mut f: F) -> impl FnMut(On<E>, Commands)
+where
+    E: EntityEvent,
+    B: Bundle,
+    F: FnMut(&E) -> EffectBundle<B> + Send + Sync + 'static,
+{
+    move |event: On<E>, mut commands: Commands| {
+        let bundle = f(event.event());
+        commands.entity(event.event_target()).with_effect(bundle);
+    }
+}
@@ -0,0 +1,294 @@
+use crate::command::{
+    effects_to_evict, find_existing_effect, insert_effect, outranked, stacks_to_evict,
+};
+use crate::registry::{EffectMergeRegistry, KeepEffect, resolve_mode};
+use crate::relation::stack_count;
+use crate::{AlchemySettings, EffectBundle, EffectImmunity, EffectMode, EffectPriority, Magnitude};
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::prelude::*;
+use std::any::TypeId;
+
+/// What would happen if an effect was applied to a target. See [`preview_effect`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum EffectOutcome {
+    /// The effect wouldn't be applied, such as due to [`EffectImmunity`], a higher [priority](EffectPriority) effect or a limit.
+    Blocked,
+    /// A new effect would be spawned.
+    Spawn,
+    /// The existing effect would be overwritten ([`EffectMode::Insert`]).
+    Insert(Entity),
+    /// The effect would be merged into the existing effect ([`EffectMode::Merge`]).
+    Merge(Entity),
+    /// The existing effect would be kept, and the new effect discarded ([`EffectMode::Select`]).
+    Keep(Entity),
+    /// The existing effect would be replaced by the new effect ([`EffectMode::Select`]).
+    Replace(Entity),
+}
+
+/// The result of [`preview_effect`], which describes the effect after it would be applied.
+///
+/// Only the built-in effect components are predicted ([`EffectStacks`](crate::EffectStacks),
+/// [`Lifetime`](crate::Lifetime), [`Delay`](crate::Delay), [`Magnitude`], etc.),
+/// alongside any components from the new effect's bundle.
+pub struct EffectPreview {
+    /// What would happen to the effect.
+    pub outcome: EffectOutcome,
+    world: World,
+    effect: Option<Entity>,
+}
+
+impl EffectPreview {
+    /// Returns a component of the effect after it would be applied,
+    /// or `None` if it wouldn't have the component or would be [blocked](EffectOutcome::Blocked).
+    pub fn get<T: Component>(&self) -> Option<&T> {
+        self.world.get::<T>(self.effect?)
+    }
+
+    /// Returns the number of stacks the effect would have, counting effects without stacks as a single stack.
+    /// Returns `None` if the effect would be [blocked](EffectOutcome::Blocked).
+    ///
+    /// This only includes the effect itself, so other [stack-mode](EffectMode::Stack) effects aren't counted.
+    pub fn stacks(&self) -> Option<u32> {
+        Some(stack_count(&self.world, self.effect?))
+    }
+}
+
+/// Predicts the result of applying an effect to the target, without modifying the world.
+/// This is useful for tooltips (such as "reapplying will refresh to 4s and reach 3 stacks") and tests.
+///
+/// The merge math is run against copies of the built-in components, using the registered [merge](crate::EffectMergeFn)
+/// and [replacement](crate::EffectReplaceFn) functions. Custom components are only included if they are in the new effect's bundle,
+/// and [custom resolvers](crate::EffectResolver) are previewed using the effect's [mode](EffectBundle::mode).
+pub fn preview_effect<B: Bundle + Clone>(
+    world: &World,
+    target: Entity,
+    bundle: &EffectBundle<B>,
+) -> EffectPreview {
+    let mut preview = EffectPreview {
+        outcome: EffectOutcome::Blocked,
+        world: World::new(),
+        effect: None,
+    };
+
+    if let Some(settings) = world.get_resource::<AlchemySettings>() {
+        preview.world.insert_resource(settings.clone());
+    }
+
+    let mut bundle = bundle.clone();
+    resolve_mode(world, &mut bundle);
+
+    if let Some(immunity) = world.get::<EffectImmunity>(target)
+        && immunity.blocks(&bundle.name, &bundle.tags, bundle.polarity)
+    {
+        return preview;
+    }
+
+    // The new effect, as if it was spawned.
+    let incoming = preview.world.spawn_empty().id();
+    insert_effect(preview.world.entity_mut(incoming), bundle.clone(), ());
+
+    // Unnamed effects are matched using their components. Unregistered components can't be on an existing effect.
+    let components: Option<Vec<ComponentId>> = if bundle.name.is_empty() {
+        B::get_component_ids(world.components()).collect()
+    } else {
+        Some(Vec::new())
+    };
+
+    let existing = match (bundle.resolved_mode(), components) {
+        (EffectMode::Stack, _) | (_, None) => None,
+        (mode, Some(components)) => find_existing_effect(
+            world,
+            target,
+            mode,
+            bundle.category.as_ref(),
+            bundle.id.as_ref(),
+            &bundle.name,
+            &components,
+        ),
+    };
+
+    let Some(existing) = existing else {
+        let stack_room = bundle.resolved_mode() != EffectMode::Stack
+            || stacks_to_evict(world, target, &bundle.name).is_some();
+
+        if stack_room && effects_to_evict(world, target, bundle.priority).is_some() {
+            preview.outcome = EffectOutcome::Spawn;
+            preview.effect = Some(incoming);
+        }
+        return preview;
+    };
+
+    if outranked(world, existing, bundle.resolved_mode(), bundle.priority) {
+        return preview;
+    }
+
+    // A copy of the existing effect, before it is changed.
+    let old = preview.world.spawn_empty().id();
+    copy_builtin(world, existing, &mut preview.world, old);
+
+    match bundle.resolved_mode() {
+        EffectMode::Stack => unreachable!(),
+        EffectMode::Insert => {
+            let new = preview.world.spawn_empty().id();
+            copy_builtin(world, existing, &mut preview.world, new);
+            insert_effect(preview.world.entity_mut(new), bundle.clone(), ());
+
+            preview.outcome = EffectOutcome::Insert(existing);
+            preview.effect = Some(new);
+        }
+        EffectMode::Merge => {
+            #[cfg(feature = "stacks")]
+            let stack_limit = crate::command::stack_limit(world, target, &bundle.name);
+
+            #[cfg(feature = "stacks")]
+            if let Some((max, crate::StackLimitPolicy::Reject)) = stack_limit
+                && stack_count(world, existing) >= max as u32
+            {
+                return preview;
+            }
+
+            let new = preview.world.spawn_empty().id();
+            copy_builtin(world, existing, &mut preview.world, new);
+            insert_effect(preview.world.entity_mut(new), bundle.clone(), ());
+            #[cfg(feature = "stacks")]
+            apply_stack_gain(world, existing, &mut preview.world, incoming, new);
+            merge_builtin(world, &mut preview.world, incoming, new, old);
+
+            #[cfg(feature = "stacks")]
+            if let Some((max, _)) = stack_limit {
+                crate::command::cap_stacks(&mut preview.world, new, max);
+            }
+
+            preview.outcome = EffectOutcome::Merge(existing);
+            preview.effect = Some(new);
+        }
+        EffectMode::Select => {
+            let keep = select_builtin(world, &preview.world, incoming, old);
+
+            (preview.outcome, preview.effect) = match keep {
+                KeepEffect::Old => (EffectOutcome::Keep(existing), Some(old)),
+                KeepEffect::Incoming => (EffectOutcome::Replace(existing), Some(incoming)),
+            };
+        }
+    }
+
+    preview
+}
+
+/// Invokes the macro `$f` with each built-in component type.
+macro_rules! for_each_builtin {
+    ($f:ident) => {{
+        $f!(EffectPriority);
+        $f!(Magnitude);
+        $f!(crate::EffectSources);
+        $f!(crate::StackContributions);
+        #[cfg(feature = "timers")]
+        {
+            $f!(crate::Lifetime);
+            $f!(crate::Delay);
+            $f!(crate::TickCount);
+            $f!(crate::EffectAge);
+        }
+        #[cfg(feature = "stacks")]
+        {
+            $f!(crate::EffectStacks);
+            $f!(crate::EffectStacksU32);
+            $f!(crate::StackMergeMode);
+        }
+    }};
+}
+
+/// Copies the built-in components of an effect into the preview world.
+fn copy_builtin(world: &World, from: Entity, preview: &mut World, to: Entity) {
+    macro_rules! copy {
+        ($ty:ty) => {
+            if let Some(component) = world.get::<$ty>(from) {
+                preview.entity_mut(to).insert(component.clone());
+            }
+        };
+    }
+
+    for_each_builtin!(copy);
+}
+
+/// Runs the registered merge functions for the built-in components that the `incoming` effect has.
+/// Components missing from the incoming effect aren't overwritten, so they aren't merged.
+fn merge_builtin(world: &World, preview: &mut World, incoming: Entity, new: Entity, old: Entity) {
+    let Some(registry) = world.get_resource::<EffectMergeRegistry>() else {
+        return;
+    };
+
+    macro_rules! merge {
+        ($ty:ty) => {
+            if preview.entity(incoming).contains::<$ty>()
+                && preview.entity(old).contains::<$ty>()
+                && let Some(entry) = registry.merges.get(&TypeId::of::<$ty>())
+            {
+                (entry.merge)(preview.entity_mut(new), old);
+            }
+        };
+    }
+
+    for_each_builtin!(merge);
+}
+
+/// Multiplies the incoming stacks by the [`StackGainModifier`](crate::StackGainModifier)s on the target, before they are merged.
+/// Effects in the preview world aren't applied to a target, so the stack merge functions can't find the modifiers themselves.
+#[cfg(feature = "stacks")]
+fn apply_stack_gain(
+    world: &World,
+    existing: Entity,
+    preview: &mut World,
+    incoming: Entity,
+    new: Entity,
+) {
+    use crate::{EffectStacks, EffectStacksU32};
+
+    let multiplier = crate::component::stack_gain_multiplier(world, existing);
+    let Some(registry) = world.get_resource::<EffectMergeRegistry>() else {
+        return;
+    };
+
+    // Stacks are only multiplied when they are merged.
+    let merged = |id: TypeId, has: bool| has && registry.merges.contains_key(&id);
+    let incoming = preview.entity(incoming);
+    let stacks = merged(
+        TypeId::of::<EffectStacks>(),
+        incoming.contains::<EffectStacks>(),
+    );
+    let stacks_u32 = merged(
+        TypeId::of::<EffectStacksU32>(),
+        incoming.contains::<EffectStacksU32>(),
+    );
+
+    let mut new = preview.entity_mut(new);
+
+    if stacks && let Some(mut stacks) = new.get_mut::<EffectStacks>() {
+        *stacks *= multiplier;
+    }
+
+    if stacks_u32 && let Some(mut stacks) = new.get_mut::<EffectStacksU32>() {
+        *stacks *= multiplier as u32;
+    }
+}
+
+/// Runs the first registered replacement function for the built-in components that both effects have.
+fn select_builtin(world: &World, preview: &World, incoming: Entity, old: Entity) -> KeepEffect {
+    let Some(registry) = world.get_resource::<EffectMergeRegistry>() else {
+        return KeepEffect::Incoming;
+    };
+
+    macro_rules! select {
+        ($ty:ty) => {
+            if preview.entity(incoming).contains::<$ty>()
+                && preview.entity(old).contains::<$ty>()
+                && let Some(replace) = registry.replacements.get(&TypeId::of::<$ty>())
+            {
+                return replace(preview, old, incoming);
+            }
+        };
+    }
+
+    for_each_builtin!(select);
+    KeepEffect::Incoming
+}
@@ -0,0 +1,108 @@
+use crate::EffectedBy;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+#[cfg(feature = "timers")]
+use std::time::Duration;
+
+/// A [`SystemParam`] for reading the effects that are applied to a target.
+/// Only effect components are accessed, and only immutably, so this can be used alongside mutable queries of the target.
+///
+/// Effects are returned as entities, rather than [`EntityRef`]s, since those would conflict with every mutable query in the system.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// fn poison_damage(reader: EffectReader, mut targets: Query<(Entity, &mut Health)>) {
+///     for (target, mut health) in &mut targets {
+///         health.0 -= reader.stacks_of(target, "Poison") as f32;
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(poison_damage);
+/// ```
+#[derive(SystemParam)]
+pub struct EffectReader<'w, 's> {
+    targets: Query<'w, 's, &'static EffectedBy>,
+    names: Query<'w, 's, &'static Name>,
+    #[cfg(feature = "stacks")]
+    stacks: Query<
+        'w,
+        's,
+        (
+            Option<&'static crate::EffectStacks>,
+            Option<&'static crate::EffectStacksU32>,
+        ),
+    >,
+    #[cfg(feature = "timers")]
+    lifetimes: Query<'w, 's, &'static crate::Lifetime>,
+}
+
+impl EffectReader<'_, '_> {
+    /// Returns an iterator over all effects applied to the target.
+    pub fn effects_on(&self, target: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.targets
+            .get(target)
+            .into_iter()
+            .flat_map(|effected_by| effected_by.iter())
+    }
+
+    /// Returns an iterator over the effects applied to the target with the given [`Name`].
+    pub fn named<'a>(&'a self, target: Entity, name: &'a str) -> impl Iterator<Item = Entity> + 'a {
+        self.effects_on(target).filter(move |effect| {
+            self.names
+                .get(*effect)
+                .is_ok_and(|other| other.as_str() == name)
+        })
+    }
+
+    /// Returns true if any of the effects applied to the target have the given [`Name`].
+    pub fn has_effect(&self, target: Entity, name: &str) -> bool {
+        self.named(target, name).next().is_some()
+    }
+
+    /// Returns the total number of stacks of the effects with the given [`Name`].
+    ///
+    /// This works for both [stack](crate::EffectMode::Stack) and [merge](crate::EffectMode::Merge) effects,
+    /// so independent stacked effects (each with their own lifetime) can still be counted together, by summing [`EffectStacks`](crate::EffectStacks) (or [`EffectStacksU32`](crate::EffectStacksU32))
+    /// and counting effects without it as a single stack. The sum saturates at [`u32::MAX`].
+    pub fn stacks_of(&self, target: Entity, name: &str) -> u32 {
+        #[cfg(feature = "stacks")]
+        return self
+            .named(target, name)
+            .map(|effect| match self.stacks.get(effect) {
+                Ok((_, Some(stacks))) => stacks.0,
+                Ok((Some(stacks), None)) => stacks.0.into(),
+                _ => 1,
+            })
+            .fold(0, u32::saturating_add);
+
+        #[cfg(not(feature = "stacks"))]
+        return self.named(target, name).count() as u32;
+    }
+
+    /// Returns the longest remaining [`Lifetime`](crate::Lifetime) of the effects with the given [`Name`],
+    /// or `None` if none of them have a lifetime.
+    #[cfg(feature = "timers")]
+    pub fn remaining_lifetime(&self, target: Entity, name: &str) -> Option<Duration> {
+        self.named(target, name)
+            .filter_map(|effect| self.lifetimes.get(effect).ok())
+            .map(|lifetime| lifetime.timer.remaining())
+            .max()
+    }
+
+    /// Returns the fraction (between zero and one) of the [`Lifetime`](crate::Lifetime) remaining
+    /// for the effect with the given [`Name`], such as for a progress bar.
+    ///
+    /// If there are multiple matching effects, the one with the longest [remaining lifetime](Self::remaining_lifetime) is used.
+    /// Returns `None` if none of them have a lifetime.
+    #[cfg(feature = "timers")]
+    pub fn lifetime_fraction(&self, target: Entity, name: &str) -> Option<f32> {
+        self.named(target, name)
+            .filter_map(|effect| self.lifetimes.get(effect).ok())
+            .max_by_key(|lifetime| lifetime.timer.remaining())
+            .map(|lifetime| lifetime.timer.fraction_remaining())
+    }
+}
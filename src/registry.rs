@@ -1,5 +1,8 @@
+use crate::{EffectBundle, EffectMode};
 use bevy_ecs::prelude::*;
+use bevy_reflect::{Reflect, TypeRegistry};
 use std::any::TypeId;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// A function used to merge effects with [`EffectMode::Merge`](crate::EffectMode::Merge),
@@ -19,6 +22,39 @@ use std::collections::HashMap;
 /// ```
 pub type EffectMergeFn = fn(new: EntityWorldMut, outgoing: Entity);
 
+/// A function used to decide which effect survives with [`EffectMode::Select`](crate::EffectMode::Select),
+/// which must be registered in the [registry](EffectMergeRegistry::register_replacement).
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::KeepEffect;
+/// #[derive(Component, Clone)]
+/// struct AttackUp(f32);
+///
+/// fn keep_stronger(world: &World, old: Entity, incoming: Entity) -> KeepEffect {
+///     let old = world.get::<AttackUp>(old).unwrap();
+///     let incoming = world.get::<AttackUp>(incoming).unwrap();
+///
+///     if old.0 > incoming.0 {
+///         KeepEffect::Old
+///     } else {
+///         KeepEffect::Incoming
+///     }
+/// }
+/// ```
+pub type EffectReplaceFn = fn(world: &World, old: Entity, incoming: Entity) -> KeepEffect;
+
+/// The result of an [`EffectReplaceFn`], which decides which effect survives.
+#[derive(Reflect, Eq, PartialEq, Debug, Copy, Clone)]
+#[reflect(PartialEq, Debug, Clone)]
+pub enum KeepEffect {
+    /// The existing effect is kept, and the incoming one is discarded.
+    Old,
+    /// The incoming effect is kept, and the existing one is despawned.
+    Incoming,
+}
+
 /// Stores the effect merge logic for each registered component.
 /// New components can be registered by providing a [`EffectMergeFn`] to the [`register`](EffectMergeRegistry::register) method.
 /// This function will be run whenever an effect is applied twice to the same entity with [`EffectMode::Merge`](crate::EffectMode::Merge).
@@ -44,13 +80,292 @@ pub type EffectMergeFn = fn(new: EntityWorldMut, outgoing: Entity);
 /// ```
 #[derive(Resource, Default)]
 pub struct EffectMergeRegistry {
-    pub(crate) merges: HashMap<TypeId, EffectMergeFn>,
+    pub(crate) merges: HashMap<TypeId, MergeEntry>,
+    pub(crate) replacements: HashMap<TypeId, EffectReplaceFn>,
+}
+
+/// A registered [`EffectMergeFn`], alongside a typed function for copying the component between entities.
+#[derive(Copy, Clone)]
+pub(crate) struct MergeEntry {
+    pub(crate) merge: EffectMergeFn,
+    pub(crate) copy: fn(world: &mut World, from: Entity, to: Entity),
+    /// If true, the component can only be copied using reflection, so it can't be copied by an entity cloner.
+    pub(crate) reflect: bool,
+}
+
+fn copy_component<T: Component + Clone>(world: &mut World, from: Entity, to: Entity) {
+    if let Some(component) = world.get::<T>(from).cloned() {
+        world.entity_mut(to).insert(component);
+    }
+}
+
+fn copy_component_reflect<T: Component + Reflect>(world: &mut World, from: Entity, to: Entity) {
+    let component = world
+        .get::<T>(from)
+        .and_then(|component| component.reflect_clone().ok())
+        .and_then(|component| component.take::<T>().ok());
+
+    if let Some(component) = component {
+        world.entity_mut(to).insert(component);
+    }
 }
 
 impl EffectMergeRegistry {
     /// Registers a [`EffectMergeFn`] to be run whenever two `T` status effects are merged.
     pub fn register<T: Component + Clone>(&mut self, f: EffectMergeFn) -> &mut Self {
-        self.merges.insert(TypeId::of::<T>(), f);
+        self.merges.insert(
+            TypeId::of::<T>(),
+            MergeEntry {
+                merge: f,
+                copy: copy_component::<T>,
+                reflect: false,
+            },
+        );
+        self
+    }
+
+    /// Registers a [`EffectMergeFn`] for a component that implements [`Reflect`] but not [`Clone`].
+    /// The old component is copied using [reflection](bevy_reflect::PartialReflect::reflect_clone),
+    /// which is slower than [`register`](Self::register).
+    ///
+    /// If the component can't be cloned using reflection, it won't be merged.
+    pub fn register_reflect<T: Component + Reflect>(&mut self, f: EffectMergeFn) -> &mut Self {
+        self.merges.insert(
+            TypeId::of::<T>(),
+            MergeEntry {
+                merge: f,
+                copy: copy_component_reflect::<T>,
+                reflect: true,
+            },
+        );
+        self
+    }
+
+    /// Registers a [`EffectReplaceFn`] to be run whenever two `T` status effects collide with
+    /// [`EffectMode::Select`](crate::EffectMode::Select).
+    pub fn register_replacement<T: Component>(&mut self, f: EffectReplaceFn) -> &mut Self {
+        self.replacements.insert(TypeId::of::<T>(), f);
+        self
+    }
+
+    /// Removes the [`EffectMergeFn`] registered for `T`, returning it if there was one.
+    /// After this, `T` will be overwritten rather than merged.
+    pub fn unregister<T: Component>(&mut self) -> Option<EffectMergeFn> {
+        self.merges
+            .remove(&TypeId::of::<T>())
+            .map(|entry| entry.merge)
+    }
+
+    /// Returns true if an [`EffectMergeFn`] is registered for `T`.
+    pub fn contains<T: Component>(&self) -> bool {
+        self.merges.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns an iterator over the types of the components that have an [`EffectMergeFn`] registered, in no particular order.
+    pub fn registered_types(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.merges.keys().copied()
+    }
+
+    /// Returns an iterator over the [type paths](bevy_reflect::TypePath) of the components that have an [`EffectMergeFn`] registered,
+    /// such as for displaying in a debug overlay.
+    ///
+    /// Components that aren't registered in the reflection `type_registry`
+    /// (normally the [`AppTypeRegistry`]) are skipped.
+    pub fn registered_type_paths<'a>(
+        &'a self,
+        type_registry: &'a TypeRegistry,
+    ) -> impl Iterator<Item = &'static str> + 'a {
+        self.registered_types()
+            .filter_map(|id| type_registry.get_type_info(id))
+            .map(|info| info.type_path())
+    }
+}
+
+/// A function used to apply an effect's contribution to its target, which must be registered in the [registry](EffectApplyRegistry).
+/// This is run every frame for each effect with the registered component.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// #[derive(Component)]
+/// struct Regeneration(f32);
+///
+/// fn apply_regeneration(mut target: EntityWorldMut, effect: Entity) {
+///     let amount = target.world().get::<Regeneration>(effect).unwrap().0;
+///
+///     if let Some(mut health) = target.get_mut::<Health>() {
+///         health.0 += amount;
+///     }
+/// }
+/// ```
+pub type EffectApplyFn = fn(target: EntityWorldMut, effect: Entity);
+
+/// Stores the apply logic for each registered effect component.
+/// New components can be registered by providing a [`EffectApplyFn`] to the [`register`](EffectApplyRegistry::register) method.
+/// This function will be run every frame (during [`AlchemySet::ApplyEffects`](crate::AlchemySet::ApplyEffects))
+/// for each effect that has the component.
+///
+/// If an effect has multiple registered components, the order that their functions run in is unspecified.
+#[derive(Resource, Default)]
+pub struct EffectApplyRegistry {
+    pub(crate) applies: HashMap<TypeId, EffectApplyFn>,
+}
+
+impl EffectApplyRegistry {
+    /// Registers a [`EffectApplyFn`] to be run every frame for each `T` status effect.
+    pub fn register<T: Component>(&mut self, f: EffectApplyFn) -> &mut Self {
+        self.applies.insert(TypeId::of::<T>(), f);
         self
     }
 }
+
+/// A function that decides whether an effect is maintained, which must be registered in the [registry](EffectConditionRegistry)
+/// (or used directly in a [`ConditionalEffect`](crate::ConditionalEffect)).
+/// This is run every frame for each effect with the registered component, and the effect is despawned once it returns `false`.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct InHealingZone;
+///
+/// #[derive(Component)]
+/// struct ZoneRegeneration(f32);
+///
+/// fn in_healing_zone(world: &World, target: Entity, _effect: Entity) -> bool {
+///     world.get::<InHealingZone>(target).is_some()
+/// }
+/// ```
+pub type EffectConditionFn = fn(world: &World, target: Entity, effect: Entity) -> bool;
+
+/// Stores the conditions for each registered effect component.
+/// New components can be registered by providing a [`EffectConditionFn`] to the [`register`](EffectConditionRegistry::register) method.
+/// This function will be run every frame (before [`AlchemySet::ApplyEffects`](crate::AlchemySet::ApplyEffects))
+/// for each effect that has the component, and the effect is despawned once it returns `false`.
+///
+/// If an effect has multiple registered components, all of their conditions must hold.
+#[derive(Resource, Default)]
+pub struct EffectConditionRegistry {
+    pub(crate) conditions: HashMap<TypeId, EffectConditionFn>,
+}
+
+impl EffectConditionRegistry {
+    /// Registers a [`EffectConditionFn`] to be checked every frame for each `T` status effect.
+    pub fn register<T: Component>(&mut self, f: EffectConditionFn) -> &mut Self {
+        self.conditions.insert(TypeId::of::<T>(), f);
+        self
+    }
+}
+
+/// A function that is run when an effect's [`Lifetime`](crate::Lifetime) finishes, right before the effect is despawned,
+/// which must be registered in the [registry](EffectExpireRegistry).
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// /// A shield that explodes when it expires, damaging its target.
+/// #[derive(Component)]
+/// struct ExplodingShield(f32);
+///
+/// fn explode_shield(mut target: EntityWorldMut, effect: Entity) {
+///     let damage = target.world().get::<ExplodingShield>(effect).unwrap().0;
+///
+///     if let Some(mut health) = target.get_mut::<Health>() {
+///         health.0 -= damage;
+///     }
+/// }
+/// ```
+#[cfg(feature = "timers")]
+pub type EffectExpireFn = fn(target: EntityWorldMut, effect: Entity);
+
+/// Stores the expire logic for each registered effect component.
+/// New components can be registered by providing a [`EffectExpireFn`] to the [`register`](EffectExpireRegistry::register) method.
+/// This function will be run when an effect with the component expires (during [`AlchemySet::DespawnExpired`](crate::AlchemySet::DespawnExpired)),
+/// while the effect still exists.
+///
+/// Effects that are [lingering](crate::LingerOnExpire) run their functions when they are despawned, after lingering.
+/// Effects that are despawned for any other reason (such as being [cleared](crate::EffectCommandsExt::clear_effects))
+/// don't run their functions. For that, use an observer instead.
+///
+/// If an effect has multiple registered components, the order that their functions run in is unspecified.
+#[cfg(feature = "timers")]
+#[derive(Resource, Default)]
+pub struct EffectExpireRegistry {
+    pub(crate) expires: HashMap<TypeId, EffectExpireFn>,
+}
+
+#[cfg(feature = "timers")]
+impl EffectExpireRegistry {
+    /// Registers a [`EffectExpireFn`] to be run whenever a `T` status effect expires.
+    pub fn register<T: Component>(&mut self, f: EffectExpireFn) -> &mut Self {
+        self.expires.insert(TypeId::of::<T>(), f);
+        self
+    }
+}
+
+/// Stores the [`EffectMode`] for each registered effect name, so that a mode can be declared once (such as "poison is always merged"),
+/// rather than on every [`EffectBundle`]. This prevents applications of the same effect from accidentally using different modes.
+///
+/// The registered mode is used when an effect with the name is applied, unless the bundle sets its own [mode](EffectBundle::mode)
+/// (including [`EffectMode::Stack`]), in which case the bundle's mode is used instead.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component, Default)]
+/// struct Poison;
+///
+/// fn main() {
+///     let mut world = World::new();
+///
+///     world.get_resource_or_init::<EffectModeRegistry>()
+///         .register("Poison", EffectMode::Merge);
+/// }
+///
+/// fn apply_poison(mut commands: Commands, target: Entity) {
+///     // This is merged, without needing to set the mode.
+///     commands.entity(target).with_effect(EffectBundle {
+///         name: Name::new("Poison"),
+///         bundle: Poison,
+///         ..Default::default()
+///     });
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct EffectModeRegistry {
+    pub(crate) modes: HashMap<Cow<'static, str>, EffectMode>,
+}
+
+impl EffectModeRegistry {
+    /// Registers the mode that is used whenever an effect with the given name is applied.
+    pub fn register(&mut self, name: impl Into<Cow<'static, str>>, mode: EffectMode) -> &mut Self {
+        self.modes.insert(name.into(), mode);
+        self
+    }
+
+    /// Returns the mode registered for the given name, if there is one.
+    pub fn get(&self, name: &str) -> Option<EffectMode> {
+        self.modes.get(name).copied()
+    }
+}
+
+/// Sets the bundle's mode using the [`EffectModeRegistry`] (or the default mode), unless the bundle already has one.
+pub(crate) fn resolve_mode<B: Bundle>(world: &World, bundle: &mut EffectBundle<B>) {
+    if bundle.mode.is_some() {
+        return;
+    }
+
+    bundle.mode = Some(
+        world
+            .get_resource::<EffectModeRegistry>()
+            .and_then(|registry| registry.get(bundle.name.as_str()))
+            .unwrap_or_default(),
+    );
+}
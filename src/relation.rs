@@ -1,19 +1,147 @@
 use crate::ReflectComponent;
-use bevy_ecs::prelude::{Component, Entity};
+#[cfg(feature = "timers")]
+use bevy_ecs::prelude::Query;
+use bevy_ecs::prelude::{Component, Entity, Name, World};
+#[cfg(feature = "timers")]
+use bevy_ecs::query::QueryFilter;
 use bevy_reflect::Reflect;
+#[cfg(feature = "timers")]
+use std::cmp::Ordering;
+use std::time::Duration;
 
 /// Stores the entity that is being effected by this status effect.
+///
+/// The target can be read using [`target`](Self::target) (or [`get_target`](Self::get_target), given only the effect entity).
 #[derive(Component, Reflect, Eq, PartialEq, Debug, Clone)]
 #[relationship(relationship_target = EffectedBy)]
+#[cfg_attr(feature = "timers", require(crate::EffectAge))]
 #[reflect(Component, PartialEq, Debug, Clone)]
 pub struct Effecting(pub Entity);
 
+impl Effecting {
+    /// Returns the entity that this effect is applied to.
+    pub fn target(&self) -> Entity {
+        self.0
+    }
+
+    /// Returns the target of an effect entity,
+    /// or `None` if the entity doesn't exist or isn't applied to a target (such as an [unbound](crate::AlchemyCommandsExt::spawn_effect) effect).
+    pub fn get_target(world: &World, effect: Entity) -> Option<Entity> {
+        world
+            .get_entity(effect)
+            .ok()?
+            .get::<Effecting>()
+            .map(Effecting::target)
+    }
+}
+
 /// Stores all the status effects that are effecting this entity.
+///
+/// When this entity is despawned, all of its effects are despawned as well,
+/// so effects never point at a despawned target.
 #[derive(Component, Reflect, Eq, PartialEq, Debug, Clone)]
 #[relationship_target(relationship = Effecting, linked_spawn)]
 #[reflect(Component, PartialEq, Debug, Clone)]
 pub struct EffectedBy(Vec<Entity>);
 
+impl EffectedBy {
+    /// Returns the number of effects that are currently effecting this entity.
+    pub fn count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if any of the effects have the given [`Name`].
+    ///
+    /// Inside systems, the [`EffectReader`](crate::EffectReader) can be used instead.
+    pub fn has_effect(&self, world: &World, name: &str) -> bool {
+        self.0.iter().any(|entity| {
+            world
+                .get::<Name>(*entity)
+                .is_some_and(|n| n.as_str() == name)
+        })
+    }
+}
+
+#[cfg(feature = "timers")]
+impl EffectedBy {
+    /// Returns the effects sorted by their remaining [`Lifetime`](crate::Lifetime),
+    /// with the soonest to expire first (or last, if `descending` is true).
+    ///
+    /// Effects without a lifetime are always sorted last, and ties are ordered by entity index, so the order is stable between frames.
+    ///
+    /// Inside systems, [`sorted_by_lifetime_in`](Self::sorted_by_lifetime_in) can be used instead.
+    pub fn sorted_by_lifetime(&self, world: &World, descending: bool) -> Vec<Entity> {
+        self.sort_by_lifetime(|entity| world.get::<crate::Lifetime>(entity), descending)
+    }
+
+    /// Returns the effects sorted by their remaining [`Lifetime`](crate::Lifetime), using a query to read the lifetimes.
+    /// See [`sorted_by_lifetime`](Self::sorted_by_lifetime).
+    pub fn sorted_by_lifetime_in<F: QueryFilter>(
+        &self,
+        lifetimes: &Query<&crate::Lifetime, F>,
+        descending: bool,
+    ) -> Vec<Entity> {
+        self.sort_by_lifetime(|entity| lifetimes.get(entity).ok(), descending)
+    }
+
+    fn sort_by_lifetime<'a>(
+        &self,
+        lifetime: impl Fn(Entity) -> Option<&'a crate::Lifetime>,
+        descending: bool,
+    ) -> Vec<Entity> {
+        let mut effects: Vec<_> = self
+            .0
+            .iter()
+            .map(|&entity| (lifetime(entity).map(|l| l.timer.remaining()), entity))
+            .collect();
+
+        effects.sort_by(|(a, a_entity), (b, b_entity)| {
+            let order = match (a, b) {
+                (Some(a), Some(b)) if descending => b.cmp(a),
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+            order.then(a_entity.index_u32().cmp(&b_entity.index_u32()))
+        });
+
+        effects.into_iter().map(|(_, entity)| entity).collect()
+    }
+}
+
+/// Returns the number of stacks on an effect, counting effects without stacks as a single stack.
+#[cfg(feature = "stacks")]
+pub(crate) fn stack_count(world: &World, effect: Entity) -> u32 {
+    if let Some(stacks) = world.get::<crate::EffectStacksU32>(effect) {
+        return stacks.0;
+    }
+
+    world
+        .get::<crate::EffectStacks>(effect)
+        .map_or(1, |stacks| stacks.0.into())
+}
+
+#[cfg(not(feature = "stacks"))]
+pub(crate) fn stack_count(_world: &World, _effect: Entity) -> u32 {
+    1
+}
+
+/// Returns the time remaining on an effect's [`Lifetime`](crate::Lifetime),
+/// or `None` if it doesn't have one or it is [permanent](crate::Lifetime::permanent).
+#[cfg(feature = "timers")]
+pub(crate) fn remaining_lifetime(world: &World, effect: Entity) -> Option<Duration> {
+    world
+        .get::<crate::Lifetime>(effect)
+        .filter(|lifetime| !lifetime.is_permanent())
+        .map(|lifetime| lifetime.timer.remaining())
+}
+
+#[cfg(not(feature = "timers"))]
+pub(crate) fn remaining_lifetime(_world: &World, _effect: Entity) -> Option<Duration> {
+    None
+}
+
 impl<'a> IntoIterator for &'a EffectedBy {
     type Item = <Self::IntoIter as Iterator>::Item;
 
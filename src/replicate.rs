@@ -0,0 +1,155 @@
+use crate::relation::{remaining_lifetime, stack_count};
+use crate::{AddEffectCommand, EffectBundle, EffectedBy, Effecting};
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+use std::time::Duration;
+
+/// A compact snapshot of an effect's state, for replicating effects over the network.
+///
+/// Snapshots are taken on the server using [`from_effect`](Self::from_effect) (or [`from_target`](Self::from_target)),
+/// sent to clients (such as by serializing them using reflection), and then applied using
+/// [`apply_snapshot`](crate::EffectCommandsExt::apply_snapshot).
+/// Only the name, stacks and remaining lifetime are included, so any other components must be replicated separately.
+#[derive(Reflect, Eq, PartialEq, Debug, Default, Clone)]
+#[reflect(PartialEq, Debug, Default, Clone)]
+pub struct EffectSnapshot {
+    /// The [`Name`] of the effect, which is used to find the matching effect when applying the snapshot.
+    pub name: String,
+    /// The stack count to set on the client's effect, which is one for effects without stacks.
+    pub stacks: u32,
+    /// The remaining [`Lifetime`](crate::Lifetime) to reconcile the client's effect with,
+    /// or `None` if the effect never expires.
+    pub remaining: Option<Duration>,
+}
+
+impl EffectSnapshot {
+    /// Captures the state of an effect entity.
+    /// Returns `None` if the entity doesn't exist or isn't applied to a target.
+    pub fn from_effect(world: &World, effect: Entity) -> Option<Self> {
+        let entity = world.get_entity(effect).ok()?;
+        entity.get::<Effecting>()?;
+
+        Some(Self {
+            name: entity
+                .get::<Name>()
+                .map(|name| name.as_str().to_string())
+                .unwrap_or_default(),
+            stacks: stack_count(world, effect),
+            remaining: remaining_lifetime(world, effect),
+        })
+    }
+
+    /// Captures the state of every effect applied to a target entity, in the order they were applied.
+    pub fn from_target(world: &World, target: Entity) -> Vec<Self> {
+        let Some(effected_by) = world.get::<EffectedBy>(target) else {
+            return Vec::new();
+        };
+
+        effected_by
+            .iter()
+            .filter_map(|effect| Self::from_effect(world, effect))
+            .collect()
+    }
+}
+
+/// Updates the first effect on a target entity whose name matches an [`EffectSnapshot`],
+/// or spawns a new effect if there isn't one.
+///
+/// - The stacks are set on the effect's [`EffectStacksU32`](crate::EffectStacksU32) or [`EffectStacks`](crate::EffectStacks)
+///   (saturating at 255). If it has neither, an `EffectStacks` is added when there is more than one stack.
+///   Stacks are ignored without the `stacks` feature.
+/// - The remaining time is set on the effect's [`Lifetime`](crate::Lifetime) using [`reconcile`](crate::Lifetime::reconcile), which is added if missing.
+///   If the snapshot has no remaining time, the lifetime is left unchanged.
+///   Lifetimes are ignored without the `timers` feature.
+///
+/// New effects are applied the same as [`with_effect`](crate::EffectCommandsExt::with_effect),
+/// so they can still be blocked by [immunities](crate::EffectImmunity).
+///
+/// This is normally used via [`apply_snapshot`](crate::EffectCommandsExt::apply_snapshot).
+pub struct ApplyEffectSnapshotCommand {
+    /// The entity to apply the snapshot to.
+    pub target: Entity,
+    /// The snapshot to apply.
+    pub snapshot: EffectSnapshot,
+}
+
+impl Command for ApplyEffectSnapshotCommand {
+    fn apply(self, world: &mut World) {
+        let Ok(target) = world.get_entity(self.target) else {
+            return;
+        };
+
+        let existing = target.get::<EffectedBy>().and_then(|effected_by| {
+            effected_by.iter().find(|effect| {
+                world
+                    .get::<Name>(*effect)
+                    .is_some_and(|name| name.as_str() == self.snapshot.name)
+            })
+        });
+
+        let effect = match existing {
+            Some(effect) => effect,
+            None => {
+                let effect = world.spawn_empty().id();
+                AddEffectCommand {
+                    target: self.target,
+                    bundle: EffectBundle {
+                        name: Name::new(self.snapshot.name.clone()),
+                        bundle: (),
+                        ..Default::default()
+                    },
+                    entity: Some(effect),
+                }
+                .apply(world);
+
+                // The effect was blocked.
+                if world.get::<Effecting>(effect).is_none() {
+                    return;
+                }
+
+                effect
+            }
+        };
+
+        #[cfg(feature = "stacks")]
+        set_stacks(world, effect, self.snapshot.stacks);
+
+        #[cfg(feature = "timers")]
+        if let Some(remaining) = self.snapshot.remaining {
+            set_remaining(world, effect, remaining);
+        }
+
+        #[cfg(not(any(feature = "stacks", feature = "timers")))]
+        let _ = effect;
+    }
+}
+
+#[cfg(feature = "stacks")]
+fn set_stacks(world: &mut World, effect: Entity, stacks: u32) {
+    if let Some(mut stacks_u32) = world.get_mut::<crate::EffectStacksU32>(effect) {
+        stacks_u32.0 = stacks;
+        return;
+    }
+
+    let stacks = crate::EffectStacks(stacks.min(u8::MAX.into()) as u8);
+    match world.get_mut::<crate::EffectStacks>(effect) {
+        Some(mut old) => *old = stacks,
+        None if stacks.0 > 1 => {
+            world.entity_mut(effect).insert(stacks);
+        }
+        None => {}
+    }
+}
+
+#[cfg(feature = "timers")]
+fn set_remaining(world: &mut World, effect: Entity, remaining: Duration) {
+    use crate::{EffectTimer, Lifetime};
+
+    let Some(mut lifetime) = world.get_mut::<Lifetime>(effect) else {
+        world.entity_mut(effect).insert(Lifetime::new(remaining));
+        return;
+    };
+
+    lifetime.reconcile(remaining);
+}
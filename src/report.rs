@@ -0,0 +1,166 @@
+use crate::EffectedBy;
+use crate::relation::{remaining_lifetime, stack_count};
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy_log::info;
+use bevy_reflect::{Reflect, TypeRegistry};
+use std::fmt::Write;
+use std::time::Duration;
+
+/// A summary of a single active effect, for debugging. See [`report_effects`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectSummary {
+    /// The entity that the effect is applied to.
+    pub target: Entity,
+    /// The effect entity.
+    pub effect: Entity,
+    /// The name of the effect, if it has one.
+    pub name: Option<Name>,
+    /// The number of stacks, from [`EffectStacks`](crate::EffectStacks) (or [`EffectStacksU32`](crate::EffectStacksU32)).
+    /// Effects without stacks count as a single stack.
+    pub stacks: u32,
+    /// The time remaining on the effect's [`Lifetime`](crate::Lifetime).
+    /// This is `None` for effects without a lifetime, or with a [permanent](crate::Lifetime::permanent) one.
+    pub remaining: Option<Duration>,
+}
+
+/// Returns a summary of every active effect in the world, grouped by target.
+/// Targets are sorted by entity index, and each target's effects are in the order they were applied.
+///
+/// This is intended for debugging, such as for a console command that dumps every effect.
+/// Effects that are missing optional components (such as a name or stacks) are still included.
+pub fn report_effects(world: &World) -> Vec<EffectSummary> {
+    let Some(mut query) = world.try_query::<(Entity, &EffectedBy)>() else {
+        return Vec::new();
+    };
+
+    let mut targets: Vec<(Entity, &EffectedBy)> = query.iter(world).collect();
+    targets.sort_by_key(|(target, _)| target.index_u32());
+
+    targets
+        .into_iter()
+        .flat_map(|(target, effected_by)| {
+            effected_by.iter().map(move |effect| EffectSummary {
+                target,
+                effect,
+                name: world.get::<Name>(effect).cloned(),
+                stacks: stack_count(world, effect),
+                remaining: remaining_lifetime(world, effect),
+            })
+        })
+        .collect()
+}
+
+/// Logs every active effect in the world, grouped by target.
+///
+/// If the world has an [`AppTypeRegistry`], the values of each effect's reflected components are included.
+/// This can be used as a system, for example with a run condition that checks for a debug key.
+pub fn log_effects(world: &World) {
+    let type_registry = world
+        .get_resource::<AppTypeRegistry>()
+        .map(|registry| registry.read());
+    let mut report = String::new();
+    let mut last_target = None;
+
+    for summary in report_effects(world) {
+        if last_target != Some(summary.target) {
+            last_target = Some(summary.target);
+            let _ = writeln!(report, "{}:", summary.target);
+        }
+
+        let name = summary.name.as_ref().map_or("<unnamed>", Name::as_str);
+        let _ = write!(
+            report,
+            "  {name} ({}): {} stack(s)",
+            summary.effect, summary.stacks
+        );
+
+        if let Some(remaining) = summary.remaining {
+            let _ = write!(report, ", {:.2}s remaining", remaining.as_secs_f32());
+        }
+
+        if let Some(type_registry) = &type_registry {
+            for component in reflect_components(world, type_registry, summary.effect) {
+                let _ = write!(report, "\n    {component:?}");
+            }
+        }
+
+        report.push('\n');
+    }
+
+    if report.is_empty() {
+        info!("No active effects.");
+    } else {
+        info!("Active effects:\n{report}");
+    }
+}
+
+/// An effect alongside the reflected values of its components. See [`reflect_effects`].
+#[derive(Debug)]
+pub struct ReflectedEffect<'w> {
+    /// The effect entity.
+    pub effect: Entity,
+    /// The effect's components that are registered for reflection, in no particular order.
+    pub components: Vec<&'w dyn Reflect>,
+}
+
+/// Returns every effect applied to the target, alongside the reflected values of all of its components, in the order they were applied.
+/// This is intended for generic debug inspectors, which can display effects without knowing their component types.
+///
+/// The `type_registry` is normally the [`AppTypeRegistry`].
+/// Components that aren't registered, or don't reflect [`Component`], are skipped.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// fn inspect(world: &World, target: Entity) {
+///     let type_registry = world.resource::<AppTypeRegistry>().read();
+///
+///     for effect in reflect_effects(world, &type_registry, target) {
+///         println!("{}:", effect.effect);
+///
+///         for component in effect.components {
+///             println!("  {component:?}");
+///         }
+///     }
+/// }
+/// ```
+pub fn reflect_effects<'w>(
+    world: &'w World,
+    type_registry: &TypeRegistry,
+    target: Entity,
+) -> Vec<ReflectedEffect<'w>> {
+    let Some(effected_by) = world.get::<EffectedBy>(target) else {
+        return Vec::new();
+    };
+
+    effected_by
+        .iter()
+        .map(|effect| ReflectedEffect {
+            effect,
+            components: reflect_components(world, type_registry, effect),
+        })
+        .collect()
+}
+
+/// Returns the reflected components of an effect, using the `type_registry`.
+fn reflect_components<'w>(
+    world: &'w World,
+    type_registry: &TypeRegistry,
+    effect: Entity,
+) -> Vec<&'w dyn Reflect> {
+    let entity = world.entity(effect);
+
+    entity
+        .archetype()
+        .components()
+        .iter()
+        .filter_map(|component_id| {
+            let type_id = world.components().get_info(*component_id)?.type_id()?;
+            type_registry
+                .get_type_data::<ReflectComponent>(type_id)?
+                .reflect(entity)
+        })
+        .collect()
+}
@@ -0,0 +1,152 @@
+use crate::command::{find_existing_effect, find_replacement, merge_components, merge_entries};
+use crate::registry::{KeepEffect, MergeEntry};
+use crate::{EffectCategory, EffectId, EffectMode};
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::entity_disabling::Disabled;
+use bevy_ecs::prelude::*;
+
+/// A function that finds the effect on the `target` that an `incoming` effect collides with.
+/// The incoming effect has been spawned, but isn't bound to the target yet.
+pub type EffectMatchFn = fn(world: &World, target: Entity, incoming: Entity) -> Option<Entity>;
+
+/// A function that resolves a collision between an `existing` effect and an `incoming` one.
+/// The incoming effect has already been bound to the target.
+///
+/// Whichever effect isn't kept is despawned afterward, so this shouldn't despawn either of them.
+pub type EffectCollisionFn =
+    fn(world: &mut World, existing: Entity, incoming: Entity) -> KeepEffect;
+
+/// Decides how an effect collides with the effects that are already applied to its target.
+///
+/// Each [`EffectMode`] (other than [`Stack`](EffectMode::Stack)) has a built-in resolver, which can be accessed using [`EffectMode::resolver`].
+/// Custom application policies (such as strongest-wins) can be implemented by setting
+/// [`EffectBundle::resolver`](crate::EffectBundle::resolver), which overrides the mode's resolver.
+///
+/// Effects with a custom resolver are spawned and then [bound](crate::BindEffectCommand),
+/// which is slower than the built-in modes, as they insert directly into the existing effect.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component)]
+/// struct Damage(f32);
+///
+/// // Only keeps the effect that deals the most damage.
+/// fn strongest_wins(world: &mut World, existing: Entity, incoming: Entity) -> KeepEffect {
+///     let damage = |entity| world.get::<Damage>(entity).map_or(0.0, |damage| damage.0);
+///
+///     if damage(existing) > damage(incoming) {
+///         KeepEffect::Old
+///     } else {
+///         KeepEffect::Incoming
+///     }
+/// }
+///
+/// let resolver = EffectResolver::new(strongest_wins);
+/// ```
+#[derive(Component, Debug, Copy, Clone)]
+pub struct EffectResolver {
+    /// Finds the existing effect that the incoming effect collides with.
+    pub find: EffectMatchFn,
+    /// Resolves a collision with the existing effect.
+    pub resolve: EffectCollisionFn,
+}
+
+impl EffectResolver {
+    /// The resolver used by [`EffectMode::Insert`].
+    pub const INSERT: Self = Self::new(resolve_insert);
+    /// The resolver used by [`EffectMode::Merge`].
+    pub const MERGE: Self = Self::new(resolve_merge);
+    /// The resolver used by [`EffectMode::Select`].
+    pub const SELECT: Self = Self::new(resolve_select);
+
+    /// Creates a new resolver, which finds collisions using [`find_matching_effect`].
+    pub const fn new(resolve: EffectCollisionFn) -> Self {
+        Self {
+            find: find_matching_effect,
+            resolve,
+        }
+    }
+
+    /// A builder that overwrites the function used to find collisions.
+    pub const fn with_find(mut self, find: EffectMatchFn) -> Self {
+        self.find = find;
+        self
+    }
+}
+
+impl EffectMode {
+    /// Returns the built-in [`EffectResolver`] for this mode.
+    /// [`Stack`](EffectMode::Stack) effects never collide, so they don't have one.
+    pub const fn resolver(self) -> Option<EffectResolver> {
+        match self {
+            EffectMode::Stack => None,
+            EffectMode::Insert => Some(EffectResolver::INSERT),
+            EffectMode::Merge => Some(EffectResolver::MERGE),
+            EffectMode::Select => Some(EffectResolver::SELECT),
+        }
+    }
+}
+
+/// The default [`EffectMatchFn`], which finds an effect with the same mode
+/// and the same [category](EffectCategory), [ID](EffectId) or [name](Name) (in that order of precedence).
+///
+/// Effects with an empty name only match existing effects that have all of the incoming effect's components.
+pub fn find_matching_effect(world: &World, target: Entity, incoming: Entity) -> Option<Entity> {
+    let incoming = world.get_entity(incoming).ok()?;
+    let name = incoming.get::<Name>()?;
+
+    // The incoming effect is disabled until it is bound, unlike the existing effect.
+    let disabled = world.component_id::<Disabled>();
+    let components: Vec<ComponentId> = if name.is_empty() {
+        incoming
+            .archetype()
+            .components()
+            .iter()
+            .copied()
+            .filter(|component| Some(*component) != disabled)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    find_existing_effect(
+        world,
+        target,
+        *incoming.get::<EffectMode>()?,
+        incoming.get::<EffectCategory>(),
+        incoming.get::<EffectId>(),
+        name,
+        &components,
+    )
+}
+
+/// The [`EffectCollisionFn`] for [`EffectMode::Insert`], which always keeps the incoming effect.
+pub fn resolve_insert(_world: &mut World, _existing: Entity, _incoming: Entity) -> KeepEffect {
+    KeepEffect::Incoming
+}
+
+/// The [`EffectCollisionFn`] for [`EffectMode::Merge`].
+/// The existing effect is merged into the incoming one using the [`EffectMergeRegistry`](crate::EffectMergeRegistry), for registered components that both have.
+pub fn resolve_merge(world: &mut World, existing: Entity, incoming: Entity) -> KeepEffect {
+    let name = world.get::<Name>(incoming).cloned().unwrap_or_default();
+    let components: Vec<ComponentId> = world.entity(incoming).archetype().components().to_vec();
+
+    if let Some(entries) = merge_entries(world, &name, &components, existing) {
+        let entries: Vec<MergeEntry> = entries.into_iter().map(|(_, entry)| entry).collect();
+        merge_components(world, &entries, incoming, existing);
+    }
+
+    KeepEffect::Incoming
+}
+
+/// The [`EffectCollisionFn`] for [`EffectMode::Select`].
+/// The first registered [replacement function](crate::EffectReplaceFn) that both effects have a component for decides.
+pub fn resolve_select(world: &mut World, existing: Entity, incoming: Entity) -> KeepEffect {
+    let components: Vec<ComponentId> = world.entity(incoming).archetype().components().to_vec();
+
+    find_replacement(world, &components, existing).map_or(KeepEffect::Incoming, |replace| {
+        replace(world, existing, incoming)
+    })
+}
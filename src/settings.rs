@@ -0,0 +1,50 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_reflect::prelude::ReflectDefault;
+
+/// Global configuration for how effects get applied.
+#[derive(Resource, Reflect, Eq, PartialEq, Debug, Clone)]
+#[reflect(Resource, PartialEq, Debug, Default, Clone)]
+pub struct AlchemySettings {
+    /// Whether an effect that gets [inserted](crate::EffectMode::Insert) or [merged](crate::EffectMode::Merge)
+    /// into an existing effect is marked as [just applied](crate::EffectJustApplied).
+    ///
+    /// Defaults to `true`.
+    pub mark_reapplied: bool,
+    /// Whether [merge-mode](crate::EffectMode::Merge) effects automatically track their number of stacks,
+    /// by adding `EffectStacks` when they are applied. Requires the `stacks` feature.
+    ///
+    /// This can be disabled if merge effects shouldn't track stacks,
+    /// in which case `EffectStacks` can still be added manually to specific effects.
+    ///
+    /// Defaults to `true`.
+    pub track_merge_stacks: bool,
+    /// Whether effects are checked for likely misconfigurations when they are applied,
+    /// such as a merge-mode effect with a component that has no registered [merge function](crate::EffectMergeFn).
+    /// Problems are logged as warnings, and this only runs in debug builds.
+    ///
+    /// Defaults to `true`.
+    pub validate_effects: bool,
+    /// Whether setup mistakes panic instead of logging a warning, so they are caught immediately in tests and during development.
+    /// This covers merging effects without an [`EffectMergeRegistry`](crate::EffectMergeRegistry),
+    /// and (if [`validate_effects`](Self::validate_effects) is enabled) any misconfiguration found by validation,
+    /// such as merging a component that has no registered [merge function](crate::EffectMergeFn).
+    /// This only applies in debug builds, so release builds always fall back to warning.
+    ///
+    /// The settings are added by the [`AlchemyPlugin`](crate::AlchemyPlugin),
+    /// so to catch a missing plugin, this resource must be inserted manually.
+    ///
+    /// Defaults to `false`.
+    pub strict: bool,
+}
+
+impl Default for AlchemySettings {
+    fn default() -> Self {
+        Self {
+            mark_reapplied: true,
+            track_merge_stacks: true,
+            validate_effects: true,
+            strict: false,
+        }
+    }
+}
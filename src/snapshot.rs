@@ -0,0 +1,122 @@
+use crate::Effecting;
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use std::marker::PhantomData;
+
+/// Captures the `T` component of an effect's target into a [`Snapshot<T>`] whenever the effect is applied.
+///
+/// This is not included in the [`AlchemyPlugin`](crate::AlchemyPlugin), and must be added for each captured `T`.
+pub struct SnapshotPlugin<T: Component + Clone> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Component + Clone> Plugin for SnapshotPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(snapshot_on_insert::<T>)
+            .add_observer(snapshot_on_bind::<T>);
+    }
+}
+
+impl<T: Component + Clone> SnapshotPlugin<T> {
+    /// Creates a new plugin that captures the target's `T` component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Component + Clone> Default for SnapshotPlugin<T> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Stores a copy of the target's `T` component from when the effect was applied.
+/// Requires the [`SnapshotPlugin<T>`].
+///
+/// There are two common ways for an effect to scale with the stats of its target:
+/// - **Dynamic** effects read the target's current stats every frame, so they change as the target does.
+/// - **Snapshot** effects capture the target's stats once, when they are applied, and then use that fixed value.
+///
+/// Adding an empty `Snapshot<T>` to the effect's bundle makes it a snapshot effect.
+/// The snapshot is retaken when the effect is reapplied (or [bound](crate::EffectCommandsExt::bind_effect) to a new target).
+/// If the target doesn't have `T`, the snapshot stays empty.
+///
+/// # Example
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// #[derive(Component, Clone)]
+/// struct MaxHealth(f32);
+///
+/// /// Heals a percentage of the target's max health, as it was when the effect was applied.
+/// #[derive(Component, Default)]
+/// struct Regeneration {
+///     percent: f32,
+/// }
+///
+/// fn apply(mut commands: Commands, target: Single<Entity, With<MaxHealth>>) {
+///     commands.entity(*target).with_effect(EffectBundle {
+///         bundle: (Regeneration { percent: 0.1 }, Snapshot::<MaxHealth>::default()),
+///         ..Default::default()
+///     });
+/// }
+///
+/// fn regenerate(effects: Query<(&Regeneration, &Snapshot<MaxHealth>)>) {
+///     for (regeneration, snapshot) in &effects {
+///         let Some(max_health) = snapshot.get() else {
+///             continue;
+///         };
+///
+///         let amount = max_health.0 * regeneration.percent;
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct Snapshot<T: Component + Clone> {
+    value: Option<T>,
+}
+
+impl<T: Component + Clone> Snapshot<T> {
+    /// Returns the captured value, or `None` if it hasn't been captured yet (or the target didn't have `T`).
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+}
+
+impl<T: Component + Clone> Default for Snapshot<T> {
+    fn default() -> Self {
+        Self { value: None }
+    }
+}
+
+fn capture<T: Component + Clone>(
+    effect: Entity,
+    effects: &mut Query<(&Effecting, &mut Snapshot<T>)>,
+    targets: &Query<&T>,
+) {
+    let Ok((target, mut snapshot)) = effects.get_mut(effect) else {
+        return;
+    };
+
+    snapshot.value = targets.get(target.0).ok().cloned();
+}
+
+fn snapshot_on_insert<T: Component + Clone>(
+    insert: On<Insert, Snapshot<T>>,
+    mut effects: Query<(&Effecting, &mut Snapshot<T>)>,
+    targets: Query<&T>,
+) {
+    capture(insert.entity, &mut effects, &targets);
+}
+
+/// Captures effects that already had a [`Snapshot<T>`] before being bound to a target.
+fn snapshot_on_bind<T: Component + Clone>(
+    add: On<Add, Effecting>,
+    mut effects: Query<(&Effecting, &mut Snapshot<T>)>,
+    targets: Query<&T>,
+) {
+    capture(add.entity, &mut effects, &targets);
+}
@@ -0,0 +1,60 @@
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_time::Time;
+use std::time::Duration;
+
+/// Replaces real time with manually advanced time, so that effect timers can be tested deterministically.
+///
+/// Time only advances when [`advance_time`](TestTimeExt::advance_time) is called.
+/// Any other update has a delta of zero, so timers won't tick.
+///
+/// Do not add this alongside Bevy's `TimePlugin`.
+///
+/// # Example
+/// ```rust
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_alchemy::*;
+/// # use std::time::Duration;
+/// let mut app = App::new();
+/// app.add_plugins((AlchemyPlugin, TestTimePlugin));
+///
+/// let target = app.world_mut().spawn_empty().id();
+/// app.world_mut().commands().entity(target).with_effect(EffectBundle {
+///     bundle: Lifetime::from_seconds(4.0),
+///     ..Default::default()
+/// });
+///
+/// app.advance_time(Duration::from_secs(3));
+/// assert!(app.world().get::<EffectedBy>(target).is_some());
+///
+/// app.advance_time(Duration::from_secs(1));
+/// assert!(app.world().get::<EffectedBy>(target).is_none());
+/// ```
+pub struct TestTimePlugin;
+
+impl Plugin for TestTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Time>()
+            .add_systems(Last, reset_time_delta);
+    }
+}
+
+/// Zeroes the delta at the end of each update, so that time only advances when requested.
+fn reset_time_delta(mut time: ResMut<Time>) {
+    time.advance_by(Duration::ZERO);
+}
+
+/// An extension trait for advancing time in an [`App`] that uses the [`TestTimePlugin`].
+pub trait TestTimeExt {
+    /// Advances time by `delta`, and then runs a single update.
+    fn advance_time(&mut self, delta: Duration) -> &mut Self;
+}
+
+impl TestTimeExt for App {
+    fn advance_time(&mut self, delta: Duration) -> &mut Self {
+        self.world_mut().resource_mut::<Time>().advance_by(delta);
+        self.update();
+        self
+    }
+}
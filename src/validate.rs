@@ -0,0 +1,133 @@
+use crate::{
+    AlchemySettings, EffectBundle, EffectMergeRegistry, EffectMode, EffectSources, Magnitude,
+    StackContributions,
+};
+use bevy_ecs::prelude::*;
+use std::any::TypeId;
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+/// Panics if [`AlchemySettings::strict`] is enabled (in debug builds), otherwise logs a warning once per message.
+macro_rules! warn_or_panic {
+    ($world:expr, $($arg:tt)+) => {
+        if $crate::validate::is_strict($world) {
+            panic!($($arg)+);
+        } else {
+            $crate::validate::warn_once(format!($($arg)+));
+        }
+    };
+}
+
+pub(crate) use warn_or_panic;
+
+/// Logs a warning, unless the same message has already been logged.
+/// Unlike [`warn_once!`](bevy_log::warn_once), this is deduplicated by message rather than by callsite,
+/// so a single check can still warn about every misconfigured effect and component.
+pub(crate) fn warn_once(message: String) {
+    static LOGGED: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(Default::default);
+
+    let mut logged = LOGGED.lock().unwrap_or_else(PoisonError::into_inner);
+    if !logged.contains(&message) {
+        bevy_log::warn!("{message}");
+        logged.insert(message);
+    }
+}
+
+/// Returns true if [`AlchemySettings::strict`] is enabled, which only applies in debug builds.
+pub(crate) fn is_strict(world: &World) -> bool {
+    cfg!(debug_assertions)
+        && world
+            .get_resource::<AlchemySettings>()
+            .is_some_and(|settings| settings.strict)
+}
+
+/// Logs warnings about likely misconfigured effects, such as a merge effect with a component that has no merge function.
+/// This only runs in debug builds, and can be disabled using [`AlchemySettings::validate_effects`].
+pub(crate) fn validate_effect<B: Bundle>(world: &mut World, bundle: &EffectBundle<B>) {
+    if !cfg!(debug_assertions)
+        || !world
+            .get_resource::<AlchemySettings>()
+            .is_none_or(|settings| settings.validate_effects)
+    {
+        return;
+    }
+
+    let ids: Vec<_> = B::component_ids(&mut world.components_registrator()).collect();
+    let components: HashSet<TypeId> = ids
+        .into_iter()
+        .filter_map(|id| world.components().get_info(id)?.type_id())
+        .collect();
+
+    let name = &bundle.name;
+    let has = |id: TypeId| components.contains(&id);
+
+    if bundle.resolved_mode() == EffectMode::Merge {
+        // A missing registry is already warned about when merging.
+        let registry = world.get_resource::<EffectMergeRegistry>();
+        let check_merge = |present: bool, id: TypeId, component: &str| {
+            if present && registry.is_some_and(|registry| !registry.merges.contains_key(&id)) {
+                warn_or_panic!(
+                    world,
+                    "Effect `{name}` uses `EffectMode::Merge` and has a `{component}`, but no merge function is registered for it, \
+                    so it will be overwritten instead."
+                );
+            }
+        };
+
+        let id = TypeId::of::<Magnitude>();
+        check_merge(has(id), id, "Magnitude");
+        let id = TypeId::of::<EffectSources>();
+        check_merge(has(id), id, "EffectSources");
+        let id = TypeId::of::<StackContributions>();
+        check_merge(has(id), id, "StackContributions");
+        #[cfg(feature = "timers")]
+        {
+            let id = TypeId::of::<crate::Lifetime>();
+            check_merge(has(id), id, "Lifetime");
+            let id = TypeId::of::<crate::Delay>();
+            check_merge(has(id), id, "Delay");
+        }
+        #[cfg(feature = "stacks")]
+        {
+            // Stacks can also be added automatically, rather than being in the bundle.
+            let id = TypeId::of::<crate::EffectStacks>();
+            let stacks = has(id) || crate::command::initial_stacks(world, bundle).is_some();
+            check_merge(stacks, id, "EffectStacks");
+            let id = TypeId::of::<crate::EffectStacksU32>();
+            check_merge(has(id), id, "EffectStacksU32");
+        }
+    }
+
+    #[cfg(feature = "stacks")]
+    if bundle.resolved_mode() != EffectMode::Merge && has(TypeId::of::<crate::StackMergeMode>()) {
+        warn_or_panic!(
+            world,
+            "Effect `{name}` has a `StackMergeMode`, but uses `EffectMode::{:?}`. Stacks are only merged with `EffectMode::Merge`.",
+            bundle.resolved_mode()
+        );
+    }
+
+    #[cfg(feature = "timers")]
+    {
+        if has(TypeId::of::<crate::TickCount>()) && !has(TypeId::of::<crate::Delay>()) {
+            warn_or_panic!(
+                world,
+                "Effect `{name}` has a `TickCount`, but no `Delay`, so it will never be despawned."
+            );
+        }
+
+        let lifetime = has(TypeId::of::<crate::Lifetime>());
+        if !lifetime && has(TypeId::of::<crate::LingerOnExpire>()) {
+            warn_or_panic!(
+                world,
+                "Effect `{name}` has `LingerOnExpire`, but no `Lifetime`, so it will never expire."
+            );
+        }
+        if !lifetime && has(TypeId::of::<crate::LifetimeScaling>()) {
+            warn_or_panic!(
+                world,
+                "Effect `{name}` has a `LifetimeScaling`, but no `Lifetime`, so it will never be scaled."
+            );
+        }
+    }
+}
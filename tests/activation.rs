@@ -0,0 +1,142 @@
+//! Tests delaying effects from becoming active using [`ActivationDelay`].
+#![cfg(feature = "test_utils")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Warmup;
+
+fn init_app(activation: ActivationDelay, mode: EffectMode) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin));
+
+    let target = app.world_mut().spawn_empty().id();
+    apply(&mut app, target, activation, mode);
+
+    (app, target)
+}
+
+fn apply(app: &mut App, target: Entity, activation: ActivationDelay, mode: EffectMode) {
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            mode: Some(mode),
+            ..EffectBundle::new((
+                Warmup,
+                activation,
+                Lifetime::from_seconds(3.0),
+                Delay::from_seconds(1.0),
+            ))
+        });
+    app.world_mut().flush();
+}
+
+fn effect(app: &mut App) -> Entity {
+    app.world_mut()
+        .query_filtered::<Entity, With<Warmup>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[test]
+fn inactive_until_finished() {
+    let (mut app, _) = init_app(ActivationDelay::from_seconds(2.0), EffectMode::Stack);
+    let effect = effect(&mut app);
+    assert!(app.world().entity(effect).contains::<EffectInactive>());
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().entity(effect).contains::<EffectInactive>());
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(!app.world().entity(effect).contains::<EffectInactive>());
+
+    // The lifetime and delay didn't tick during the warmup.
+    let entity = app.world().entity(effect);
+    assert_eq!(
+        entity.get::<Lifetime>().unwrap().timer.elapsed(),
+        Duration::ZERO
+    );
+    assert_eq!(
+        entity.get::<Delay>().unwrap().timer.elapsed(),
+        Duration::ZERO
+    );
+
+    app.advance_time(Duration::from_secs(1));
+    let entity = app.world().entity(effect);
+    assert_eq!(
+        entity.get::<Lifetime>().unwrap().timer.elapsed(),
+        Duration::from_secs(1)
+    );
+    assert_eq!(entity.get::<Delay>().unwrap().ticks_this_frame(), 1);
+}
+
+#[test]
+fn lifetime_ticking() {
+    let (mut app, target) = init_app(
+        ActivationDelay::from_seconds(2.0).with_lifetime_ticking(),
+        EffectMode::Stack,
+    );
+    let effect = effect(&mut app);
+
+    app.advance_time(Duration::from_secs(2));
+    assert_eq!(
+        app.world().get::<Lifetime>(effect).unwrap().timer.elapsed(),
+        Duration::from_secs(2)
+    );
+
+    // The effect expires one second after becoming active.
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn merge_keeps_progress() {
+    let (mut app, target) = init_app(ActivationDelay::from_seconds(2.0), EffectMode::Merge);
+    let effect = effect(&mut app);
+
+    app.advance_time(Duration::from_secs(2));
+    assert!(!app.world().entity(effect).contains::<EffectInactive>());
+
+    // Reapplying doesn't restart the warmup.
+    apply(
+        &mut app,
+        target,
+        ActivationDelay::from_seconds(2.0),
+        EffectMode::Merge,
+    );
+    assert!(!app.world().entity(effect).contains::<EffectInactive>());
+}
+
+#[test]
+fn apply_fns_skip_inactive() {
+    #[derive(Component, Default)]
+    struct Applied(u32);
+
+    fn count(mut target: EntityWorldMut, _effect: Entity) {
+        target.get_mut::<Applied>().unwrap().0 += 1;
+    }
+
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin));
+    app.world_mut()
+        .resource_mut::<EffectApplyRegistry>()
+        .register::<Warmup>(count);
+
+    let target = app.world_mut().spawn(Applied::default()).id();
+    apply(
+        &mut app,
+        target,
+        ActivationDelay::from_seconds(2.0),
+        EffectMode::Stack,
+    );
+
+    app.advance_time(Duration::from_secs(1));
+    assert_eq!(app.world().get::<Applied>(target).unwrap().0, 0);
+
+    app.advance_time(Duration::from_secs(1));
+    assert_eq!(app.world().get::<Applied>(target).unwrap().0, 1);
+}
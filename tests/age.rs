@@ -0,0 +1,104 @@
+//! Tests the behaviour of [`EffectAge`].
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use std::time::Duration;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn init_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin).init_resource::<Time>();
+    app
+}
+
+fn advance(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+}
+
+/// Applies an effect, advances by one second, and then reapplies it.
+fn reapply(mode: EffectMode, bundle: impl Bundle + Clone + Default) -> Duration {
+    let mut app = init_app();
+
+    let target = app.world_mut().spawn_empty().id();
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            mode: Some(mode),
+            bundle: bundle.clone(),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    advance(&mut app);
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            mode: Some(mode),
+            bundle,
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    app.world().get::<EffectAge>(effect).unwrap().elapsed
+}
+
+#[test]
+fn age_ticks() {
+    let mut app = init_app();
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    advance(&mut app);
+    advance(&mut app);
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    assert_eq!(
+        app.world().get::<EffectAge>(effect).unwrap().elapsed,
+        Duration::from_secs(2)
+    );
+}
+
+#[test]
+fn insert_keeps_age() {
+    assert_eq!(
+        reapply(EffectMode::Insert, MyEffect(0)),
+        Duration::from_secs(1)
+    );
+}
+
+#[test]
+fn merge_keep() {
+    assert_eq!(
+        reapply(EffectMode::Merge, EffectAge::with_mode(AgeMergeMode::Keep)),
+        Duration::from_secs(1)
+    );
+}
+
+#[test]
+fn merge_reset() {
+    assert_eq!(
+        reapply(EffectMode::Merge, EffectAge::with_mode(AgeMergeMode::Reset)),
+        Duration::ZERO
+    );
+}
@@ -0,0 +1,54 @@
+//! Tests the behaviour of the [`EffectApplyRegistry`].
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq)]
+struct Health(u8);
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Regeneration(u8);
+
+fn apply_regeneration(mut target: EntityWorldMut, effect: Entity) {
+    let amount = target.world().get::<Regeneration>(effect).unwrap().0;
+
+    if let Some(mut health) = target.get_mut::<Health>() {
+        health.0 += amount;
+    }
+}
+
+#[test]
+fn apply_every_frame() {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin).init_resource::<Time>();
+    app.world_mut()
+        .resource_mut::<EffectApplyRegistry>()
+        .register::<Regeneration>(apply_regeneration);
+
+    let target = app.world_mut().spawn(Health(0)).id();
+    let untouched = app.world_mut().spawn(Health(0)).id();
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            mode: Some(EffectMode::Stack),
+            bundle: Regeneration(1),
+            ..Default::default()
+        })
+        .with_effect(EffectBundle {
+            mode: Some(EffectMode::Stack),
+            bundle: Regeneration(2),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(6)));
+    assert_eq!(app.world().get::<Health>(untouched), Some(&Health(0)));
+}
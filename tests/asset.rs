@@ -0,0 +1,111 @@
+//! Tests loading and applying effects from RON using [`EffectAsset`].
+#![cfg(feature = "assets")]
+
+use bevy_alchemy::*;
+use bevy_asset::Assets;
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::Reflect;
+use std::time::Duration;
+
+#[derive(Component, Reflect, Debug, PartialEq, Default, Clone)]
+#[reflect(Component)]
+struct Poison {
+    damage: f32,
+}
+
+const POISON: &str = r#"(
+    name: "Poison",
+    mode: Insert,
+    lifetime: Some(5.0),
+    delay: Some(1.0),
+    components: {
+        "asset::Poison": (damage: 2.0),
+    },
+)"#;
+
+fn init_world() -> World {
+    let mut world = World::new();
+
+    let registry = AppTypeRegistry::default();
+    {
+        let mut registry = registry.write();
+        registry.register::<Poison>();
+        registry.register::<Lifetime>();
+        registry.register::<Delay>();
+    }
+    world.insert_resource(registry);
+    world.init_resource::<Assets<EffectAsset>>();
+    world
+}
+
+fn load(world: &World, ron: &str) -> EffectAsset {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    EffectAsset::from_ron(ron, &registry).unwrap()
+}
+
+#[test]
+fn deserialize() {
+    let world = init_world();
+    let asset = load(&world, POISON);
+
+    assert_eq!(asset.name, Name::new("Poison"));
+    assert_eq!(asset.mode, Some(EffectMode::Insert));
+    assert_eq!(asset.lifetime, Some(Duration::from_secs(5)));
+    assert_eq!(asset.delay, Some(Duration::from_secs(1)));
+    assert_eq!(asset.components.len(), 1);
+}
+
+#[test]
+fn defaults() {
+    let world = init_world();
+    let asset = load(&world, r#"(name: "Empty")"#);
+
+    assert_eq!(asset.mode, None);
+    assert_eq!(asset.lifetime, None);
+    assert_eq!(asset.delay, None);
+    assert!(asset.components.is_empty());
+}
+
+#[test]
+fn unregistered_component() {
+    let world = init_world();
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    let result = EffectAsset::from_ron(
+        r#"(name: "Unknown", components: { "asset::Unknown": () })"#,
+        &registry,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn apply() {
+    let mut world = init_world();
+    let asset = load(&world, POISON);
+    let handle = world.resource_mut::<Assets<EffectAsset>>().add(asset);
+
+    let target = world.spawn_empty().id();
+    for _ in 0..2 {
+        world
+            .commands()
+            .entity(target)
+            .with_effect_asset(handle.clone());
+    }
+    world.flush();
+
+    let effects = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effects.len(), 1);
+
+    let effect = world.entity(effects.collection()[0]);
+    assert_eq!(effect.get::<Name>(), Some(&Name::new("Poison")));
+    assert_eq!(effect.get::<Poison>(), Some(&Poison { damage: 2.0 }));
+    assert_eq!(
+        effect.get::<Lifetime>().unwrap().timer.duration(),
+        Duration::from_secs(5)
+    );
+    assert_eq!(
+        effect.get::<Delay>().unwrap().timer.duration(),
+        Duration::from_secs(1)
+    );
+}
@@ -0,0 +1,79 @@
+//! Tests the behaviour of applying many effects at once.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+#[test]
+fn batch_stack() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_stacked_effects((0..5).map(|i| EffectBundle {
+            mode: Some(EffectMode::Stack),
+            bundle: MyEffect(i),
+            ..Default::default()
+        }));
+
+    world.flush();
+
+    let effected_by = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effected_by.len(), 5);
+
+    for effect in effected_by.iter() {
+        assert_eq!(world.get::<Effecting>(effect), Some(&Effecting(target)));
+        assert!(world.get::<EffectJustApplied>(effect).is_some());
+    }
+}
+
+#[test]
+fn batch_mixed_modes() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_stacked_effects(
+        [EffectMode::Stack, EffectMode::Insert, EffectMode::Insert].map(|mode| EffectBundle {
+            mode: Some(mode),
+            bundle: MyEffect(0),
+            ..Default::default()
+        }),
+    );
+
+    world.flush();
+
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 2);
+}
+
+#[test]
+fn batch_immunity() {
+    let mut world = World::new();
+
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::name("Blocked")))
+        .id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_stacked_effects(["Blocked", "Allowed"].map(|name| EffectBundle {
+            name: Name::new(name),
+            bundle: MyEffect(0),
+            ..Default::default()
+        }));
+
+    world.flush();
+
+    let effected_by = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effected_by.len(), 1);
+    assert_eq!(
+        world.get::<Name>(effected_by.collection()[0]),
+        Some(&Name::new("Allowed"))
+    );
+}
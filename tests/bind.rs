@@ -0,0 +1,119 @@
+//! Tests spawning unbound effects and binding them to targets later.
+
+use bevy_alchemy::*;
+use bevy_ecs::entity_disabling::Disabled;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn spawn_unbound(world: &mut World, mode: EffectMode, value: u8) -> Entity {
+    let effect = world
+        .commands()
+        .spawn_effect(EffectBundle {
+            name: Name::new("Effect"),
+            mode: Some(mode),
+            bundle: MyEffect(value),
+            ..Default::default()
+        })
+        .id();
+    world.flush();
+    effect
+}
+
+#[test]
+fn unbound_is_disabled() {
+    let mut world = World::new();
+    let effect = spawn_unbound(&mut world, EffectMode::Stack, 0);
+
+    let effect = world.entity(effect);
+    assert!(effect.contains::<Disabled>());
+    assert!(!effect.contains::<Effecting>());
+    assert_eq!(effect.get::<MyEffect>(), Some(&MyEffect(0)));
+    assert_eq!(effect.get::<Name>(), Some(&Name::new("Effect")));
+}
+
+#[test]
+fn bind() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+    let effect = spawn_unbound(&mut world, EffectMode::Stack, 0);
+
+    world.commands().entity(target).bind_effect(effect);
+    world.flush();
+
+    let effect = world.entity(effect);
+    assert!(!effect.contains::<Disabled>());
+    assert!(effect.contains::<EffectJustApplied>());
+    assert_eq!(effect.get::<Effecting>(), Some(&Effecting(target)));
+}
+
+#[test]
+fn bind_insert_replaces_existing() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new("Effect"),
+        mode: Some(EffectMode::Insert),
+        bundle: MyEffect(0),
+        ..Default::default()
+    });
+    let effect = spawn_unbound(&mut world, EffectMode::Insert, 1);
+
+    world.commands().entity(target).bind_effect(effect);
+    world.flush();
+
+    let effected_by = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effected_by.iter().collect::<Vec<_>>(), vec![effect]);
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(1)));
+}
+
+#[test]
+fn bind_merge() {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<Magnitude>(merge_effect_magnitude);
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new("Effect"),
+        mode: Some(EffectMode::Merge),
+        bundle: Magnitude::new(1.0),
+        ..Default::default()
+    });
+
+    let effect = world
+        .commands()
+        .spawn_effect(EffectBundle {
+            name: Name::new("Effect"),
+            mode: Some(EffectMode::Merge),
+            bundle: Magnitude::new(2.0),
+            ..Default::default()
+        })
+        .id();
+
+    world.commands().entity(target).bind_effect(effect);
+    world.flush();
+
+    let effected_by = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effected_by.iter().collect::<Vec<_>>(), vec![effect]);
+    assert_eq!(world.get::<Magnitude>(effect).unwrap().value, 3.0);
+}
+
+#[test]
+fn bind_blocked_by_immunity() {
+    let mut world = World::new();
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::name("Effect")))
+        .id();
+    let effect = spawn_unbound(&mut world, EffectMode::Stack, 0);
+
+    world.commands().entity(target).bind_effect(effect);
+    world.flush();
+
+    assert!(world.get_entity(effect).is_err());
+    assert!(world.get::<EffectedBy>(target).is_none());
+}
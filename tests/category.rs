@@ -0,0 +1,60 @@
+//! Tests the behaviour of matching effects using an [`EffectCategory`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+fn apply_all(effects: impl IntoIterator<Item = (&'static str, Option<&'static str>)>) -> World {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<Magnitude>(merge_effect_magnitude);
+
+    let target = world.spawn_empty().id();
+
+    for (name, category) in effects {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new(name),
+            category: category.map(EffectCategory::new),
+            mode: Some(EffectMode::Merge),
+            bundle: Magnitude::new(1.0),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    world
+}
+
+fn magnitudes(world: &mut World) -> Vec<f32> {
+    world
+        .query::<&Magnitude>()
+        .iter(world)
+        .map(|magnitude| magnitude.value)
+        .collect()
+}
+
+#[test]
+fn merge_by_category() {
+    let mut world = apply_all([("Burn", Some("Fire")), ("Ignite", Some("Fire"))]);
+    assert_eq!(magnitudes(&mut world), vec![2.0]);
+}
+
+#[test]
+fn different_categories() {
+    let mut world = apply_all([("Burn", Some("Fire")), ("Burn", Some("Frost"))]);
+    assert_eq!(magnitudes(&mut world), vec![1.0, 1.0]);
+}
+
+#[test]
+fn names_without_category() {
+    let mut world = apply_all([("Burn", None), ("Ignite", None)]);
+    assert_eq!(magnitudes(&mut world), vec![1.0, 1.0]);
+}
+
+#[test]
+fn category_is_opt_in() {
+    // An effect with a category doesn't match an effect without one, even if they share a name.
+    let mut world = apply_all([("Burn", None), ("Burn", Some("Fire"))]);
+    assert_eq!(magnitudes(&mut world), vec![1.0, 1.0]);
+}
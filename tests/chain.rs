@@ -0,0 +1,110 @@
+//! Tests applying effects to the target or sources of another effect.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Weakened;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Thorns;
+
+fn weaken_poisoned(mut commands: Commands, effects: Query<&Effecting, With<Poison>>) {
+    for effecting in &effects {
+        commands.apply_effect_to_target(
+            effecting,
+            EffectBundle {
+                name: Name::new("Weakened"),
+                mode: Some(EffectMode::Insert),
+                bundle: Weakened,
+                ..Default::default()
+            },
+        );
+    }
+}
+
+fn thorns(mut commands: Commands, effects: Query<&EffectSources, With<Poison>>) {
+    for sources in &effects {
+        commands.apply_effect_to_sources(sources, EffectBundle::new(Thorns));
+    }
+}
+
+#[test]
+fn apply_to_target() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new(Poison));
+    world
+        .commands()
+        .entity(other)
+        .with_effect(EffectBundle::new(Weakened));
+    world.flush();
+
+    world.run_system_cached(weaken_poisoned).unwrap();
+
+    let effects: Vec<_> = world.get::<EffectedBy>(target).unwrap().iter().collect();
+    assert_eq!(effects.len(), 2);
+    assert!(world.get::<Weakened>(effects[1]).is_some());
+    assert_eq!(world.get::<EffectedBy>(other).unwrap().len(), 1);
+}
+
+#[test]
+fn apply_to_target_merges() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new(Poison));
+    world.flush();
+
+    world.run_system_cached(weaken_poisoned).unwrap();
+    world.run_system_cached(weaken_poisoned).unwrap();
+
+    let weakened = world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .filter(|effect| world.get::<Weakened>(*effect).is_some())
+        .count();
+    assert_eq!(weakened, 1);
+}
+
+#[test]
+fn apply_to_sources() {
+    let mut world = World::new();
+    let caster_a = world.spawn_empty().id();
+    let caster_b = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new((
+            Poison,
+            EffectSources(vec![caster_a, caster_b]),
+        )));
+    world.flush();
+
+    world.run_system_cached(thorns).unwrap();
+
+    for caster in [caster_a, caster_b] {
+        let effects = world.get::<EffectedBy>(caster).unwrap();
+        assert_eq!(effects.len(), 1);
+        assert!(
+            world
+                .get::<Thorns>(effects.iter().next().unwrap())
+                .is_some()
+        );
+    }
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 1);
+}
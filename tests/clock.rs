@@ -0,0 +1,88 @@
+//! Tests ticking effect timers using a custom clock, with an [`EffectClock`].
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use std::time::Duration;
+
+/// A clock that runs separately from the game's time.
+#[derive(Default)]
+struct BulletTime;
+
+fn init_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, EffectClockPlugin::<BulletTime>::new()))
+        .init_resource::<Time>();
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effects(|effects| {
+            effects.spawn(EffectBundle {
+                name: Name::new("Default"),
+                bundle: Lifetime::from_seconds(1.0),
+                ..Default::default()
+            });
+            effects.spawn(EffectBundle {
+                name: Name::new("Bullet Time"),
+                bundle: (
+                    Lifetime::from_seconds(1.0),
+                    EffectClock::<BulletTime>::new(),
+                ),
+                ..Default::default()
+            });
+        });
+    app.world_mut().flush();
+
+    let effects = app
+        .world()
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .collection()
+        .clone();
+    (app, effects[0], effects[1])
+}
+
+fn elapsed(app: &App, effect: Entity) -> Duration {
+    app.world().get::<Lifetime>(effect).unwrap().timer.elapsed()
+}
+
+#[test]
+fn tick_using_custom_clock() {
+    let (mut app, default, custom) = init_app();
+
+    app.world_mut()
+        .resource_mut::<Time<BulletTime>>()
+        .advance_by(Duration::from_millis(250));
+    app.update();
+
+    assert_eq!(elapsed(&app, default), Duration::ZERO);
+    assert_eq!(elapsed(&app, custom), Duration::from_millis(250));
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_millis(500));
+    app.world_mut()
+        .resource_mut::<Time<BulletTime>>()
+        .advance_by(Duration::ZERO);
+    app.update();
+
+    assert_eq!(elapsed(&app, default), Duration::from_millis(500));
+    assert_eq!(elapsed(&app, custom), Duration::from_millis(250));
+}
+
+#[test]
+fn despawn_using_custom_clock() {
+    let (mut app, default, custom) = init_app();
+
+    app.world_mut()
+        .resource_mut::<Time<BulletTime>>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+
+    assert!(app.world().get_entity(default).is_ok());
+    assert!(app.world().get_entity(custom).is_err());
+}
@@ -0,0 +1,102 @@
+//! Tests maintaining effects using [`ConditionalEffect`] and the [`EffectConditionRegistry`].
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq)]
+struct Health(u8);
+
+#[derive(Component)]
+struct InHealingZone;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Regeneration(u8);
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct ZoneRegeneration(u8);
+
+fn apply_regeneration(mut target: EntityWorldMut, effect: Entity) {
+    let amount = target.world().get::<Regeneration>(effect).unwrap().0;
+
+    if let Some(mut health) = target.get_mut::<Health>() {
+        health.0 += amount;
+    }
+}
+
+fn in_healing_zone(world: &World, target: Entity, _effect: Entity) -> bool {
+    world.get::<InHealingZone>(target).is_some()
+}
+
+fn init_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin).init_resource::<Time>();
+    app.world_mut()
+        .resource_mut::<EffectApplyRegistry>()
+        .register::<Regeneration>(apply_regeneration);
+    app
+}
+
+#[test]
+fn conditional_effect() {
+    let mut app = init_app();
+
+    let target = app.world_mut().spawn((Health(0), InHealingZone)).id();
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new((
+            Regeneration(1),
+            ConditionalEffect(in_healing_zone),
+        )));
+    app.world_mut().flush();
+
+    app.update();
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(1)));
+
+    app.world_mut().entity_mut(target).remove::<InHealingZone>();
+    app.update();
+
+    // The effect is removed before it's applied.
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(1)));
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn registered_condition() {
+    let mut app = init_app();
+    app.world_mut()
+        .resource_mut::<EffectConditionRegistry>()
+        .register::<ZoneRegeneration>(in_healing_zone);
+
+    let inside = app.world_mut().spawn(InHealingZone).id();
+    let outside = app.world_mut().spawn_empty().id();
+
+    for target in [inside, outside] {
+        app.world_mut()
+            .commands()
+            .entity(target)
+            .with_effect(EffectBundle::new(ZoneRegeneration(1)))
+            .with_effect(EffectBundle::new(Regeneration(1)));
+    }
+    app.world_mut().flush();
+
+    app.update();
+
+    let zone_effects = |app: &mut App, target: Entity| {
+        app.world_mut()
+            .query::<(&Effecting, &ZoneRegeneration)>()
+            .iter(app.world())
+            .filter(|(effecting, _)| effecting.0 == target)
+            .count()
+    };
+
+    assert_eq!(zone_effects(&mut app, inside), 1);
+    assert_eq!(zone_effects(&mut app, outside), 0);
+
+    // Effects without a registered component aren't affected.
+    assert_eq!(app.world().get::<EffectedBy>(outside).unwrap().len(), 1);
+}
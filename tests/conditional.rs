@@ -0,0 +1,63 @@
+//! Tests the behaviour of conditionally applying effects.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+#[derive(Component)]
+struct Wet;
+
+#[derive(Resource)]
+struct EffectsEnabled(bool);
+
+fn apply_if_wet(world: &mut World, target: Entity) {
+    world.commands().entity(target).with_effect_if(
+        EffectBundle {
+            bundle: MyEffect(0),
+            ..Default::default()
+        },
+        |entity| entity.contains::<Wet>(),
+    );
+
+    world.flush();
+}
+
+#[test]
+fn predicate_on_target() {
+    let mut world = World::new();
+
+    let wet = world.spawn(Wet).id();
+    let dry = world.spawn_empty().id();
+
+    apply_if_wet(&mut world, wet);
+    apply_if_wet(&mut world, dry);
+
+    assert_eq!(world.get::<EffectedBy>(wet).unwrap().len(), 1);
+    assert!(world.get::<EffectedBy>(dry).is_none());
+}
+
+#[test]
+fn predicate_on_resource() {
+    let mut world = World::new();
+    world.insert_resource(EffectsEnabled(false));
+
+    let target = world.spawn_empty().id();
+
+    for _ in 0..2 {
+        world.commands().entity(target).with_effect_if(
+            EffectBundle {
+                mode: Some(EffectMode::Stack),
+                bundle: MyEffect(0),
+                ..Default::default()
+            },
+            |entity| entity.world().resource::<EffectsEnabled>().0,
+        );
+
+        world.flush();
+        world.resource_mut::<EffectsEnabled>().0 = true;
+    }
+
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 1);
+}
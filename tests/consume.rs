@@ -0,0 +1,91 @@
+//! Tests removing stacks from effects using [`consume_stacks`](EffectCommandsExt::consume_stacks).
+#![cfg(feature = "stacks")]
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Default)]
+struct Combo;
+
+#[derive(Resource, Default)]
+struct Changes(Vec<(u8, u8)>);
+
+fn init_world(mode: EffectMode, count: usize) -> (World, Entity) {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    world.init_resource::<Changes>();
+    world.add_observer(|event: On<StacksChanged>, mut changes: ResMut<Changes>| {
+        changes.0.push((event.old, event.new));
+    });
+
+    let target = world.spawn_empty().id();
+
+    for _ in 0..count {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Combo"),
+            mode: Some(mode),
+            bundle: Combo,
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+    world.resource_mut::<Changes>().0.clear();
+
+    (world, target)
+}
+
+fn stacks(world: &mut World) -> Vec<u8> {
+    world
+        .query_filtered::<&EffectStacks, With<Combo>>()
+        .iter(world)
+        .map(|stacks| stacks.0)
+        .collect()
+}
+
+#[test]
+fn reduce_merged() {
+    let (mut world, target) = init_world(EffectMode::Merge, 5);
+
+    world.commands().entity(target).consume_stacks("Combo", 2);
+    world.flush();
+
+    assert_eq!(stacks(&mut world), vec![3]);
+    assert_eq!(world.resource::<Changes>().0, vec![(5, 3)]);
+}
+
+#[test]
+fn despawn_at_zero() {
+    let (mut world, target) = init_world(EffectMode::Merge, 2);
+
+    world.commands().entity(target).consume_stacks("Combo", 3);
+    world.flush();
+
+    assert!(stacks(&mut world).is_empty());
+    assert_eq!(world.query::<&Combo>().iter(&world).count(), 0);
+    assert_eq!(world.resource::<Changes>().0, vec![(2, 0)]);
+}
+
+#[test]
+fn stacked_effects() {
+    let (mut world, target) = init_world(EffectMode::Stack, 3);
+
+    world.commands().entity(target).consume_stacks("Combo", 2);
+    world.flush();
+
+    assert_eq!(world.query::<&Combo>().iter(&world).count(), 1);
+}
+
+#[test]
+fn missing_effect() {
+    let (mut world, target) = init_world(EffectMode::Merge, 2);
+
+    world.commands().entity(target).consume_stacks("Other", 1);
+    world.flush();
+
+    assert_eq!(stacks(&mut world), vec![2]);
+    assert!(world.resource::<Changes>().0.is_empty());
+}
@@ -0,0 +1,75 @@
+//! Tests tracking which entities applied each stack of a merged effect, using [`StackContributions`].
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+fn apply(world: &mut World, target: Entity, source: Entity) {
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new("Poison"),
+        mode: Some(EffectMode::Merge),
+        bundle: (Poison, StackContributions::new(source)),
+        ..Default::default()
+    });
+    world.flush();
+}
+
+fn contributions(world: &mut World) -> StackContributions {
+    world
+        .query::<&StackContributions>()
+        .single(world)
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn merge_appends() {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin);
+    let world = app.world_mut();
+
+    let first = world.spawn_empty().id();
+    let second = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    apply(world, target, first);
+    apply(world, target, second);
+    apply(world, target, first);
+
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 1);
+
+    let contributions = contributions(world);
+    assert_eq!(contributions.0, vec![first, second, first]);
+    assert_eq!(contributions.latest(), Some(first));
+    assert_eq!(contributions.count_from(first), 2);
+    assert_eq!(contributions.count_from(second), 1);
+}
+
+#[test]
+fn merge_without_registered_function() {
+    let mut world = World::new();
+    world.init_resource::<EffectMergeRegistry>();
+
+    let first = world.spawn_empty().id();
+    let second = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    apply(&mut world, target, first);
+    apply(&mut world, target, second);
+
+    // Without the merge function, only the latest contribution is kept.
+    assert_eq!(contributions(&mut world).0, vec![second]);
+}
+
+#[test]
+fn merge_order() {
+    let first = Entity::from_raw_u32(1).unwrap();
+    let second = Entity::from_raw_u32(2).unwrap();
+
+    let mut new = StackContributions::new(second);
+    new.merge(&StackContributions(vec![first, first]));
+    assert_eq!(new.0, vec![first, first, second]);
+}
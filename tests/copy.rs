@@ -0,0 +1,106 @@
+//! Tests copying effects between targets using [`CopyEffectCommand`].
+#![cfg(all(feature = "timers", feature = "stacks"))]
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Infection(u8);
+
+fn init_world() -> World {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks)
+        .register::<Lifetime>(merge_effect_timer::<Lifetime>);
+    world
+}
+
+fn infect(world: &mut World, target: Entity) -> Entity {
+    world.commands().entity(target).with_effect(EffectBundle {
+        mode: Some(EffectMode::Merge),
+        ..EffectBundle::new((
+            Infection(1),
+            Lifetime::from_seconds(5.0).with_mode(TimerMergeMode::Sum),
+        ))
+    });
+    world.flush();
+
+    world.get::<EffectedBy>(target).unwrap().collection()[0]
+}
+
+#[test]
+fn copy_state() {
+    let mut world = init_world();
+    let source = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    let effect = infect(&mut world, source);
+    infect(&mut world, source);
+    world
+        .get_mut::<Lifetime>(effect)
+        .unwrap()
+        .timer
+        .tick(Duration::from_secs(2));
+
+    world.commands().entity(target).copy_effect_from(effect);
+    world.flush();
+
+    let copy = world.get::<EffectedBy>(target).unwrap().collection()[0];
+    assert_ne!(copy, effect);
+    assert_eq!(world.get::<Effecting>(copy), Some(&Effecting(target)));
+    assert_eq!(world.get::<Infection>(copy), Some(&Infection(1)));
+    assert_eq!(world.get::<EffectStacks>(copy), Some(&EffectStacks(2)));
+    assert_eq!(
+        world.get::<Lifetime>(copy).unwrap().timer.elapsed(),
+        Duration::from_secs(2)
+    );
+    assert!(world.entity(copy).contains::<EffectJustApplied>());
+
+    // The original is unchanged.
+    assert_eq!(world.get::<Effecting>(effect), Some(&Effecting(source)));
+    assert_eq!(world.get::<EffectStacks>(effect), Some(&EffectStacks(2)));
+}
+
+#[test]
+fn copy_merge() {
+    let mut world = init_world();
+    let source = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    let effect = infect(&mut world, source);
+    infect(&mut world, target);
+
+    world.commands().entity(target).copy_effect_from(effect);
+    world.flush();
+
+    let effects = world.get::<EffectedBy>(target).unwrap().collection();
+    assert_eq!(effects.len(), 1);
+    assert_eq!(
+        world.get::<EffectStacks>(effects[0]),
+        Some(&EffectStacks(2))
+    );
+    assert_eq!(
+        world.get::<Lifetime>(effects[0]).unwrap().timer.duration(),
+        Duration::from_secs(10)
+    );
+}
+
+#[test]
+fn copy_blocked() {
+    let mut world = init_world();
+    let source = world.spawn_empty().id();
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::All))
+        .id();
+
+    let effect = infect(&mut world, source);
+    let count = world.entities().len();
+
+    world.commands().entity(target).copy_effect_from(effect);
+    world.flush();
+
+    assert!(world.get::<EffectedBy>(target).is_none());
+    assert_eq!(world.entities().len(), count);
+}
@@ -0,0 +1,119 @@
+//! Tests dealing damage using [`DamageOverTime`] effects.
+#![cfg(all(feature = "damage", feature = "test_utils"))]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Component, Debug, PartialEq)]
+struct Health(f32);
+
+impl Damageable for Health {
+    fn damage(&mut self, amount: f32) {
+        self.0 -= amount;
+    }
+}
+
+fn init_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        AlchemyPlugin,
+        TestTimePlugin,
+        DamageOverTimePlugin::<Health>::new(),
+    ));
+
+    let target = app.world_mut().spawn(Health(100.0)).id();
+    (app, target)
+}
+
+fn apply<B: Bundle>(app: &mut App, target: Entity, bundle: EffectBundle<B>) {
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(bundle);
+    app.world_mut().flush();
+}
+
+#[test]
+fn damage_each_tick() {
+    let (mut app, target) = init_app();
+    apply(
+        &mut app,
+        target,
+        EffectBundle::new((Delay::from_seconds(1.0), DamageOverTime::new(5.0))),
+    );
+
+    app.advance_time(Duration::from_millis(500));
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(100.0)));
+
+    app.advance_time(Duration::from_millis(500));
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(95.0)));
+
+    app.advance_time(Duration::from_secs(1));
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(90.0)));
+}
+
+#[test]
+fn damage_every_tick_on_long_frames() {
+    let (mut app, target) = init_app();
+    apply(
+        &mut app,
+        target,
+        EffectBundle::new((Delay::from_seconds(1.0), DamageOverTime::new(5.0))),
+    );
+
+    app.advance_time(Duration::from_secs(3));
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(85.0)));
+}
+
+#[test]
+fn scale_with_stacks() {
+    let (mut app, target) = init_app();
+    let bundle = EffectBundle {
+        name: "Poison".into(),
+        mode: Some(EffectMode::Merge),
+        bundle: (
+            Delay::from_seconds(1.0),
+            DamageOverTime::new(5.0).with_stack_scaling(),
+            EffectStacks(1),
+        ),
+        ..Default::default()
+    };
+    apply(&mut app, target, bundle.clone());
+    apply(&mut app, target, bundle);
+
+    app.advance_time(Duration::from_secs(1));
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(90.0)));
+}
+
+#[test]
+fn no_damage_while_inactive() {
+    let (mut app, target) = init_app();
+    apply(
+        &mut app,
+        target,
+        EffectBundle::new((
+            ActivationDelay::from_seconds(2.0),
+            Delay::from_seconds(1.0),
+            DamageOverTime::new(5.0),
+        )),
+    );
+
+    app.advance_time(Duration::from_secs(1));
+    assert_eq!(app.world().get::<Health>(target), Some(&Health(100.0)));
+}
+
+#[test]
+fn ignore_targets_without_health() {
+    let (mut app, _) = init_app();
+    let target = app.world_mut().spawn_empty().id();
+    apply(
+        &mut app,
+        target,
+        EffectBundle::new((Delay::from_seconds(1.0), DamageOverTime::new(5.0))),
+    );
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+}
@@ -0,0 +1,137 @@
+//! Tests scaling stat effects by their lifetime using [`Decaying`].
+#![cfg(all(feature = "immediate_stats", feature = "timers"))]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use immediate_stats::*;
+use std::time::Duration;
+
+#[derive(Component, StatContainer)]
+struct MovementSpeed(Stat);
+
+fn speed(speed: &mut MovementSpeed) -> &mut Stat {
+    &mut speed.0
+}
+
+fn setup() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin)
+        .add_plugins(StatEffectPlugin::<MovementSpeed>::new())
+        .init_resource::<Time>();
+
+    let target = app.world_mut().spawn(MovementSpeed(Stat::new(100))).id();
+    (app, target)
+}
+
+fn advance(app: &mut App, duration: Duration) {
+    app.world_mut().resource_mut::<Time>().advance_by(duration);
+    app.update();
+}
+
+#[test]
+fn decays_over_lifetime() {
+    let (mut app, target) = setup();
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new((
+            Lifetime::from_seconds(2.0),
+            Decaying::new(Modifier::from_bonus(100), speed),
+        )));
+
+    advance(&mut app, Duration::ZERO);
+    assert_eq!(
+        app.world().get::<MovementSpeed>(target).unwrap().0.total(),
+        200
+    );
+
+    advance(&mut app, Duration::from_millis(500));
+    assert_eq!(
+        app.world().get::<MovementSpeed>(target).unwrap().0.total(),
+        175
+    );
+
+    advance(&mut app, Duration::from_secs(1));
+    assert_eq!(
+        app.world().get::<MovementSpeed>(target).unwrap().0.total(),
+        125
+    );
+}
+
+#[test]
+fn grows_with_scaling() {
+    let (mut app, target) = setup();
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new((
+            Lifetime::from_seconds(2.0),
+            LifetimeScaling::new(ScalingCurve::LinearUp),
+            Decaying::new(Modifier::from_bonus(100), speed),
+        )));
+
+    advance(&mut app, Duration::ZERO);
+    assert_eq!(
+        app.world().get::<MovementSpeed>(target).unwrap().0.total(),
+        100
+    );
+
+    advance(&mut app, Duration::from_millis(500));
+    assert_eq!(
+        app.world().get::<MovementSpeed>(target).unwrap().0.total(),
+        125
+    );
+}
+
+#[test]
+fn not_applied_while_lingering() {
+    let (mut app, target) = setup();
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new((
+            Lifetime::from_seconds(1.0),
+            LifetimeScaling::new(ScalingCurve::LinearUp),
+            LingerOnExpire,
+            Decaying::new(Modifier::from_bonus(100), speed),
+        )));
+
+    advance(&mut app, Duration::from_secs(1));
+    assert_eq!(
+        app.world().get::<MovementSpeed>(target).unwrap().0.total(),
+        100
+    );
+}
+
+#[test]
+fn requires_lifetime() {
+    let (mut app, target) = setup();
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new(Decaying::new(
+            Modifier::from_bonus(100),
+            speed,
+        )));
+
+    advance(&mut app, Duration::ZERO);
+    assert_eq!(
+        app.world().get::<MovementSpeed>(target).unwrap().0.total(),
+        100
+    );
+}
+
+#[test]
+fn scaled_with_stacks() {
+    let decaying = Decaying::new(Modifier::from_bonus(100), speed);
+    let mut lifetime = Lifetime::from_seconds(4.0);
+    lifetime.timer.tick(Duration::from_secs(2));
+
+    assert_eq!(decaying.scaled_with_stacks(3, &lifetime, None).bonus, 150);
+}
@@ -0,0 +1,101 @@
+//! Tests generating merge functions using `#[derive(Effect)]`.
+#![cfg(feature = "derive")]
+
+use bevy_alchemy::*;
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+#[derive(Component, Clone, Debug, PartialEq, Default, Effect)]
+#[effect(merge = "sum")]
+struct Poison {
+    damage: f32,
+    #[effect(merge = "max")]
+    slow: f32,
+    #[effect(merge = "min")]
+    interval: f32,
+    #[effect(merge = "keep")]
+    source: u8,
+    #[effect(merge = "replace")]
+    color: u8,
+}
+
+#[derive(Component, Reflect, Clone, Debug, PartialEq, Default, Effect)]
+#[effect(merge = "max", reflect)]
+struct Strength(u32);
+
+#[derive(Component, Clone, Debug, PartialEq, Default, Effect)]
+struct Marker;
+
+#[test]
+fn merge_strategies() {
+    let mut new = Poison {
+        damage: 1.0,
+        slow: 0.2,
+        interval: 2.0,
+        source: 1,
+        color: 1,
+    };
+
+    new.merge(&Poison {
+        damage: 2.0,
+        slow: 0.5,
+        interval: 1.0,
+        source: 0,
+        color: 0,
+    });
+
+    assert_eq!(
+        new,
+        Poison {
+            damage: 3.0,
+            slow: 0.5,
+            interval: 1.0,
+            source: 0,
+            color: 1,
+        }
+    );
+}
+
+#[test]
+fn register_and_merge() {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin)
+        .register_effect::<Poison>()
+        .register_effect::<Strength>()
+        .register_effect::<Marker>();
+
+    let world = app.world_mut();
+    let target = world.spawn_empty().id();
+
+    for (damage, strength) in [(1.0, 3), (2.0, 1)] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Effect"),
+            mode: Some(EffectMode::Merge),
+            ..EffectBundle::new((
+                Poison {
+                    damage,
+                    ..Default::default()
+                },
+                Strength(strength),
+                Marker,
+            ))
+        });
+    }
+    world.flush();
+
+    let (poison, strength) = world.query::<(&Poison, &Strength)>().single(world).unwrap();
+    assert_eq!(poison.damage, 3.0);
+    assert_eq!(strength, &Strength(3));
+}
+
+#[test]
+fn register_reflect() {
+    let mut app = App::new();
+    app.register_effect::<Strength>()
+        .register_effect::<Marker>();
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.contains(std::any::TypeId::of::<Strength>()));
+    assert!(!registry.contains(std::any::TypeId::of::<Marker>()));
+}
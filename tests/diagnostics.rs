@@ -0,0 +1,49 @@
+//! Tests the measurements of the [`AlchemyDiagnosticsPlugin`].
+#![cfg(feature = "diagnostics")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_diagnostic::{DiagnosticPath, DiagnosticsStore};
+
+fn value(app: &App, path: &DiagnosticPath) -> Option<f64> {
+    app.world()
+        .resource::<DiagnosticsStore>()
+        .get(path)?
+        .value()
+}
+
+#[test]
+fn effect_counts() {
+    let mut app = App::new();
+    app.add_plugins(AlchemyDiagnosticsPlugin::default());
+
+    let first = app.world_mut().spawn_empty().id();
+    let second = app.world_mut().spawn_empty().id();
+
+    for _ in 0..3 {
+        app.world_mut()
+            .commands()
+            .entity(first)
+            .with_effect(EffectBundle::<()>::default());
+    }
+
+    app.world_mut()
+        .commands()
+        .entity(second)
+        .with_effect(EffectBundle::<()>::default());
+
+    app.update();
+
+    assert_eq!(
+        value(&app, &AlchemyDiagnosticsPlugin::EFFECT_COUNT),
+        Some(4.0)
+    );
+    assert_eq!(
+        value(&app, &AlchemyDiagnosticsPlugin::TARGET_COUNT),
+        Some(2.0)
+    );
+    assert_eq!(
+        value(&app, &AlchemyDiagnosticsPlugin::MAX_EFFECTS_PER_TARGET),
+        Some(3.0)
+    );
+}
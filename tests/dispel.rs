@@ -0,0 +1,170 @@
+//! Tests removing a number of effects using [`dispel`](EffectCommandsExt::dispel).
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn remaining(world: &mut World) -> Vec<u8> {
+    let mut remaining: Vec<u8> = world
+        .query::<&MyEffect>()
+        .iter(world)
+        .map(|effect| effect.0)
+        .collect();
+    remaining.sort();
+    remaining
+}
+
+fn dispel(world: &mut World, target: Entity, filter: EffectFilter, order: DispelOrder) {
+    world.commands().entity(target).dispel(filter, 2, order);
+    world.flush();
+}
+
+/// Spawns three debuffs (0, 1 and 2) followed by a buff (3).
+fn init_world() -> (World, Entity) {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        for i in 0..3 {
+            effects.spawn(EffectBundle {
+                tags: EffectTags::new(["Debuff"]),
+                bundle: MyEffect(i),
+                ..Default::default()
+            });
+        }
+        effects.spawn(EffectBundle {
+            tags: EffectTags::new(["Buff"]),
+            bundle: MyEffect(3),
+            ..Default::default()
+        });
+    });
+    world.flush();
+
+    (world, target)
+}
+
+#[test]
+fn dispel_oldest() {
+    let (mut world, target) = init_world();
+    dispel(
+        &mut world,
+        target,
+        EffectFilter::tag("Debuff"),
+        DispelOrder::Oldest,
+    );
+    assert_eq!(remaining(&mut world), vec![2, 3]);
+}
+
+#[test]
+fn dispel_newest() {
+    let (mut world, target) = init_world();
+    dispel(
+        &mut world,
+        target,
+        EffectFilter::tag("Debuff"),
+        DispelOrder::Newest,
+    );
+    assert_eq!(remaining(&mut world), vec![0, 3]);
+}
+
+#[test]
+fn dispel_reapplied_as_newest() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    for (name, i) in [("A", 0), ("B", 1), ("A", 2)] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new(name),
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(i),
+            ..Default::default()
+        });
+    }
+    world.flush();
+
+    world
+        .commands()
+        .entity(target)
+        .dispel(EffectFilter::All, 1, DispelOrder::Oldest);
+    world.flush();
+
+    // `A` was reapplied after `B`, so `B` is the oldest.
+    assert_eq!(remaining(&mut world), vec![2]);
+}
+
+#[test]
+fn dispel_more_than_matching() {
+    let (mut world, target) = init_world();
+    world
+        .commands()
+        .entity(target)
+        .dispel(EffectFilter::tag("Buff"), 5, DispelOrder::Oldest);
+    world.flush();
+    assert_eq!(remaining(&mut world), vec![0, 1, 2]);
+}
+
+#[test]
+fn dispel_without_effects() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+    dispel(&mut world, target, EffectFilter::All, DispelOrder::Oldest);
+}
+
+#[cfg(feature = "stacks")]
+#[test]
+fn dispel_most_stacks() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        for (i, stacks) in [1, 3, 2, 3].into_iter().enumerate() {
+            effects.spawn(EffectBundle {
+                bundle: (MyEffect(i as u8), EffectStacks(stacks)),
+                ..Default::default()
+            });
+        }
+    });
+    world.flush();
+
+    dispel(
+        &mut world,
+        target,
+        EffectFilter::All,
+        DispelOrder::MostStacks,
+    );
+    assert_eq!(remaining(&mut world), vec![0, 2]);
+}
+
+#[cfg(feature = "timers")]
+#[test]
+fn dispel_soonest_expiry() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle::new(MyEffect(0)));
+        effects.spawn(EffectBundle::new((
+            MyEffect(1),
+            Lifetime::from_seconds(5.0),
+        )));
+        effects.spawn(EffectBundle::new((
+            MyEffect(2),
+            Lifetime::from_seconds(1.0),
+        )));
+        effects.spawn(EffectBundle::new((
+            MyEffect(3),
+            Lifetime::from_seconds(3.0),
+        )));
+    });
+    world.flush();
+
+    dispel(
+        &mut world,
+        target,
+        EffectFilter::All,
+        DispelOrder::SoonestExpiry,
+    );
+    assert_eq!(remaining(&mut world), vec![0, 1]);
+}
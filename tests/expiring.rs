@@ -0,0 +1,208 @@
+//! Tests the behaviour of effects when their [`Lifetime`] finishes.
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use std::time::Duration;
+
+fn init_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin).init_resource::<Time>();
+    app
+}
+
+fn expire(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(1.0));
+    app.update();
+}
+
+#[test]
+fn linger_for_one_frame() {
+    let mut app = init_app();
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            bundle: (Lifetime::from_seconds(1.0), LingerOnExpire),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    expire(&mut app);
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    assert!(app.world().get::<EffectExpiring>(effect).is_some());
+
+    app.update();
+
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn refresh_while_lingering() {
+    let mut app = init_app();
+
+    let target = app.world_mut().spawn_empty().id();
+    let apply = |app: &mut App| {
+        app.world_mut()
+            .commands()
+            .entity(target)
+            .with_effect(EffectBundle {
+                name: Name::new("Shield"),
+                mode: Some(EffectMode::Insert),
+                bundle: (Lifetime::from_seconds(1.0), LingerOnExpire),
+                ..Default::default()
+            });
+        app.world_mut().flush();
+    };
+
+    apply(&mut app);
+    expire(&mut app);
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    assert!(app.world().get::<EffectExpiring>(effect).is_some());
+
+    // Reapplying the effect refreshes its lifetime, so it is no longer expiring.
+    apply(&mut app);
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::ZERO);
+    app.update();
+
+    assert!(app.world().get::<EffectExpiring>(effect).is_none());
+
+    // It lingers again once the refreshed lifetime finishes.
+    expire(&mut app);
+    assert!(app.world().get::<EffectExpiring>(effect).is_some());
+
+    app.update();
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn despawn_immediately_by_default() {
+    let mut app = init_app();
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            bundle: Lifetime::from_seconds(1.0),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    expire(&mut app);
+
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn permanent_never_despawns() {
+    let mut app = init_app();
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            bundle: Lifetime::permanent(),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    expire(&mut app);
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    let lifetime = app.world().get::<Lifetime>(effect).unwrap();
+    assert_eq!(lifetime.timer.elapsed(), Duration::from_secs_f32(1.0));
+}
+
+#[derive(Component)]
+struct Health(f32);
+
+#[derive(Component, Default)]
+struct ExplodingShield(f32);
+
+fn explode_shield(mut target: EntityWorldMut, effect: Entity) {
+    let damage = target.world().get::<ExplodingShield>(effect).unwrap().0;
+    target.get_mut::<Health>().unwrap().0 -= damage;
+}
+
+fn apply_shield(app: &mut App, linger: bool) -> Entity {
+    app.world_mut()
+        .resource_mut::<EffectExpireRegistry>()
+        .register::<ExplodingShield>(explode_shield);
+
+    let target = app.world_mut().spawn(Health(10.0)).id();
+    let mut commands = app.world_mut().commands();
+    let mut target_commands = commands.entity(target);
+
+    if linger {
+        target_commands.with_effect(EffectBundle {
+            name: Name::new("Shield"),
+            bundle: (
+                Lifetime::from_seconds(1.0),
+                ExplodingShield(3.0),
+                LingerOnExpire,
+            ),
+            ..Default::default()
+        });
+    } else {
+        target_commands.with_effect(EffectBundle {
+            name: Name::new("Shield"),
+            bundle: (Lifetime::from_seconds(1.0), ExplodingShield(3.0)),
+            ..Default::default()
+        });
+    }
+
+    app.world_mut().flush();
+    target
+}
+
+#[test]
+fn run_expire_fn() {
+    let mut app = init_app();
+    let target = apply_shield(&mut app, false);
+
+    expire(&mut app);
+
+    assert_eq!(app.world().get::<Health>(target).unwrap().0, 7.0);
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn run_expire_fn_after_lingering() {
+    let mut app = init_app();
+    let target = apply_shield(&mut app, true);
+
+    expire(&mut app);
+    assert_eq!(app.world().get::<Health>(target).unwrap().0, 10.0);
+
+    app.update();
+    assert_eq!(app.world().get::<Health>(target).unwrap().0, 7.0);
+}
+
+#[test]
+fn skip_expire_fn_when_cleared() {
+    let mut app = init_app();
+    let target = apply_shield(&mut app, false);
+
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .clear_effects(EffectFilter::name("Shield"));
+    app.world_mut().flush();
+
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+
+    expire(&mut app);
+    assert_eq!(app.world().get::<Health>(target).unwrap().0, 10.0);
+}
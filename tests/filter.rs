@@ -0,0 +1,295 @@
+//! Tests the behaviour of [`EffectFilter`]s, when used for immunity and clearing effects.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default)]
+struct MyEffect(u8);
+
+fn effects(world: &mut World) -> Vec<u8> {
+    world
+        .query::<&MyEffect>()
+        .iter(world)
+        .map(|c| c.0)
+        .collect()
+}
+
+#[test]
+fn immunity_by_name() {
+    let mut world = World::new();
+
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::name("Poison")))
+        .id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            name: Name::new("Regen"),
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    assert_eq!(effects(&mut world), vec![1]);
+}
+
+#[test]
+fn immunity_by_tag() {
+    let mut world = World::new();
+
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::tag("Magic")))
+        .id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            tags: EffectTags::new(["Debuff", "Magic"]),
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            tags: EffectTags::new(["Debuff", "Physical"]),
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    assert_eq!(effects(&mut world), vec![1]);
+}
+
+#[test]
+fn clear_by_tag() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            tags: EffectTags::new(["Debuff"]),
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            tags: EffectTags::new(["Buff"]),
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    world
+        .commands()
+        .entity(target)
+        .clear_effects(EffectFilter::tag("Debuff"));
+
+    world.flush();
+
+    assert_eq!(effects(&mut world), vec![1]);
+}
+
+#[test]
+fn remove_by_name() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            name: Name::new("Regen"),
+            bundle: MyEffect(2),
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    world.commands().entity(target).remove_effect("Poison");
+
+    world.flush();
+
+    assert_eq!(effects(&mut world), vec![2]);
+}
+
+#[test]
+fn immunity_by_polarity() {
+    let mut world = World::new();
+
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::Polarity(EffectPolarity::Debuff)))
+        .id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            polarity: EffectPolarity::Debuff,
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            polarity: EffectPolarity::Buff,
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            bundle: MyEffect(2),
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    assert_eq!(effects(&mut world), vec![1, 2]);
+}
+
+#[test]
+fn clear_by_polarity() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            polarity: EffectPolarity::Debuff,
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            polarity: EffectPolarity::Buff,
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            bundle: MyEffect(2),
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    // Effects are neutral by default.
+    let polarities: Vec<EffectPolarity> = world
+        .query::<&EffectPolarity>()
+        .iter(&world)
+        .copied()
+        .collect();
+    assert!(polarities.contains(&EffectPolarity::Neutral));
+
+    world
+        .commands()
+        .entity(target)
+        .clear_effects(EffectFilter::Polarity(EffectPolarity::Debuff));
+
+    world.flush();
+
+    let mut remaining = effects(&mut world);
+    remaining.sort();
+    assert_eq!(remaining, vec![1, 2]);
+}
+
+#[test]
+fn bind_blocked_by_polarity() {
+    let mut world = World::new();
+
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::Polarity(EffectPolarity::Debuff)))
+        .id();
+
+    let effect = world
+        .commands()
+        .spawn_effect(EffectBundle {
+            polarity: EffectPolarity::Debuff,
+            bundle: MyEffect(0),
+            ..Default::default()
+        })
+        .id();
+    world.commands().entity(target).bind_effect(effect);
+
+    world.flush();
+
+    assert!(world.get_entity(effect).is_err());
+}
+
+#[test]
+fn remove_from_many_targets() {
+    let mut world = World::new();
+
+    let targets: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+    let untouched = world.spawn_empty().id();
+    let empty = world.spawn_empty().id();
+
+    for (i, target) in targets.iter().chain([&untouched]).enumerate() {
+        world.commands().entity(*target).with_effects(|effects| {
+            effects.spawn(EffectBundle {
+                name: Name::new("Poison"),
+                bundle: MyEffect(i as u8),
+                ..Default::default()
+            });
+            effects.spawn(EffectBundle {
+                name: Name::new("Haste"),
+                bundle: MyEffect(10 + i as u8),
+                ..Default::default()
+            });
+        });
+    }
+
+    world.flush();
+
+    // Targets without effects (or that don't exist) are skipped.
+    let missing = world.spawn_empty().id();
+    world.despawn(missing);
+
+    world
+        .commands()
+        .remove_effect_from(targets.iter().copied().chain([empty, missing]), "Poison");
+
+    world.flush();
+
+    let mut remaining = effects(&mut world);
+    remaining.sort();
+    assert_eq!(remaining, vec![3, 10, 11, 12, 13]);
+}
+
+#[test]
+fn clear_from_many_targets() {
+    let mut world = World::new();
+
+    let first = world.spawn_empty().id();
+    let second = world.spawn_empty().id();
+
+    for (i, target) in [first, second].into_iter().enumerate() {
+        world.commands().entity(target).with_effect(EffectBundle {
+            tags: EffectTags::new(["Debuff"]),
+            bundle: MyEffect(i as u8),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    world
+        .commands()
+        .clear_effects_from([first, second], EffectFilter::tag("Debuff"));
+
+    world.flush();
+
+    assert!(effects(&mut world).is_empty());
+}
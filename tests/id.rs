@@ -0,0 +1,50 @@
+//! Tests the behaviour of matching effects using an [`EffectId`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+struct Poison;
+
+struct Burn;
+
+fn count_effects(effects: impl IntoIterator<Item = (&'static str, Option<EffectId>)>) -> usize {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    for (name, id) in effects {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new(name),
+            id,
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    world.get::<EffectedBy>(target).unwrap().len()
+}
+
+#[test]
+fn match_by_id() {
+    let poison = Some(EffectId::of::<Poison>());
+    assert_eq!(count_effects([("A", poison), ("B", poison)]), 1);
+}
+
+#[test]
+fn different_ids_same_name() {
+    let poison = Some(EffectId::of::<Poison>());
+    let burn = Some(EffectId::of::<Burn>());
+    assert_eq!(count_effects([("A", poison), ("A", burn)]), 2);
+}
+
+#[test]
+fn match_by_name_without_id() {
+    assert_eq!(count_effects([("A", None), ("A", None)]), 1);
+    assert_eq!(count_effects([("A", None), ("B", None)]), 2);
+}
@@ -0,0 +1,83 @@
+//! Tests the behaviour of the [`EffectIndex`].
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Burn;
+
+#[test]
+fn index_by_kind() {
+    let mut app = App::new();
+    app.add_plugins(EffectIndexPlugin::<Poison>::new());
+    let world = app.world_mut();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            bundle: Poison,
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            bundle: Poison,
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            bundle: Burn,
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    let index = world.get::<EffectIndex<Poison>>(target).unwrap();
+    assert_eq!(index.len(), 2);
+
+    for effect in index.iter() {
+        assert!(world.get::<Poison>(effect).is_some());
+    }
+
+    let removed = index.iter().next().unwrap();
+    world.despawn(removed);
+    world.flush();
+
+    let index = world.get::<EffectIndex<Poison>>(target).unwrap();
+    assert_eq!(index.len(), 1);
+    assert!(!index.contains(removed));
+
+    // The effects weren't given names, so they are named after their bundle.
+    world.commands().entity(target).remove_effect("Poison");
+    world.flush();
+
+    assert!(world.get::<EffectIndex<Poison>>(target).is_none());
+}
+
+#[test]
+fn index_bound_effect() {
+    let mut app = App::new();
+    app.add_plugins(EffectIndexPlugin::<Poison>::new());
+    let world = app.world_mut();
+
+    let target = world.spawn_empty().id();
+    let effect = world
+        .commands()
+        .spawn_effect(EffectBundle {
+            bundle: Poison,
+            ..Default::default()
+        })
+        .id();
+    world.flush();
+
+    assert!(world.get::<EffectIndex<Poison>>(target).is_none());
+
+    world.commands().entity(target).bind_effect(effect);
+    world.flush();
+
+    let index = world.get::<EffectIndex<Poison>>(target).unwrap();
+    assert_eq!(index.iter().collect::<Vec<_>>(), vec![effect]);
+}
@@ -0,0 +1,74 @@
+//! Tests randomizing the interval of a [`Delay`] using [`DelayJitter`].
+#![cfg(feature = "test_utils")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+const MIN: Duration = Duration::from_millis(800);
+const MAX: Duration = Duration::from_millis(1200);
+
+fn init_app(count: usize) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin));
+
+    let target = app.world_mut().spawn_empty().id();
+    for _ in 0..count {
+        app.world_mut()
+            .commands()
+            .entity(target)
+            .with_effect(EffectBundle {
+                bundle: Delay::from_seconds(1.0).with_jitter(MIN..=MAX),
+                ..Default::default()
+            });
+    }
+    app.world_mut().flush();
+
+    (app, target)
+}
+
+fn durations(app: &App, target: Entity) -> Vec<Duration> {
+    app.world()
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .map(|effect| app.world().get::<Delay>(effect).unwrap().timer.duration())
+        .collect()
+}
+
+#[test]
+fn jitter_within_range() {
+    let (mut app, target) = init_app(1);
+
+    let mut seen = Vec::new();
+    for _ in 0..8 {
+        let duration = durations(&app, target)[0];
+        app.advance_time(duration);
+
+        let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+        assert!(
+            app.world()
+                .get::<Delay>(effect)
+                .unwrap()
+                .timer
+                .just_finished()
+        );
+
+        let next = durations(&app, target)[0];
+        assert!((MIN..=MAX).contains(&next));
+        seen.push(next);
+    }
+
+    assert!(seen.iter().any(|duration| *duration != seen[0]));
+}
+
+#[test]
+fn jitter_differs_between_effects() {
+    let (mut app, target) = init_app(2);
+
+    app.advance_time(Duration::from_secs(1));
+
+    let durations = durations(&app, target);
+    assert_ne!(durations[0], durations[1]);
+}
@@ -0,0 +1,67 @@
+//! Tests the behaviour of the [`EffectJustApplied`] marker.
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default)]
+struct MyEffect(u8);
+
+fn init_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin).init_resource::<Time>();
+    app
+}
+
+fn apply(app: &mut App, target: Entity, value: u8) {
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(value),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+}
+
+fn just_applied(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(), With<EffectJustApplied>>()
+        .iter(world)
+        .count()
+}
+
+#[test]
+fn removed_at_end_of_frame() {
+    let mut app = init_app();
+    let target = app.world_mut().spawn_empty().id();
+
+    apply(&mut app, target, 0);
+    assert_eq!(just_applied(&mut app), 1);
+
+    app.update();
+    assert_eq!(just_applied(&mut app), 0);
+
+    apply(&mut app, target, 1);
+    assert_eq!(just_applied(&mut app), 1);
+}
+
+#[test]
+fn reapplication_not_marked() {
+    let mut app = init_app();
+    app.insert_resource(AlchemySettings {
+        mark_reapplied: false,
+        ..Default::default()
+    });
+    let target = app.world_mut().spawn_empty().id();
+
+    apply(&mut app, target, 0);
+    app.update();
+
+    apply(&mut app, target, 1);
+    assert_eq!(just_applied(&mut app), 0);
+}
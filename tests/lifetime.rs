@@ -0,0 +1,153 @@
+//! Tests the despawning of effects once their [`Lifetime`] finishes, using the [`TestTimePlugin`].
+#![cfg(feature = "test_utils")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+fn init_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin));
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            bundle: Lifetime::from_seconds(4.0),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    (app, target)
+}
+
+#[test]
+fn despawn_after_lifetime() {
+    let (mut app, target) = init_app();
+
+    app.advance_time(Duration::from_secs(3));
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn update_without_advancing() {
+    let (mut app, target) = init_app();
+
+    app.advance_time(Duration::from_secs(3));
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+}
+
+#[test]
+fn permanent_never_despawns() {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin));
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            bundle: Lifetime::permanent(),
+            ..Default::default()
+        });
+
+    app.advance_time(Duration::from_secs(1_000_000));
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+}
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+#[test]
+fn apply_for() {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin));
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .apply_for(Poison, Duration::from_secs(2));
+    app.world_mut().flush();
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    assert_eq!(app.world().get::<Name>(effect), Some(&Name::new("Poison")));
+    assert_eq!(
+        app.world().get::<EffectMode>(effect),
+        Some(&EffectMode::Insert)
+    );
+
+    app.advance_time(Duration::from_secs(1));
+
+    // Reapplying refreshes the duration.
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .apply_for(Poison, Duration::from_secs(2));
+
+    app.advance_time(Duration::from_secs(1));
+    assert_eq!(app.world().get::<EffectedBy>(target).unwrap().len(), 1);
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn despawned_effect_does_not_panic() {
+    let (mut app, target) = init_app();
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    app.world_mut().despawn(effect);
+
+    app.advance_time(Duration::from_secs(5));
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+/// Despawns every effect, like a cleanse would.
+fn cleanse(mut commands: Commands, effects: Query<Entity, With<Effecting>>) {
+    for effect in &effects {
+        commands.entity(effect).despawn();
+    }
+}
+
+#[test]
+fn despawned_in_same_frame_does_not_panic() {
+    for linger in [false, true] {
+        let mut app = App::new();
+        // The cleanse's commands are applied after the finished lifetimes are found.
+        app.add_plugins((AlchemyPlugin, TestTimePlugin))
+            .add_systems(
+                PreUpdate,
+                cleanse
+                    .after(AlchemySet::TickTimers)
+                    .before_ignore_deferred(AlchemySet::DespawnExpired),
+            );
+
+        let target = app.world_mut().spawn_empty().id();
+        let mut commands = app.world_mut().commands();
+        if linger {
+            commands.entity(target).with_effect(EffectBundle::new((
+                Lifetime::from_seconds(1.0),
+                LingerOnExpire,
+            )));
+        } else {
+            commands
+                .entity(target)
+                .with_effect(EffectBundle::new(Lifetime::from_seconds(1.0)));
+        }
+        app.world_mut().flush();
+
+        app.advance_time(Duration::from_secs(1));
+        assert!(app.world().get::<EffectedBy>(target).is_none());
+    }
+}
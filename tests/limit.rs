@@ -0,0 +1,109 @@
+//! Tests the behaviour of limiting effects using [`MaxEffects`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn apply_many(limit: MaxEffects) -> (World, Entity) {
+    let mut world = World::new();
+
+    let target = world.spawn(limit).id();
+
+    for i in 0..4 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Stack),
+            bundle: MyEffect(i),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    (world, target)
+}
+
+fn effect_values(world: &World, target: Entity) -> Vec<u8> {
+    world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .map(|effect| world.get::<MyEffect>(effect).unwrap().0)
+        .collect()
+}
+
+#[test]
+fn reject() {
+    let (world, target) = apply_many(MaxEffects::new(2));
+    assert_eq!(effect_values(&world, target), vec![0, 1]);
+}
+
+#[test]
+fn evict_oldest() {
+    let (world, target) =
+        apply_many(MaxEffects::new(2).with_policy(EffectLimitPolicy::EvictOldest));
+    assert_eq!(effect_values(&world, target), vec![2, 3]);
+}
+
+#[test]
+fn evict_reapplied_last() {
+    let mut world = World::new();
+
+    let target = world
+        .spawn(MaxEffects::new(2).with_policy(EffectLimitPolicy::EvictOldest))
+        .id();
+
+    for (name, i) in [("A", 0), ("B", 1), ("A", 2), ("C", 3)] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new(name),
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(i),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    // `A` was reapplied after `B`, so `B` is the oldest.
+    assert_eq!(effect_values(&world, target), vec![2, 3]);
+}
+
+#[test]
+fn reapply_at_limit() {
+    let mut world = World::new();
+
+    let target = world.spawn(MaxEffects::new(1)).id();
+
+    for i in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(i),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    assert_eq!(effect_values(&world, target), vec![1]);
+}
+
+#[test]
+fn batch_limit() {
+    let mut world = World::new();
+
+    let target = world.spawn(MaxEffects::new(2)).id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_stacked_effects((0..4).map(|i| EffectBundle {
+            mode: Some(EffectMode::Stack),
+            bundle: MyEffect(i),
+            ..Default::default()
+        }));
+
+    world.flush();
+
+    assert_eq!(effect_values(&world, target), vec![0, 1]);
+}
@@ -0,0 +1,45 @@
+//! Tests the behaviour of merging [`Magnitude`]s for each [`MagnitudeMergeMode`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+fn merge_magnitudes(first: f32, second: f32, mode: MagnitudeMergeMode) -> f32 {
+    let mut world = World::new();
+
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<Magnitude>(merge_effect_magnitude);
+
+    let target = world.spawn_empty().id();
+
+    for value in [first, second] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: Magnitude::new(value).with_mode(mode),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    world.query::<&Magnitude>().single(&world).unwrap().value
+}
+
+#[test]
+fn merge_sum() {
+    assert_eq!(
+        merge_magnitudes(0.1, 0.2, MagnitudeMergeMode::Sum),
+        0.1 + 0.2
+    );
+}
+
+#[test]
+fn merge_max() {
+    assert_eq!(merge_magnitudes(0.5, 0.2, MagnitudeMergeMode::Max), 0.5);
+    assert_eq!(merge_magnitudes(0.2, 0.5, MagnitudeMergeMode::Max), 0.5);
+}
+
+#[test]
+fn merge_average() {
+    assert_eq!(merge_magnitudes(1.0, 2.0, MagnitudeMergeMode::Average), 1.5);
+}
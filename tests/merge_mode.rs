@@ -1,7 +1,10 @@
 //! Tests the behaviour of adding effects with each [`EffectMode`].
+#![cfg(feature = "timers")]
 
 use bevy_alchemy::*;
+use bevy_ecs::entity_disabling::Disabled;
 use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
 use bevy_time::*;
 use std::time::Duration;
 
@@ -58,12 +61,12 @@ fn insert() {
     let target = world.spawn_empty().id();
 
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Insert,
+        mode: Some(EffectMode::Insert),
         bundle: MyEffect(0),
         ..Default::default()
     });
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Insert,
+        mode: Some(EffectMode::Insert),
         bundle: MyEffect(1),
         ..Default::default()
     });
@@ -96,12 +99,12 @@ fn mixed() {
     });
 
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Insert,
+        mode: Some(EffectMode::Insert),
         bundle: MyEffect(2),
         ..Default::default()
     });
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Insert,
+        mode: Some(EffectMode::Insert),
         bundle: MyEffect(3),
         ..Default::default()
     });
@@ -127,7 +130,7 @@ fn timer_merge_replace() {
     let target = world.spawn_empty().id();
     let second_lifetime = Lifetime::from_seconds(2.0).with_mode(TimerMergeMode::Replace);
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (
             Lifetime::from_seconds(1.0).with_mode(TimerMergeMode::Replace),
             MyEffect(0),
@@ -135,7 +138,7 @@ fn timer_merge_replace() {
         ..Default::default()
     });
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (second_lifetime.clone(), MyEffect(1)),
         ..Default::default()
     });
@@ -159,12 +162,12 @@ fn timer_merge_keep() {
     let target = world.spawn_empty().id();
     let first_delay = Delay::from_seconds(1.0).with_mode(TimerMergeMode::Keep);
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (first_delay.clone(), MyEffect(0)),
         ..Default::default()
     });
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (
             Delay::from_seconds(2.0).with_mode(TimerMergeMode::Keep),
             MyEffect(1),
@@ -193,18 +196,19 @@ fn timer_merge_fraction() {
     first_timer.tick(Duration::from_secs_f32(1.0));
 
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (
             Delay {
                 timer: first_timer,
                 mode: TimerMergeMode::Fraction,
+                jitter: None,
             },
             MyEffect(0),
         ),
         ..Default::default()
     });
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (
             Delay::from_seconds(10.0).with_mode(TimerMergeMode::Fraction),
             MyEffect(1),
@@ -222,6 +226,7 @@ fn timer_merge_fraction() {
         &Delay {
             timer: expected_timer,
             mode: TimerMergeMode::Fraction,
+            jitter: None,
         }
     );
     assert_eq!(
@@ -238,7 +243,7 @@ fn timer_merge_max() {
     let max = Delay::from_seconds(3.0).with_mode(TimerMergeMode::Max);
 
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (
             Delay::from_seconds(1.0).with_mode(TimerMergeMode::Max),
             MyEffect(0),
@@ -246,12 +251,12 @@ fn timer_merge_max() {
         ..Default::default()
     });
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (max.clone(), MyEffect(1)),
         ..Default::default()
     });
     world.commands().entity(target).with_effect(EffectBundle {
-        mode: EffectMode::Merge,
+        mode: Some(EffectMode::Merge),
         bundle: (
             Delay::from_seconds(2.0).with_mode(TimerMergeMode::Max),
             MyEffect(2),
@@ -267,3 +272,186 @@ fn timer_merge_max() {
         &MyEffect(2)
     );
 }
+
+#[test]
+fn timer_merge_multiple() {
+    let mut world = init_world();
+
+    let target = world.spawn_empty().id();
+
+    for _ in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: (
+                Lifetime::from_seconds(1.0).with_mode(TimerMergeMode::Sum),
+                Delay::from_seconds(2.0).with_mode(TimerMergeMode::Sum),
+                MyEffect(0),
+            ),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    let lifetime = world.query::<&Lifetime>().single(&world).unwrap();
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(2));
+
+    let delay = world.query::<&Delay>().single(&world).unwrap();
+    assert_eq!(delay.timer.duration(), Duration::from_secs(4));
+}
+
+#[test]
+fn merge_skips_missing_components() {
+    let mut world = init_world();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effect(EffectBundle {
+        mode: Some(EffectMode::Merge),
+        bundle: (
+            Lifetime::from_seconds(1.0).with_mode(TimerMergeMode::Sum),
+            MyEffect(0),
+        ),
+        ..Default::default()
+    });
+    world.commands().entity(target).with_effect(EffectBundle {
+        mode: Some(EffectMode::Merge),
+        bundle: MyEffect(1),
+        ..Default::default()
+    });
+
+    world.flush();
+
+    let lifetime = world.query::<&Lifetime>().single(&world).unwrap();
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(1));
+}
+
+/// A component that implements `Reflect`, but not `Clone`.
+#[derive(Component, Reflect, Debug, PartialEq, Default)]
+struct ReflectOnly(f32);
+
+fn merge_reflect_only(mut new: EntityWorldMut, outgoing: Entity) {
+    let outgoing = new.world().get::<ReflectOnly>(outgoing).unwrap().0;
+    new.get_mut::<ReflectOnly>().unwrap().0 += outgoing;
+}
+
+#[test]
+fn merge_reflect() {
+    let mut world = init_world();
+    world
+        .resource_mut::<EffectMergeRegistry>()
+        .register_reflect::<ReflectOnly>(merge_reflect_only);
+
+    let target = world.spawn_empty().id();
+
+    for _ in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: ReflectOnly(1.0),
+            ..Default::default()
+        });
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("With Timer"),
+            mode: Some(EffectMode::Merge),
+            bundle: (
+                ReflectOnly(1.0),
+                Lifetime::from_seconds(1.0).with_mode(TimerMergeMode::Sum),
+            ),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    for (reflect_only, lifetime) in world
+        .query::<(&ReflectOnly, Option<&Lifetime>)>()
+        .iter(&world)
+    {
+        assert_eq!(reflect_only, &ReflectOnly(2.0));
+
+        if let Some(lifetime) = lifetime {
+            assert_eq!(lifetime.timer.duration(), Duration::from_secs(2));
+        }
+    }
+}
+
+#[test]
+fn merge_despawns_temp_entity() {
+    let mut world = init_world();
+    world
+        .resource_mut::<EffectMergeRegistry>()
+        .register_reflect::<ReflectOnly>(merge_reflect_only);
+
+    let target = world.spawn_empty().id();
+
+    let apply = |world: &mut World| {
+        // One mergeable component, which uses the typed copy.
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Single"),
+            mode: Some(EffectMode::Merge),
+            bundle: Lifetime::from_seconds(1.0),
+            ..Default::default()
+        });
+        // Many mergeable components, which uses the entity cloner.
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Multiple"),
+            mode: Some(EffectMode::Merge),
+            bundle: (Lifetime::from_seconds(1.0), Delay::from_seconds(1.0)),
+            ..Default::default()
+        });
+        // Many mergeable components, including a reflect-only one.
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Reflect"),
+            mode: Some(EffectMode::Merge),
+            bundle: (ReflectOnly(1.0), Lifetime::from_seconds(1.0)),
+            ..Default::default()
+        });
+        world.flush();
+    };
+
+    apply(&mut world);
+    let expected = world.entities().count_spawned();
+
+    for _ in 0..5 {
+        apply(&mut world);
+    }
+
+    assert_eq!(world.entities().count_spawned(), expected);
+    assert_eq!(
+        world
+            .query_filtered::<(), With<Disabled>>()
+            .iter(&world)
+            .count(),
+        0
+    );
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 3);
+}
+
+#[test]
+fn merge_without_registry() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    for value in [1, 2] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: (
+                Lifetime::from_seconds(value as f32).with_mode(TimerMergeMode::Sum),
+                MyEffect(value),
+            ),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    // Without a registry, the incoming components overwrite the old ones instead of merging.
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 1);
+    assert_eq!(
+        world.query::<&MyEffect>().single(&world).unwrap(),
+        &MyEffect(2)
+    );
+    let lifetime = world.query::<&Lifetime>().single(&world).unwrap();
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(2));
+}
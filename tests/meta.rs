@@ -0,0 +1,131 @@
+//! Tests effects that modify the stack gain of other effects using [`StackGainModifier`].
+#![cfg(feature = "stacks")]
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Burn;
+
+fn init_world() -> (World, Entity) {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    let target = world.spawn_empty().id();
+    (world, target)
+}
+
+fn apply<B: Bundle>(world: &mut World, target: Entity, name: &'static str, bundle: B) {
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new(name),
+        mode: Some(EffectMode::Merge),
+        ..EffectBundle::new(bundle)
+    });
+    world.flush();
+}
+
+fn stacks<T: Component>(world: &mut World) -> EffectStacks {
+    *world
+        .query_filtered::<&EffectStacks, With<T>>()
+        .single(world)
+        .unwrap()
+}
+
+#[test]
+fn double_matching_stack_gain() {
+    let (mut world, target) = init_world();
+
+    apply(
+        &mut world,
+        target,
+        "Curse",
+        StackGainModifier::new(EffectFilter::name("Poison"), 2),
+    );
+
+    apply(&mut world, target, "Poison", Poison);
+    apply(&mut world, target, "Burn", Burn);
+    // The modifier doesn't apply when the effect is first spawned.
+    assert_eq!(stacks::<Poison>(&mut world), EffectStacks(1));
+
+    apply(&mut world, target, "Poison", Poison);
+    apply(&mut world, target, "Burn", Burn);
+    assert_eq!(stacks::<Poison>(&mut world), EffectStacks(3));
+    assert_eq!(stacks::<Burn>(&mut world), EffectStacks(2));
+}
+
+#[test]
+fn modifiers_combine() {
+    let (mut world, target) = init_world();
+
+    apply(
+        &mut world,
+        target,
+        "Curse",
+        StackGainModifier::new(EffectFilter::name("Poison"), 2),
+    );
+    apply(
+        &mut world,
+        target,
+        "Hex",
+        StackGainModifier::new(EffectFilter::All, 3),
+    );
+
+    apply(&mut world, target, "Poison", Poison);
+    apply(&mut world, target, "Poison", Poison);
+    assert_eq!(stacks::<Poison>(&mut world), EffectStacks(7));
+}
+
+#[test]
+fn modifier_removed() {
+    let (mut world, target) = init_world();
+
+    apply(
+        &mut world,
+        target,
+        "Curse",
+        StackGainModifier::new(EffectFilter::name("Poison"), 2),
+    );
+    apply(&mut world, target, "Poison", Poison);
+
+    let curse = world
+        .query_filtered::<Entity, With<StackGainModifier>>()
+        .single(&world)
+        .unwrap();
+    world.despawn(curse);
+
+    apply(&mut world, target, "Poison", Poison);
+    assert_eq!(stacks::<Poison>(&mut world), EffectStacks(2));
+}
+
+#[test]
+fn preview_with_modifier() {
+    let (mut world, target) = init_world();
+
+    apply(
+        &mut world,
+        target,
+        "Curse",
+        StackGainModifier::new(EffectFilter::name("Poison"), 2),
+    );
+    apply(&mut world, target, "Poison", Poison);
+
+    let preview = preview_effect(
+        &world,
+        target,
+        &EffectBundle {
+            name: Name::new("Poison"),
+            mode: Some(EffectMode::Merge),
+            ..EffectBundle::new(Poison)
+        },
+    );
+    assert_eq!(preview.stacks(), Some(3));
+
+    // The preview matches the actual result.
+    apply(&mut world, target, "Poison", Poison);
+    assert_eq!(stacks::<Poison>(&mut world), EffectStacks(3));
+}
@@ -0,0 +1,175 @@
+//! Tests declaring the [`EffectMode`] of effects by name, using the [`EffectModeRegistry`].
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn init_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<EffectMergeRegistry>();
+    world
+        .get_resource_or_init::<EffectModeRegistry>()
+        .register("Poison", EffectMode::Merge);
+    world
+}
+
+fn effect(name: &'static str, value: u8) -> EffectBundle<MyEffect> {
+    EffectBundle {
+        name: Name::new(name),
+        bundle: MyEffect(value),
+        ..Default::default()
+    }
+}
+
+fn modes(world: &mut World, target: Entity) -> Vec<EffectMode> {
+    world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .map(|effect| *world.get::<EffectMode>(effect).unwrap())
+        .collect()
+}
+
+#[test]
+fn registered_mode() {
+    let mut world = init_world();
+    let target = world.spawn_empty().id();
+
+    for value in [1, 2] {
+        world
+            .commands()
+            .entity(target)
+            .with_effect(effect("Poison", value));
+    }
+    world.flush();
+
+    assert_eq!(modes(&mut world, target), [EffectMode::Merge]);
+    assert_eq!(
+        world.query::<&MyEffect>().single(&world).unwrap(),
+        &MyEffect(2)
+    );
+}
+
+#[test]
+fn unregistered_name() {
+    let mut world = init_world();
+    let target = world.spawn_empty().id();
+
+    for value in [1, 2] {
+        world
+            .commands()
+            .entity(target)
+            .with_effect(effect("Regen", value));
+    }
+    world.flush();
+
+    assert_eq!(
+        modes(&mut world, target),
+        [EffectMode::Stack, EffectMode::Stack]
+    );
+}
+
+#[test]
+fn bundle_overrides() {
+    let mut world = init_world();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effect(EffectBundle {
+        mode: Some(EffectMode::Insert),
+        ..effect("Poison", 1)
+    });
+    world.flush();
+
+    assert_eq!(modes(&mut world, target), [EffectMode::Insert]);
+}
+
+#[test]
+fn bundle_overrides_with_stack() {
+    let mut world = init_world();
+    let target = world.spawn_empty().id();
+
+    for value in [1, 2] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Stack),
+            ..effect("Poison", value)
+        });
+    }
+    world.flush();
+
+    assert_eq!(
+        modes(&mut world, target),
+        [EffectMode::Stack, EffectMode::Stack]
+    );
+}
+
+#[test]
+fn stacked_effects() {
+    let mut world = init_world();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_stacked_effects([
+        effect("Poison", 1),
+        effect("Poison", 2),
+        effect("Regen", 3),
+    ]);
+    world.flush();
+
+    assert_eq!(
+        modes(&mut world, target),
+        [EffectMode::Merge, EffectMode::Stack]
+    );
+}
+
+#[test]
+fn unbound_effect() {
+    let mut world = init_world();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(effect("Poison", 1));
+    let unbound = world.commands().spawn_effect(effect("Poison", 2)).id();
+    world.flush();
+
+    world.commands().entity(target).bind_effect(unbound);
+    world.flush();
+
+    assert_eq!(modes(&mut world, target), [EffectMode::Merge]);
+}
+
+#[test]
+fn preview() {
+    let mut world = init_world();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(effect("Poison", 1));
+    world.flush();
+
+    let existing = world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap();
+    let preview = preview_effect(&world, target, &effect("Poison", 2));
+    assert_eq!(preview.outcome, EffectOutcome::Merge(existing));
+}
+
+#[test]
+fn register_with_app() {
+    let mut app = App::new();
+    app.register_effect_mode("Poison", EffectMode::Insert)
+        .add_plugins(AlchemyPlugin);
+
+    assert_eq!(
+        app.world().resource::<EffectModeRegistry>().get("Poison"),
+        Some(EffectMode::Insert)
+    );
+}
@@ -0,0 +1,80 @@
+//! Tests the behaviour of modifying already applied effects.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison {
+    damage: u8,
+}
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Other;
+
+#[test]
+fn modify_named_effect() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: Poison { damage: 1 },
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            name: Name::new("Other Poison"),
+            bundle: Poison { damage: 1 },
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    world
+        .commands()
+        .entity(target)
+        .modify_effect::<Poison>("Poison", |poison| poison.damage += 2);
+
+    world.flush();
+
+    let mut damages: Vec<(String, u8)> = world
+        .query::<(&Name, &Poison)>()
+        .iter(&world)
+        .map(|(name, poison)| (name.to_string(), poison.damage))
+        .collect();
+    damages.sort();
+
+    assert_eq!(
+        damages,
+        vec![("Other Poison".to_string(), 1), ("Poison".to_string(), 3)]
+    );
+}
+
+#[test]
+fn modify_missing() {
+    let mut world = World::new();
+
+    let empty = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new("Other"),
+        bundle: Other,
+        ..Default::default()
+    });
+
+    world.flush();
+
+    world
+        .commands()
+        .entity(empty)
+        .modify_effect::<Poison>("Other", |_| panic!("No effect should be modified."));
+    world
+        .commands()
+        .entity(target)
+        .modify_effect::<Poison>("Other", |_| panic!("No effect should be modified."));
+
+    world.flush();
+}
@@ -0,0 +1,113 @@
+//! Tests the default naming of effects.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Regeneration;
+
+#[test]
+fn default_name() {
+    assert_eq!(EffectBundle::new(Poison).name, Name::new("Poison"));
+    assert_eq!(
+        EffectBundle::<(Poison, Regeneration)>::default().name,
+        Name::new("(Poison, Regeneration)")
+    );
+}
+
+#[test]
+fn different_types_dont_collide() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            mode: Some(EffectMode::Insert),
+            ..EffectBundle::new(Poison)
+        })
+        .with_effect(EffectBundle {
+            mode: Some(EffectMode::Insert),
+            ..EffectBundle::new(Regeneration)
+        });
+    world.flush();
+
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 2);
+}
+
+#[test]
+fn shared_name() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            name: Name::new("Effect"),
+            mode: Some(EffectMode::Insert),
+            ..EffectBundle::new(Poison)
+        })
+        .with_effect(EffectBundle {
+            name: Name::new("Effect"),
+            mode: Some(EffectMode::Insert),
+            ..EffectBundle::new(Regeneration)
+        });
+    world.flush();
+
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 1);
+}
+
+/// Applies two unnamed effects with [`EffectMode::Insert`], returning the number of effects on the target.
+fn apply_unnamed<A: Bundle, B: Bundle>(
+    first: A,
+    second: B,
+    resolver: Option<EffectResolver>,
+) -> usize {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            name: Name::default(),
+            mode: Some(EffectMode::Insert),
+            resolver,
+            ..EffectBundle::new(first)
+        })
+        .with_effect(EffectBundle {
+            name: Name::default(),
+            mode: Some(EffectMode::Insert),
+            resolver,
+            ..EffectBundle::new(second)
+        });
+    world.flush();
+
+    world.get::<EffectedBy>(target).unwrap().len()
+}
+
+#[test]
+fn unnamed_match_components() {
+    assert_eq!(apply_unnamed(Poison, Poison, None), 1);
+    assert_eq!(apply_unnamed(Poison, Regeneration, None), 2);
+}
+
+#[test]
+fn unnamed_match_components_with_resolver() {
+    let resolver = Some(EffectResolver::INSERT);
+    assert_eq!(apply_unnamed(Poison, Poison, resolver), 1);
+    assert_eq!(apply_unnamed(Poison, Regeneration, resolver), 2);
+}
+
+#[test]
+fn unnamed_match_superset() {
+    // The existing effect has every component of the new effect, so they collide.
+    assert_eq!(apply_unnamed((Poison, Regeneration), Poison, None), 1);
+    // The existing effect is missing `Regeneration`, so they don't.
+    assert_eq!(apply_unnamed(Poison, (Poison, Regeneration), None), 2);
+}
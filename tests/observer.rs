@@ -0,0 +1,95 @@
+//! Tests applying effects from observers using [`apply_effect_on`].
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+#[derive(EntityEvent)]
+struct Damaged {
+    entity: Entity,
+    amount: u8,
+}
+
+#[derive(Component, Debug, Eq, PartialEq, Default)]
+struct Bleed {
+    damage: u8,
+}
+
+#[derive(Resource)]
+struct Target(Entity);
+
+#[derive(Resource, Default)]
+struct Seen(Vec<u8>);
+
+fn bleed(damaged: &Damaged) -> EffectBundle<Bleed> {
+    EffectBundle::new(Bleed {
+        damage: damaged.amount / 2,
+    })
+}
+
+#[test]
+fn world_trigger() {
+    let mut world = World::new();
+    world.add_observer(apply_effect_on(bleed));
+
+    let target = world.spawn_empty().id();
+    world.trigger(Damaged {
+        entity: target,
+        amount: 10,
+    });
+    world.flush();
+
+    let effects: Vec<&Bleed> = world.query::<&Bleed>().iter(&world).collect();
+    assert_eq!(effects, vec![&Bleed { damage: 5 }]);
+    assert_eq!(
+        world.query::<&Effecting>().single(&world).unwrap(),
+        &Effecting(target)
+    );
+}
+
+#[test]
+fn entity_observer() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().observe(apply_effect_on(bleed)).id();
+    let other = world.spawn_empty().id();
+
+    for entity in [target, other] {
+        world.trigger(Damaged { entity, amount: 4 });
+    }
+    world.flush();
+
+    assert_eq!(
+        world
+            .query::<(&Effecting, &Bleed)>()
+            .single(&world)
+            .unwrap(),
+        (&Effecting(target), &Bleed { damage: 2 })
+    );
+}
+
+/// Effects applied by an observer should be visible to systems ordered after the triggering system.
+#[test]
+fn visible_to_later_systems() {
+    fn deal_damage(mut commands: Commands, target: Res<Target>) {
+        commands.trigger(Damaged {
+            entity: target.0,
+            amount: 6,
+        });
+    }
+
+    fn read_bleed(effects: Query<&Bleed>, mut seen: ResMut<Seen>) {
+        seen.0.extend(effects.iter().map(|bleed| bleed.damage));
+    }
+
+    let mut app = App::new();
+    app.add_observer(apply_effect_on(bleed))
+        .init_resource::<Seen>()
+        .add_systems(Update, (deal_damage, read_bleed).chain());
+
+    let target = app.world_mut().spawn_empty().id();
+    app.insert_resource(Target(target));
+    app.update();
+
+    assert_eq!(app.world().resource::<Seen>().0, vec![3]);
+}
@@ -0,0 +1,96 @@
+//! Tests sorting effects by when they were applied, using [`EffectOrder`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn apply(world: &mut World, target: Entity, name: &'static str, mode: EffectMode) {
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new(name),
+        mode: Some(mode),
+        bundle: MyEffect(0),
+        ..Default::default()
+    });
+    world.flush();
+}
+
+/// Returns the names of the effects, from newest to oldest.
+fn newest_first(world: &mut World) -> Vec<String> {
+    let mut effects: Vec<(EffectOrder, String)> = world
+        .query::<(&EffectOrder, &Name)>()
+        .iter(world)
+        .map(|(order, name)| (*order, name.to_string()))
+        .collect();
+    effects.sort();
+    effects.into_iter().rev().map(|(_, name)| name).collect()
+}
+
+#[test]
+fn increases_when_applied() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    for name in ["A", "B", "C"] {
+        apply(&mut world, target, name, EffectMode::Stack);
+    }
+
+    assert_eq!(newest_first(&mut world), vec!["C", "B", "A"]);
+}
+
+#[test]
+fn reapplied_effects_are_newest() {
+    for mode in [EffectMode::Insert, EffectMode::Merge, EffectMode::Select] {
+        let mut world = World::new();
+        world.init_resource::<EffectMergeRegistry>();
+        let target = world.spawn_empty().id();
+
+        apply(&mut world, target, "A", mode);
+        apply(&mut world, target, "B", mode);
+        apply(&mut world, target, "A", mode);
+
+        assert_eq!(newest_first(&mut world), vec!["A", "B"], "{mode:?}");
+    }
+}
+
+#[test]
+fn batched_effects() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_stacked_effects(["A", "B"].map(|name| EffectBundle {
+            name: Name::new(name),
+            mode: Some(EffectMode::Stack),
+            bundle: MyEffect(0),
+            ..Default::default()
+        }));
+    world.flush();
+    apply(&mut world, target, "C", EffectMode::Stack);
+
+    assert_eq!(newest_first(&mut world), vec!["C", "B", "A"]);
+}
+
+#[test]
+fn bound_effects() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let effect = world
+        .commands()
+        .spawn_effect(EffectBundle {
+            name: Name::new("A"),
+            bundle: MyEffect(0),
+            ..Default::default()
+        })
+        .id();
+    apply(&mut world, target, "B", EffectMode::Stack);
+
+    world.commands().entity(target).bind_effect(effect);
+    world.flush();
+
+    assert_eq!(newest_first(&mut world), vec!["A", "B"]);
+}
@@ -0,0 +1,92 @@
+//! Tests pausing every effect on a target using [`EffectsPaused`].
+#![cfg(feature = "test_utils")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+fn init_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin));
+
+    let target = app.world_mut().spawn(EffectsPaused).id();
+    let other = app.world_mut().spawn_empty().id();
+
+    for entity in [target, other] {
+        app.world_mut()
+            .commands()
+            .entity(entity)
+            .with_effect(EffectBundle {
+                bundle: (Lifetime::from_seconds(2.0), Delay::from_seconds(1.0)),
+                ..Default::default()
+            });
+    }
+    app.world_mut().flush();
+
+    (app, target, other)
+}
+
+fn effect(app: &App, target: Entity) -> Entity {
+    app.world().get::<EffectedBy>(target).unwrap().collection()[0]
+}
+
+#[test]
+fn paused_target() {
+    let (mut app, target, other) = init_app();
+
+    app.advance_time(Duration::from_secs(1));
+
+    let paused = effect(&app, target);
+    assert_eq!(
+        app.world().get::<Delay>(paused).unwrap().timer.elapsed(),
+        Duration::ZERO
+    );
+    assert_eq!(
+        app.world().get::<EffectAge>(paused).unwrap().elapsed,
+        Duration::ZERO
+    );
+
+    let unpaused = effect(&app, other);
+    assert!(
+        app.world()
+            .get::<Delay>(unpaused)
+            .unwrap()
+            .timer
+            .just_finished()
+    );
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+    assert!(app.world().get::<EffectedBy>(other).is_none());
+}
+
+#[test]
+fn resume() {
+    let (mut app, target, _) = init_app();
+
+    app.advance_time(Duration::from_secs(1));
+    app.world_mut().entity_mut(target).remove::<EffectsPaused>();
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn individually_paused() {
+    let (mut app, target, _) = init_app();
+
+    let effect = effect(&app, target);
+    app.world_mut()
+        .get_mut::<Lifetime>(effect)
+        .unwrap()
+        .timer
+        .pause();
+    app.world_mut().entity_mut(target).remove::<EffectsPaused>();
+
+    app.advance_time(Duration::from_secs(3));
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+}
@@ -0,0 +1,164 @@
+//! Tests predicting the result of applying an effect using [`preview_effect`].
+#![cfg(all(feature = "timers", feature = "stacks"))]
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+fn init_world() -> (World, Entity) {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks)
+        .register::<Lifetime>(merge_effect_timer::<Lifetime>)
+        .register_replacement::<Magnitude>(keep_stronger_magnitude);
+
+    let target = world.spawn_empty().id();
+    (world, target)
+}
+
+fn poison(mode: EffectMode, seconds: f32) -> EffectBundle<(Poison, Lifetime)> {
+    EffectBundle {
+        name: Name::new("Poison"),
+        mode: Some(mode),
+        ..EffectBundle::new((
+            Poison,
+            Lifetime::from_seconds(seconds).with_mode(TimerMergeMode::Sum),
+        ))
+    }
+}
+
+#[test]
+fn spawn() {
+    let (world, target) = init_world();
+
+    let preview = preview_effect(&world, target, &poison(EffectMode::Merge, 2.0));
+
+    assert_eq!(preview.outcome, EffectOutcome::Spawn);
+    assert_eq!(preview.stacks(), Some(1));
+    assert_eq!(preview.get::<Poison>(), Some(&Poison));
+}
+
+#[test]
+fn merge() {
+    let (mut world, target) = init_world();
+
+    for _ in 0..2 {
+        world
+            .commands()
+            .entity(target)
+            .with_effect(poison(EffectMode::Merge, 2.0));
+    }
+    world.flush();
+
+    let existing = world.query::<(Entity, &Poison)>().single(&world).unwrap().0;
+    let preview = preview_effect(&world, target, &poison(EffectMode::Merge, 1.0));
+
+    assert_eq!(preview.outcome, EffectOutcome::Merge(existing));
+    assert_eq!(preview.stacks(), Some(3));
+    assert_eq!(
+        preview.get::<Lifetime>().unwrap().timer.duration(),
+        Duration::from_secs(5)
+    );
+
+    // The world isn't modified.
+    assert_eq!(world.get::<EffectStacks>(existing), Some(&EffectStacks(2)));
+    assert_eq!(world.query::<&Poison>().iter(&world).count(), 1);
+
+    // The preview should match actually applying the effect.
+    world
+        .commands()
+        .entity(target)
+        .with_effect(poison(EffectMode::Merge, 1.0));
+    world.flush();
+
+    assert_eq!(world.get::<EffectStacks>(existing), Some(&EffectStacks(3)));
+    assert_eq!(
+        world.get::<Lifetime>(existing).unwrap().timer.duration(),
+        Duration::from_secs(5)
+    );
+}
+
+#[test]
+fn insert() {
+    let (mut world, target) = init_world();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(poison(EffectMode::Insert, 2.0));
+    world.flush();
+
+    let existing = world.query::<(Entity, &Poison)>().single(&world).unwrap().0;
+    let preview = preview_effect(&world, target, &poison(EffectMode::Insert, 1.0));
+
+    assert_eq!(preview.outcome, EffectOutcome::Insert(existing));
+    assert_eq!(
+        preview.get::<Lifetime>().unwrap().timer.duration(),
+        Duration::from_secs(1)
+    );
+}
+
+#[test]
+fn select() {
+    let (mut world, target) = init_world();
+
+    let magnitude = |value: f32| EffectBundle {
+        name: Name::new("Strength"),
+        mode: Some(EffectMode::Select),
+        ..EffectBundle::new(Magnitude::new(value))
+    };
+
+    world.commands().entity(target).with_effect(magnitude(2.0));
+    world.flush();
+
+    let existing = world
+        .query::<(Entity, &Magnitude)>()
+        .single(&world)
+        .unwrap()
+        .0;
+
+    let weaker = preview_effect(&world, target, &magnitude(1.0));
+    assert_eq!(weaker.outcome, EffectOutcome::Keep(existing));
+    assert_eq!(weaker.get::<Magnitude>(), Some(&Magnitude::new(2.0)));
+
+    let stronger = preview_effect(&world, target, &magnitude(3.0));
+    assert_eq!(stronger.outcome, EffectOutcome::Replace(existing));
+    assert_eq!(stronger.get::<Magnitude>(), Some(&Magnitude::new(3.0)));
+}
+
+#[test]
+fn blocked() {
+    let (mut world, target) = init_world();
+    world
+        .entity_mut(target)
+        .insert(EffectImmunity::default().with(EffectFilter::name("Poison")));
+
+    let preview = preview_effect(&world, target, &poison(EffectMode::Merge, 1.0));
+
+    assert_eq!(preview.outcome, EffectOutcome::Blocked);
+    assert_eq!(preview.stacks(), None);
+    assert_eq!(preview.get::<Poison>(), None);
+}
+
+#[test]
+fn blocked_by_stack_limit() {
+    let (mut world, target) = init_world();
+    world
+        .entity_mut(target)
+        .insert(StackLimits::default().with_limit("Poison", 2));
+
+    for _ in 0..2 {
+        world
+            .commands()
+            .entity(target)
+            .with_effect(poison(EffectMode::Stack, 1.0));
+    }
+    world.flush();
+
+    let preview = preview_effect(&world, target, &poison(EffectMode::Stack, 1.0));
+    assert_eq!(preview.outcome, EffectOutcome::Blocked);
+}
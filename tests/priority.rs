@@ -0,0 +1,77 @@
+//! Tests the behaviour of [`EffectPriority`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn apply_all(
+    target: impl Bundle,
+    mode: EffectMode,
+    priorities: impl IntoIterator<Item = i32>,
+) -> (World, Entity) {
+    let mut world = World::new();
+
+    let target = world.spawn(target).id();
+
+    for (i, priority) in priorities.into_iter().enumerate() {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(mode),
+            priority: EffectPriority(priority),
+            bundle: MyEffect(i as u8),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    (world, target)
+}
+
+fn effect_values(world: &World, target: Entity) -> Vec<u8> {
+    world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .map(|effect| world.get::<MyEffect>(effect).unwrap().0)
+        .collect()
+}
+
+#[test]
+fn insert_lower_priority() {
+    let (world, target) = apply_all((), EffectMode::Insert, [1, 0]);
+    assert_eq!(effect_values(&world, target), vec![0]);
+}
+
+#[test]
+fn insert_equal_or_higher_priority() {
+    let (world, target) = apply_all((), EffectMode::Insert, [0, 0, 1]);
+    assert_eq!(effect_values(&world, target), vec![2]);
+}
+
+#[test]
+fn select_lower_priority() {
+    let (world, target) = apply_all((), EffectMode::Select, [1, 0]);
+    assert_eq!(effect_values(&world, target), vec![0]);
+}
+
+#[test]
+fn evict_lowest_priority() {
+    let (world, target) = apply_all(
+        MaxEffects::new(2).with_policy(EffectLimitPolicy::EvictLowestPriority),
+        EffectMode::Stack,
+        [1, 0, 0, 2],
+    );
+    assert_eq!(effect_values(&world, target), vec![0, 3]);
+}
+
+#[test]
+fn evict_lowest_priority_reject() {
+    let (world, target) = apply_all(
+        MaxEffects::new(2).with_policy(EffectLimitPolicy::EvictLowestPriority),
+        EffectMode::Stack,
+        [1, 1, 0],
+    );
+    assert_eq!(effect_values(&world, target), vec![0, 1]);
+}
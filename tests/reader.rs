@@ -0,0 +1,187 @@
+//! Tests reading the effects on a target using the [`EffectReader`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::RunSystemOnce;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Poison;
+
+fn init_world() -> (World, Entity) {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    for _ in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: Poison,
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    (world, target)
+}
+
+#[test]
+fn effects_on() {
+    let (mut world, target) = init_world();
+
+    let count = world
+        .run_system_once(move |reader: EffectReader| reader.effects_on(target).count())
+        .unwrap();
+    assert_eq!(count, 2);
+
+    let other = world.spawn_empty().id();
+    let count = world
+        .run_system_once(move |reader: EffectReader| reader.effects_on(other).count())
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn has_effect() {
+    let (mut world, target) = init_world();
+
+    let (poison, burn) = world
+        .run_system_once(move |reader: EffectReader| {
+            (
+                reader.has_effect(target, "Poison"),
+                reader.has_effect(target, "Burn"),
+            )
+        })
+        .unwrap();
+
+    assert!(poison);
+    assert!(!burn);
+}
+
+#[test]
+fn stacks_of() {
+    let (mut world, target) = init_world();
+
+    let stacks = world
+        .run_system_once(move |reader: EffectReader| reader.stacks_of(target, "Poison"))
+        .unwrap();
+    assert_eq!(stacks, 2);
+}
+
+#[cfg(feature = "stacks")]
+#[test]
+fn stacks_of_merged() {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    let target = world.spawn_empty().id();
+
+    for _ in 0..3 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Poison"),
+            mode: Some(EffectMode::Merge),
+            bundle: Poison,
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    let stacks = world
+        .run_system_once(move |reader: EffectReader| reader.stacks_of(target, "Poison"))
+        .unwrap();
+    assert_eq!(stacks, 3);
+}
+
+#[cfg(feature = "stacks")]
+#[test]
+fn stacks_of_mixed() {
+    let (mut world, target) = init_world();
+
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new("Poison"),
+        bundle: (Poison, EffectStacks(3)),
+        ..Default::default()
+    });
+
+    world.flush();
+
+    let stacks = world
+        .run_system_once(move |reader: EffectReader| reader.stacks_of(target, "Poison"))
+        .unwrap();
+    assert_eq!(stacks, 5);
+}
+
+#[cfg(feature = "timers")]
+#[test]
+fn remaining_lifetime() {
+    use std::time::Duration;
+
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    for seconds in [1.0, 3.0] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: Lifetime::from_seconds(seconds),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    let (poison, burn) = world
+        .run_system_once(move |reader: EffectReader| {
+            (
+                reader.remaining_lifetime(target, "Poison"),
+                reader.remaining_lifetime(target, "Burn"),
+            )
+        })
+        .unwrap();
+
+    assert_eq!(poison, Some(Duration::from_secs(3)));
+    assert_eq!(burn, None);
+}
+
+#[cfg(feature = "timers")]
+#[test]
+fn lifetime_fraction() {
+    use std::time::Duration;
+
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    for seconds in [1.0, 4.0] {
+        let mut lifetime = Lifetime::from_seconds(seconds);
+        lifetime.timer.tick(Duration::from_millis(500));
+
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: lifetime,
+            ..Default::default()
+        });
+    }
+
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new("Burn"),
+        bundle: Poison,
+        ..Default::default()
+    });
+
+    world.flush();
+
+    let (poison, burn, missing) = world
+        .run_system_once(move |reader: EffectReader| {
+            (
+                reader.lifetime_fraction(target, "Poison"),
+                reader.lifetime_fraction(target, "Burn"),
+                reader.lifetime_fraction(target, "Missing"),
+            )
+        })
+        .unwrap();
+
+    assert_eq!(poison, Some(0.875));
+    assert_eq!(burn, None);
+    assert_eq!(missing, None);
+}
@@ -0,0 +1,133 @@
+//! Tests registering and unregistering functions in the [`EffectMergeRegistry`].
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypePath;
+
+fn apply_twice(world: &mut World) -> f32 {
+    let target = world.spawn_empty().id();
+
+    for value in [1.0, 2.0] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: Magnitude::new(value),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    world.query::<&Magnitude>().single(world).unwrap().value
+}
+
+#[test]
+fn contains() {
+    let mut registry = EffectMergeRegistry::default();
+    assert!(!registry.contains::<Magnitude>());
+
+    registry.register::<Magnitude>(merge_effect_magnitude);
+    assert!(registry.contains::<Magnitude>());
+}
+
+#[test]
+fn unregister() {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<Magnitude>(merge_effect_magnitude);
+
+    let removed = world
+        .resource_mut::<EffectMergeRegistry>()
+        .unregister::<Magnitude>();
+
+    assert!(removed.is_some());
+    assert!(
+        !world
+            .resource::<EffectMergeRegistry>()
+            .contains::<Magnitude>()
+    );
+
+    // Without a merge function, the new value overwrites the old one.
+    assert_eq!(apply_twice(&mut world), 2.0);
+}
+
+#[test]
+fn unregister_missing() {
+    let mut registry = EffectMergeRegistry::default();
+    assert!(registry.unregister::<Magnitude>().is_none());
+}
+
+#[test]
+fn registered_types() {
+    let mut registry = EffectMergeRegistry::default();
+    registry.register::<Magnitude>(merge_effect_magnitude);
+
+    let types: Vec<_> = registry.registered_types().collect();
+    assert_eq!(types, vec![std::any::TypeId::of::<Magnitude>()]);
+}
+
+#[test]
+fn registered_type_paths() {
+    let mut registry = EffectMergeRegistry::default();
+    registry.register::<Magnitude>(merge_effect_magnitude);
+
+    let mut type_registry = bevy_reflect::TypeRegistry::default();
+    assert_eq!(registry.registered_type_paths(&type_registry).count(), 0);
+
+    type_registry.register::<Magnitude>();
+    let paths: Vec<_> = registry.registered_type_paths(&type_registry).collect();
+    assert_eq!(paths, vec![<Magnitude as TypePath>::type_path()]);
+}
+
+#[derive(Component, Debug, PartialEq, Clone)]
+struct Counter(u32);
+
+fn merge_counter(mut new: EntityWorldMut, outgoing: Entity) {
+    let old = new.world().get::<Counter>(outgoing).unwrap().0;
+    new.get_mut::<Counter>().unwrap().0 += old;
+}
+
+fn apply_counter_twice(world: &mut World) -> u32 {
+    let target = world.spawn_empty().id();
+
+    for _ in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            ..EffectBundle::new(Counter(1))
+        });
+    }
+
+    world.flush();
+
+    world.query::<&Counter>().single(world).unwrap().0
+}
+
+#[test]
+fn register_with_app() {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin)
+        .register_effect_merge::<Counter>(merge_counter);
+
+    let world = app.world_mut();
+    assert!(
+        world
+            .resource::<EffectMergeRegistry>()
+            .contains::<Counter>()
+    );
+    assert_eq!(apply_counter_twice(world), 2);
+}
+
+#[test]
+fn register_with_app_before_plugin() {
+    let mut app = App::new();
+    app.register_effect_merge::<Counter>(merge_counter)
+        .add_plugins(AlchemyPlugin);
+
+    let world = app.world_mut();
+    let registry = world.resource::<EffectMergeRegistry>();
+    assert!(registry.contains::<Counter>());
+    // The plugin's own merge functions are still registered.
+    assert!(registry.contains::<Magnitude>());
+    assert_eq!(apply_counter_twice(world), 2);
+}
@@ -0,0 +1,167 @@
+//! Tests the behaviour of the [`Effecting`]/[`EffectedBy`] relationship.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default)]
+struct MyEffect(u8);
+
+#[test]
+fn despawn_target_despawns_effects() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+    });
+    world.commands().entity(other).with_effect(EffectBundle {
+        bundle: MyEffect(2),
+        ..Default::default()
+    });
+
+    world.flush();
+
+    let effects: Vec<Entity> = world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .collection()
+        .clone();
+    assert_eq!(effects.len(), 2);
+
+    world.despawn(target);
+
+    for effect in effects {
+        assert!(world.get_entity(effect).is_err());
+    }
+
+    let remaining: Vec<u8> = world
+        .query::<&MyEffect>()
+        .iter(&world)
+        .map(|c| c.0)
+        .collect();
+    assert_eq!(remaining, vec![2]);
+}
+
+#[test]
+fn count_and_has_effect() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            name: Name::new("Poison"),
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            name: Name::new("Burn"),
+            bundle: MyEffect(1),
+            ..Default::default()
+        });
+    });
+
+    world.flush();
+
+    let effected_by = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effected_by.count(), 2);
+    assert!(effected_by.has_effect(&world, "Poison"));
+    assert!(!effected_by.has_effect(&world, "Freeze"));
+}
+
+#[cfg(feature = "timers")]
+#[test]
+fn sorted_by_lifetime() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+    let none = world.spawn((Effecting(target), MyEffect(0))).id();
+    let long = world
+        .spawn((Effecting(target), Lifetime::from_seconds(5.0)))
+        .id();
+    let short = world
+        .spawn((Effecting(target), Lifetime::from_seconds(1.0)))
+        .id();
+    let tie = world
+        .spawn((Effecting(target), Lifetime::from_seconds(1.0)))
+        .id();
+    let permanent = world.spawn((Effecting(target), Lifetime::permanent())).id();
+
+    let effected_by = world.get::<EffectedBy>(target).unwrap();
+
+    assert_eq!(
+        effected_by.sorted_by_lifetime(&world, false),
+        vec![short, tie, long, permanent, none]
+    );
+    assert_eq!(
+        effected_by.sorted_by_lifetime(&world, true),
+        vec![permanent, long, short, tie, none]
+    );
+}
+
+#[cfg(feature = "timers")]
+#[test]
+fn sorted_by_lifetime_in_system() {
+    use bevy_ecs::system::RunSystemOnce;
+
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+    let long = world
+        .spawn((Effecting(target), Lifetime::from_seconds(5.0)))
+        .id();
+    let short = world
+        .spawn((Effecting(target), Lifetime::from_seconds(1.0)))
+        .id();
+
+    let sorted = world
+        .run_system_once(|targets: Query<&EffectedBy>, lifetimes: Query<&Lifetime>| {
+            targets
+                .single()
+                .unwrap()
+                .sorted_by_lifetime_in(&lifetimes, false)
+        })
+        .unwrap();
+
+    assert_eq!(sorted, vec![short, long]);
+}
+
+#[test]
+fn get_target() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new(MyEffect(0)));
+    let unbound = world
+        .commands()
+        .spawn_effect(EffectBundle::new(MyEffect(1)))
+        .id();
+    world.flush();
+
+    let effect = world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap();
+
+    assert_eq!(world.get::<Effecting>(effect).unwrap().target(), target);
+    assert_eq!(Effecting::get_target(&world, effect), Some(target));
+    assert_eq!(Effecting::get_target(&world, unbound), None);
+    assert_eq!(Effecting::get_target(&world, target), None);
+
+    world.despawn(target);
+    assert_eq!(Effecting::get_target(&world, effect), None);
+}
@@ -0,0 +1,219 @@
+//! Tests capturing and applying [`EffectSnapshot`]s, for replicating effects.
+#![cfg(all(feature = "timers", feature = "stacks"))]
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect;
+
+fn apply(
+    world: &mut World,
+    target: Entity,
+    name: &'static str,
+    bundle: impl Bundle + Default,
+) -> Entity {
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new(name),
+        mode: Some(EffectMode::Merge),
+        bundle,
+        ..Default::default()
+    });
+    world.flush();
+
+    world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .last()
+        .unwrap()
+}
+
+#[test]
+fn snapshot_effect() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let effect = apply(
+        &mut world,
+        target,
+        "Poison",
+        (EffectStacks(3), Lifetime::from_seconds(5.0)),
+    );
+    world
+        .get_mut::<Lifetime>(effect)
+        .unwrap()
+        .timer
+        .set_elapsed(Duration::from_secs(2));
+
+    assert_eq!(
+        EffectSnapshot::from_effect(&world, effect),
+        Some(EffectSnapshot {
+            name: "Poison".to_string(),
+            stacks: 3,
+            remaining: Some(Duration::from_secs(3)),
+        })
+    );
+}
+
+#[test]
+fn snapshot_defaults() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let effect = apply(
+        &mut world,
+        target,
+        "Aura",
+        (MyEffect, Lifetime::permanent()),
+    );
+
+    let snapshot = EffectSnapshot::from_effect(&world, effect).unwrap();
+    assert_eq!(snapshot.stacks, 1);
+    assert_eq!(snapshot.remaining, None);
+
+    assert_eq!(EffectSnapshot::from_effect(&world, target), None);
+}
+
+#[test]
+fn snapshot_target() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    apply(&mut world, target, "A", MyEffect);
+    apply(&mut world, target, "B", MyEffect);
+
+    let names: Vec<_> = EffectSnapshot::from_target(&world, target)
+        .into_iter()
+        .map(|snapshot| snapshot.name)
+        .collect();
+    assert_eq!(names, ["A", "B"]);
+}
+
+#[test]
+fn apply_updates_existing() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let effect = apply(
+        &mut world,
+        target,
+        "Poison",
+        (MyEffect, Lifetime::from_seconds(5.0)),
+    );
+
+    world
+        .commands()
+        .entity(target)
+        .apply_snapshot(EffectSnapshot {
+            name: "Poison".to_string(),
+            stacks: 4,
+            remaining: Some(Duration::from_secs(2)),
+        });
+    world.flush();
+
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 1);
+    assert_eq!(world.get::<EffectStacks>(effect), Some(&EffectStacks(4)));
+    assert!(world.get::<MyEffect>(effect).is_some());
+
+    let lifetime = world.get::<Lifetime>(effect).unwrap();
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(5));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(2));
+}
+
+#[test]
+fn apply_extends_duration() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let effect = apply(&mut world, target, "Poison", Lifetime::from_seconds(1.0));
+
+    world
+        .commands()
+        .entity(target)
+        .apply_snapshot(EffectSnapshot {
+            name: "Poison".to_string(),
+            stacks: 1,
+            remaining: Some(Duration::from_secs(3)),
+        });
+    world.flush();
+
+    let lifetime = world.get::<Lifetime>(effect).unwrap();
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(3));
+}
+
+#[test]
+fn apply_spawns_missing() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .apply_snapshot(EffectSnapshot {
+            name: "Poison".to_string(),
+            stacks: 300,
+            remaining: Some(Duration::from_secs(2)),
+        });
+    world.flush();
+
+    let effects = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effects.len(), 1);
+    let effect = effects.iter().next().unwrap();
+
+    assert_eq!(world.get::<Name>(effect).unwrap().as_str(), "Poison");
+    assert_eq!(world.get::<EffectStacks>(effect), Some(&EffectStacks(255)));
+    assert_eq!(
+        world.get::<Lifetime>(effect).unwrap().timer.remaining(),
+        Duration::from_secs(2)
+    );
+}
+
+#[test]
+fn apply_without_remaining_keeps_lifetime() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let effect = apply(&mut world, target, "Aura", Lifetime::permanent());
+
+    world
+        .commands()
+        .entity(target)
+        .apply_snapshot(EffectSnapshot {
+            name: "Aura".to_string(),
+            stacks: 1,
+            remaining: None,
+        });
+    world.flush();
+
+    assert!(world.get::<Lifetime>(effect).unwrap().is_permanent());
+}
+
+#[test]
+fn round_trip() {
+    let mut server = World::new();
+    let server_target = server.spawn_empty().id();
+    apply(
+        &mut server,
+        server_target,
+        "Poison",
+        (EffectStacks(2), Lifetime::from_seconds(4.0)),
+    );
+    let snapshots = EffectSnapshot::from_target(&server, server_target);
+
+    let mut client = World::new();
+    let client_target = client.spawn_empty().id();
+    for snapshot in snapshots.clone() {
+        client
+            .commands()
+            .entity(client_target)
+            .apply_snapshot(snapshot);
+    }
+    client.flush();
+
+    assert_eq!(
+        EffectSnapshot::from_target(&client, client_target),
+        snapshots
+    );
+}
@@ -0,0 +1,146 @@
+//! Tests listing every active effect using [`report_effects`], and reflecting them using [`reflect_effects`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::Reflect;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect;
+
+#[test]
+fn grouped_by_target() {
+    let mut world = World::new();
+
+    let first = world.spawn_empty().id();
+    let second = world.spawn_empty().id();
+
+    for (target, name) in [(second, "B"), (first, "A1"), (first, "A2")] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new(name),
+            bundle: MyEffect,
+            ..Default::default()
+        });
+    }
+    world.flush();
+
+    let report: Vec<(Entity, String)> = report_effects(&world)
+        .into_iter()
+        .map(|summary| (summary.target, summary.name.unwrap().to_string()))
+        .collect();
+
+    assert_eq!(
+        report,
+        vec![
+            (first, "A1".to_string()),
+            (first, "A2".to_string()),
+            (second, "B".to_string())
+        ]
+    );
+
+    // Logging includes reflected component values, which shouldn't panic for unregistered components.
+    world.init_resource::<AppTypeRegistry>();
+    log_effects(&world);
+}
+
+#[test]
+fn missing_components() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+    let effect = world.spawn(Effecting(target)).id();
+
+    let report = report_effects(&world);
+    assert_eq!(
+        report,
+        vec![EffectSummary {
+            target,
+            effect,
+            name: None,
+            stacks: 1,
+            remaining: None,
+        }]
+    );
+}
+
+#[test]
+#[cfg(all(feature = "stacks", feature = "timers"))]
+fn stacks_and_remaining() {
+    use std::time::Duration;
+
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+    world.commands().entity(target).with_effect(EffectBundle {
+        bundle: (EffectStacks(3), Lifetime::from_seconds(2.0)),
+        ..Default::default()
+    });
+    world.flush();
+
+    let report = report_effects(&world);
+    assert_eq!(report[0].stacks, 3);
+    assert_eq!(report[0].remaining, Some(Duration::from_secs(2)));
+}
+
+#[test]
+fn empty() {
+    let world = World::new();
+    assert!(report_effects(&world).is_empty());
+    log_effects(&world);
+}
+
+/// A custom effect component, which isn't known by the crate.
+#[derive(Component, Reflect, Debug, PartialEq, Default, Clone)]
+#[reflect(Component)]
+struct Burning(u8);
+
+#[test]
+fn reflect_effects_of_target() {
+    let mut world = World::new();
+    world.init_resource::<AppTypeRegistry>();
+    world
+        .resource::<AppTypeRegistry>()
+        .write()
+        .register::<Burning>();
+
+    let target = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new(Burning(1)))
+        .with_effect(EffectBundle::new((Burning(2), MyEffect)));
+    world
+        .commands()
+        .entity(other)
+        .with_effect(EffectBundle::new(Burning(3)));
+    world.flush();
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let effects = reflect_effects(&world, &type_registry, target);
+
+    let burning: Vec<u8> = effects
+        .iter()
+        .map(|effect| {
+            // `MyEffect` isn't registered, so only `Burning` is reflected.
+            assert_eq!(effect.components.len(), 1);
+            effect.components[0].downcast_ref::<Burning>().unwrap().0
+        })
+        .collect();
+    assert_eq!(burning, [1, 2]);
+
+    assert_eq!(
+        effects
+            .iter()
+            .map(|effect| effect.effect)
+            .collect::<Vec<_>>(),
+        world
+            .get::<EffectedBy>(target)
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(reflect_effects(&world, &type_registry, other).len(), 1);
+}
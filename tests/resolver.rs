@@ -0,0 +1,89 @@
+//! Tests custom collision policies using [`EffectResolver`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Default, Clone)]
+struct Damage(f32);
+
+fn strongest_wins(world: &mut World, existing: Entity, incoming: Entity) -> KeepEffect {
+    let damage = |entity| world.get::<Damage>(entity).map_or(0.0, |damage| damage.0);
+
+    if damage(existing) > damage(incoming) {
+        KeepEffect::Old
+    } else {
+        KeepEffect::Incoming
+    }
+}
+
+/// Matches any existing effect, regardless of name.
+fn match_any(world: &World, target: Entity, _incoming: Entity) -> Option<Entity> {
+    world.get::<EffectedBy>(target)?.iter().next()
+}
+
+fn apply(
+    world: &mut World,
+    target: Entity,
+    name: &'static str,
+    damage: f32,
+    resolver: EffectResolver,
+) {
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new(name),
+        mode: Some(EffectMode::Insert),
+        resolver: Some(resolver),
+        ..EffectBundle::new(Damage(damage))
+    });
+    world.flush();
+}
+
+fn damages(world: &World, target: Entity) -> Vec<f32> {
+    world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .map(|effect| world.get::<Damage>(effect).unwrap().0)
+        .collect()
+}
+
+#[test]
+fn custom_resolve() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+    let resolver = EffectResolver::new(strongest_wins);
+
+    apply(&mut world, target, "Burn", 2.0, resolver);
+    apply(&mut world, target, "Burn", 1.0, resolver);
+    assert_eq!(damages(&world, target), vec![2.0]);
+
+    apply(&mut world, target, "Burn", 3.0, resolver);
+    assert_eq!(damages(&world, target), vec![3.0]);
+}
+
+#[test]
+fn custom_find() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+    let resolver = EffectResolver::new(strongest_wins).with_find(match_any);
+
+    apply(&mut world, target, "Burn", 2.0, resolver);
+    apply(&mut world, target, "Frost", 3.0, resolver);
+
+    let effects = world.get::<EffectedBy>(target).unwrap();
+    assert_eq!(effects.len(), 1);
+    assert_eq!(
+        world.get::<Name>(effects.collection()[0]),
+        Some(&Name::new("Frost"))
+    );
+}
+
+#[test]
+fn builtin_as_custom() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    // Using a built-in resolver explicitly behaves the same as the mode.
+    apply(&mut world, target, "Burn", 2.0, EffectResolver::INSERT);
+    apply(&mut world, target, "Burn", 1.0, EffectResolver::INSERT);
+    assert_eq!(damages(&world, target), vec![1.0]);
+}
@@ -0,0 +1,103 @@
+//! Tests moving effects between targets using [`RetargetEffectCommand`].
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn apply(
+    world: &mut World,
+    target: Entity,
+    mode: EffectMode,
+    bundle: impl Bundle + Default,
+) -> Entity {
+    world.commands().entity(target).with_effect(EffectBundle {
+        name: Name::new("Effect"),
+        mode: Some(mode),
+        bundle,
+        ..Default::default()
+    });
+    world.flush();
+
+    world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .last()
+        .unwrap()
+}
+
+#[test]
+fn retarget() {
+    let mut world = World::new();
+    let caster = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    let effect = apply(&mut world, target, EffectMode::Stack, MyEffect(0));
+
+    world.commands().retarget_effect(effect, caster);
+    world.flush();
+
+    assert!(world.get::<EffectedBy>(target).is_none());
+    assert_eq!(world.get::<Effecting>(effect), Some(&Effecting(caster)));
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(0)));
+}
+
+#[test]
+fn retarget_merge() {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<Magnitude>(merge_effect_magnitude);
+
+    let caster = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    apply(&mut world, caster, EffectMode::Merge, Magnitude::new(1.0));
+    let effect = apply(&mut world, target, EffectMode::Merge, Magnitude::new(2.0));
+
+    world.commands().retarget_effect(effect, caster);
+    world.flush();
+
+    let effected_by = world.get::<EffectedBy>(caster).unwrap();
+    assert_eq!(effected_by.iter().collect::<Vec<_>>(), vec![effect]);
+    assert_eq!(world.get::<Magnitude>(effect).unwrap().value, 3.0);
+}
+
+#[test]
+fn retarget_insert() {
+    let mut world = World::new();
+    let caster = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    apply(&mut world, caster, EffectMode::Insert, MyEffect(0));
+    let effect = apply(&mut world, target, EffectMode::Insert, MyEffect(1));
+
+    world.commands().retarget_effect(effect, caster);
+    world.flush();
+
+    let effected_by = world.get::<EffectedBy>(caster).unwrap();
+    assert_eq!(effected_by.iter().collect::<Vec<_>>(), vec![effect]);
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(1)));
+}
+
+#[test]
+fn retarget_updates_index() {
+    let mut app = App::new();
+    app.add_plugins(EffectIndexPlugin::<MyEffect>::new());
+    let world = app.world_mut();
+
+    let caster = world.spawn_empty().id();
+    let target = world.spawn_empty().id();
+
+    let effect = apply(world, target, EffectMode::Stack, MyEffect(0));
+
+    world.commands().retarget_effect(effect, caster);
+    world.flush();
+
+    assert!(world.get::<EffectIndex<MyEffect>>(target).is_none());
+    let index = world.get::<EffectIndex<MyEffect>>(caster).unwrap();
+    assert_eq!(index.iter().collect::<Vec<_>>(), vec![effect]);
+}
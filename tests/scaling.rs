@@ -0,0 +1,71 @@
+//! Tests scaling effects by their elapsed lifetime using [`LifetimeScaling`].
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use std::time::Duration;
+
+#[test]
+fn curves() {
+    let cases = [
+        (ScalingCurve::LinearDown, [1.0, 0.75, 0.5, 0.0]),
+        (ScalingCurve::LinearUp, [0.0, 0.25, 0.5, 1.0]),
+        (ScalingCurve::EaseIn, [0.0, 0.0625, 0.25, 1.0]),
+        (ScalingCurve::EaseOut, [0.0, 0.4375, 0.75, 1.0]),
+    ];
+
+    for (curve, expected) in cases {
+        let actual = [0.0, 0.25, 0.5, 1.0].map(|t| curve.evaluate(t));
+        assert_eq!(actual, expected, "{curve:?}");
+    }
+}
+
+#[test]
+fn clamped() {
+    assert_eq!(ScalingCurve::LinearUp.evaluate(-1.0), 0.0);
+    assert_eq!(ScalingCurve::LinearUp.evaluate(2.0), 1.0);
+}
+
+#[test]
+fn multiplier() {
+    let mut lifetime = Lifetime::from_seconds(4.0);
+    lifetime.timer.tick(Duration::from_secs(1));
+
+    assert_eq!(LifetimeScaling::default().multiplier(&lifetime), 0.75);
+    assert_eq!(
+        LifetimeScaling::new(ScalingCurve::LinearUp).multiplier(&lifetime),
+        0.25
+    );
+}
+
+#[test]
+fn scaled_by_stacks() {
+    let mut lifetime = Lifetime::from_seconds(4.0);
+    lifetime.timer.tick(Duration::from_secs(1));
+
+    assert_eq!(
+        scaled_by_stacks_and_lifetime(10.0, 3, &lifetime, None),
+        22.5
+    );
+
+    let scaling = LifetimeScaling::new(ScalingCurve::LinearUp);
+    assert_eq!(
+        scaled_by_stacks_and_lifetime(10.0, 3, &lifetime, Some(&scaling)),
+        7.5
+    );
+}
+
+#[test]
+fn scaled_by_independent_stacks() {
+    // Stacks that decay independently are separate effects, each with their own lifetime.
+    let lifetimes = [1, 2, 3].map(|elapsed| {
+        let mut lifetime = Lifetime::from_seconds(4.0);
+        lifetime.timer.tick(Duration::from_secs(elapsed));
+        lifetime
+    });
+
+    let total: f32 = lifetimes
+        .iter()
+        .map(|lifetime| scaled_by_stacks_and_lifetime(10.0, 1, lifetime, None))
+        .sum();
+    assert_eq!(total, 7.5 + 5.0 + 2.5);
+}
@@ -0,0 +1,47 @@
+//! Tests the ordering of systems using [`AlchemySet`].
+#![cfg(feature = "timers")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct SawFinished(bool);
+
+fn record_finished(lifetimes: Query<&Lifetime>, mut saw: ResMut<SawFinished>) {
+    saw.0 |= lifetimes.iter().any(|l| l.timer.is_finished());
+}
+
+#[test]
+fn observe_before_despawn() {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin)
+        .init_resource::<Time>()
+        .init_resource::<SawFinished>()
+        .add_systems(
+            PreUpdate,
+            record_finished
+                .after(AlchemySet::TickTimers)
+                .before(AlchemySet::DespawnExpired),
+        );
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle {
+            bundle: Lifetime::from_seconds(1.0),
+            ..Default::default()
+        });
+    app.world_mut().flush();
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(1.0));
+    app.update();
+
+    assert!(app.world().resource::<SawFinished>().0);
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
@@ -0,0 +1,66 @@
+//! Tests the behaviour of [`EffectMode::Select`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn apply_all(bundles: impl IntoIterator<Item = (f32, u8)>) -> World {
+    let mut world = World::new();
+
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register_replacement::<Magnitude>(keep_stronger_magnitude);
+
+    let target = world.spawn_empty().id();
+
+    for (value, id) in bundles {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Select),
+            bundle: (Magnitude::new(value), MyEffect(id)),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    world
+}
+
+#[test]
+fn keep_stronger() {
+    let mut world = apply_all([(0.5, 0), (0.2, 1)]);
+    assert_eq!(
+        world.query::<&MyEffect>().single(&world).unwrap(),
+        &MyEffect(0)
+    );
+
+    let mut world = apply_all([(0.2, 0), (0.5, 1)]);
+    assert_eq!(
+        world.query::<&MyEffect>().single(&world).unwrap(),
+        &MyEffect(1)
+    );
+}
+
+#[test]
+fn keep_incoming_without_replacement() {
+    let mut world = World::new();
+
+    let target = world.spawn_empty().id();
+
+    for id in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Select),
+            bundle: MyEffect(id),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    assert_eq!(
+        world.query::<&MyEffect>().single(&world).unwrap(),
+        &MyEffect(1)
+    );
+}
@@ -0,0 +1,93 @@
+//! Tests capturing target stats using [`Snapshot`].
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Clone)]
+struct Health(u32);
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect;
+
+fn init() -> App {
+    let mut app = App::new();
+    app.add_plugins(SnapshotPlugin::<Health>::new());
+    app
+}
+
+fn apply(world: &mut World, target: Entity, mode: EffectMode) {
+    world.commands().entity(target).with_effect(EffectBundle {
+        mode: Some(mode),
+        bundle: (MyEffect, Snapshot::<Health>::default()),
+        ..Default::default()
+    });
+    world.flush();
+}
+
+fn snapshot(world: &mut World) -> Option<Health> {
+    world
+        .query::<&Snapshot<Health>>()
+        .single(world)
+        .unwrap()
+        .get()
+        .cloned()
+}
+
+#[test]
+fn capture_on_apply() {
+    let mut app = init();
+    let world = app.world_mut();
+
+    let target = world.spawn(Health(10)).id();
+    apply(world, target, EffectMode::Stack);
+
+    // Changes after applying don't effect the snapshot.
+    world.get_mut::<Health>(target).unwrap().0 = 5;
+
+    assert_eq!(snapshot(world), Some(Health(10)));
+}
+
+#[test]
+fn recapture_on_reapply() {
+    let mut app = init();
+    let world = app.world_mut();
+
+    let target = world.spawn(Health(10)).id();
+    apply(world, target, EffectMode::Insert);
+
+    world.get_mut::<Health>(target).unwrap().0 = 5;
+    apply(world, target, EffectMode::Insert);
+
+    assert_eq!(snapshot(world), Some(Health(5)));
+}
+
+#[test]
+fn capture_on_bind() {
+    let mut app = init();
+    let world = app.world_mut();
+
+    let target = world.spawn(Health(10)).id();
+    let effect = world
+        .commands()
+        .spawn_effect(EffectBundle {
+            bundle: (MyEffect, Snapshot::<Health>::default()),
+            ..Default::default()
+        })
+        .id();
+    world.commands().entity(target).bind_effect(effect);
+    world.flush();
+
+    assert_eq!(snapshot(world), Some(Health(10)));
+}
+
+#[test]
+fn missing_stat() {
+    let mut app = init();
+    let world = app.world_mut();
+
+    let target = world.spawn_empty().id();
+    apply(world, target, EffectMode::Stack);
+
+    assert_eq!(snapshot(world), None);
+}
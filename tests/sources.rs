@@ -0,0 +1,100 @@
+//! Tests keeping effects alive using [`EffectSources`], and releasing them with [`release_effect`](EffectCommandsExt::release_effect).
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct Regen;
+
+fn init_world() -> (World, Entity) {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectSources>(merge_effect_sources);
+
+    let target = world.spawn_empty().id();
+    (world, target)
+}
+
+fn apply(world: &mut World, target: Entity, source: Entity) {
+    world.commands().entity(target).with_effect(EffectBundle {
+        mode: Some(EffectMode::Merge),
+        bundle: (Regen, EffectSources::new(source)),
+        ..Default::default()
+    });
+    world.flush();
+}
+
+fn release(world: &mut World, target: Entity, source: Entity) {
+    world.commands().entity(target).release_effect(source);
+    world.flush();
+}
+
+fn sources(world: &mut World) -> Option<Vec<Entity>> {
+    world
+        .query_filtered::<&EffectSources, With<Regen>>()
+        .single(world)
+        .ok()
+        .map(|sources| sources.0.clone())
+}
+
+#[test]
+fn merge_unique_sources() {
+    let (mut world, target) = init_world();
+    let totems = [(); 2].map(|_| world.spawn_empty().id());
+
+    apply(&mut world, target, totems[0]);
+    apply(&mut world, target, totems[1]);
+    apply(&mut world, target, totems[0]);
+
+    let mut sources = sources(&mut world).unwrap();
+    sources.sort();
+    let mut expected = totems.to_vec();
+    expected.sort();
+    assert_eq!(sources, expected);
+}
+
+#[test]
+fn despawn_after_last_release() {
+    let (mut world, target) = init_world();
+    let totems = [(); 3].map(|_| world.spawn_empty().id());
+
+    for totem in totems {
+        apply(&mut world, target, totem);
+    }
+
+    release(&mut world, target, totems[0]);
+    release(&mut world, target, totems[2]);
+    assert_eq!(sources(&mut world), Some(vec![totems[1]]));
+
+    release(&mut world, target, totems[1]);
+    assert_eq!(sources(&mut world), None);
+    assert!(world.get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+fn release_unknown_source() {
+    let (mut world, target) = init_world();
+    let totem = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+
+    apply(&mut world, target, totem);
+    release(&mut world, target, other);
+
+    assert_eq!(sources(&mut world), Some(vec![totem]));
+}
+
+#[test]
+fn release_ignores_effects_without_sources() {
+    let (mut world, target) = init_world();
+    let totem = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new(Regen));
+    world.flush();
+
+    release(&mut world, target, totem);
+    assert_eq!(world.get::<EffectedBy>(target).unwrap().len(), 1);
+}
@@ -44,12 +44,12 @@ fn spawnable_list_insert() {
         Name::new("Target"),
         EffectedBy::spawn((
             EffectBundle {
-                mode: EffectMode::Insert,
+                mode: Some(EffectMode::Insert),
                 bundle: MyEffect(0),
                 ..Default::default()
             },
             EffectBundle {
-                mode: EffectMode::Insert,
+                mode: Some(EffectMode::Insert),
                 bundle: MyEffect(1),
                 ..Default::default()
             },
@@ -84,12 +84,12 @@ fn spawnable_list_mixed() {
                 ..Default::default()
             },
             EffectBundle {
-                mode: EffectMode::Insert,
+                mode: Some(EffectMode::Insert),
                 bundle: MyEffect(2),
                 ..Default::default()
             },
             EffectBundle {
-                mode: EffectMode::Insert,
+                mode: Some(EffectMode::Insert),
                 bundle: MyEffect(3),
                 ..Default::default()
             },
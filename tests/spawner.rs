@@ -0,0 +1,75 @@
+//! Tests the entities returned by [`EffectSpawner::spawn`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default)]
+struct MyEffect(u8);
+
+#[test]
+fn returns_spawned_entities() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let mut entities = Vec::new();
+
+    world.commands().entity(target).with_effects(|effects| {
+        for i in 0..3 {
+            entities.push(effects.spawn(EffectBundle {
+                bundle: MyEffect(i),
+                ..Default::default()
+            }));
+        }
+    });
+    world.flush();
+
+    let effected_by: Vec<Entity> = world.get::<EffectedBy>(target).unwrap().iter().collect();
+    assert_eq!(effected_by, entities);
+
+    for (i, entity) in entities.iter().enumerate() {
+        assert_eq!(world.get::<MyEffect>(*entity), Some(&MyEffect(i as u8)));
+    }
+}
+
+#[test]
+fn despawn_unused_entities() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    let mut entities = Vec::new();
+
+    world.commands().entity(target).with_effects(|effects| {
+        for i in 0..2 {
+            entities.push(effects.spawn(EffectBundle {
+                mode: Some(EffectMode::Insert),
+                bundle: MyEffect(i),
+                ..Default::default()
+            }));
+        }
+    });
+    world.flush();
+
+    // The second effect was inserted onto the first, so its entity wasn't needed.
+    assert_eq!(world.get::<MyEffect>(entities[0]), Some(&MyEffect(1)));
+    assert!(world.get_entity(entities[1]).is_err());
+}
+
+#[test]
+fn despawn_blocked_entities() {
+    let mut world = World::new();
+    let target = world
+        .spawn(EffectImmunity::default().with(EffectFilter::name("MyEffect")))
+        .id();
+
+    let mut entity = None;
+
+    world.commands().entity(target).with_effects(|effects| {
+        entity = Some(effects.spawn(EffectBundle {
+            bundle: MyEffect(0),
+            ..Default::default()
+        }));
+    });
+    world.flush();
+
+    assert!(world.get_entity(entity.unwrap()).is_err());
+}
@@ -0,0 +1,148 @@
+//! Tests the behaviour of limiting individual effects using [`StackLimits`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn apply_many(world: &mut World, target: Entity, name: &'static str, mode: EffectMode) {
+    for i in 0..4 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            name: Name::new(name),
+            mode: Some(mode),
+            bundle: MyEffect(i),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+}
+
+fn effect_values(world: &World, target: Entity, name: &str) -> Vec<u8> {
+    world
+        .get::<EffectedBy>(target)
+        .unwrap()
+        .iter()
+        .filter(|effect| world.get::<Name>(*effect).unwrap().as_str() == name)
+        .map(|effect| world.get::<MyEffect>(effect).unwrap().0)
+        .collect()
+}
+
+#[test]
+fn stack_reject() {
+    let mut world = World::new();
+    let target = world
+        .spawn(StackLimits::default().with_limit("Poison", 2))
+        .id();
+
+    apply_many(&mut world, target, "Poison", EffectMode::Stack);
+    apply_many(&mut world, target, "Regen", EffectMode::Stack);
+
+    assert_eq!(effect_values(&world, target, "Poison"), vec![0, 1]);
+    assert_eq!(effect_values(&world, target, "Regen"), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn stack_cap() {
+    let mut world = World::new();
+    let target = world
+        .spawn(
+            StackLimits::default()
+                .with_limit("Poison", 2)
+                .with_policy(StackLimitPolicy::Cap),
+        )
+        .id();
+
+    apply_many(&mut world, target, "Poison", EffectMode::Stack);
+
+    assert_eq!(effect_values(&world, target, "Poison"), vec![2, 3]);
+}
+
+#[test]
+fn stack_zero() {
+    let mut world = World::new();
+    let target = world
+        .spawn(
+            StackLimits::default()
+                .with_limit("Poison", 0)
+                .with_policy(StackLimitPolicy::Cap),
+        )
+        .id();
+
+    apply_many(&mut world, target, "Poison", EffectMode::Stack);
+
+    assert!(world.get::<EffectedBy>(target).is_none());
+}
+
+#[test]
+#[cfg(feature = "stacks")]
+fn merge_reject() {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+    let target = world
+        .spawn(StackLimits::default().with_limit("Poison", 2))
+        .id();
+
+    apply_many(&mut world, target, "Poison", EffectMode::Merge);
+
+    let effect = world.get::<EffectedBy>(target).unwrap().collection()[0];
+    assert_eq!(world.get::<EffectStacks>(effect), Some(&EffectStacks(2)));
+    // The rejected applications don't overwrite the effect.
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(1)));
+}
+
+#[test]
+#[cfg(feature = "stacks")]
+fn merge_cap() {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+    let target = world
+        .spawn(
+            StackLimits::default()
+                .with_limit("Poison", 2)
+                .with_policy(StackLimitPolicy::Cap),
+        )
+        .id();
+
+    apply_many(&mut world, target, "Poison", EffectMode::Merge);
+
+    let effect = world.get::<EffectedBy>(target).unwrap().collection()[0];
+    assert_eq!(world.get::<EffectStacks>(effect), Some(&EffectStacks(2)));
+    // Capped applications are still merged.
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(3)));
+}
+
+#[test]
+fn batch_past_limit() {
+    let mut world = World::new();
+    let reject = world
+        .spawn(StackLimits::default().with_limit("Poison", 2))
+        .id();
+    let cap = world
+        .spawn(
+            StackLimits::default()
+                .with_limit("Poison", 2)
+                .with_policy(StackLimitPolicy::Cap),
+        )
+        .id();
+
+    for target in [reject, cap] {
+        world
+            .commands()
+            .entity(target)
+            .with_stacked_effects((0..4).map(|i| EffectBundle {
+                name: Name::new("Poison"),
+                bundle: MyEffect(i),
+                ..Default::default()
+            }));
+    }
+    world.flush();
+
+    assert_eq!(effect_values(&world, reject, "Poison"), vec![0, 1]);
+    assert_eq!(effect_values(&world, cap, "Poison"), vec![2, 3]);
+}
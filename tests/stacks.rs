@@ -0,0 +1,327 @@
+//! Tests the behaviour of merging [`EffectStacks`].
+#![cfg(feature = "stacks")]
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Resource, Default)]
+struct Changes(Vec<(u8, u8)>);
+
+#[derive(Resource, Default)]
+struct WideChanges(Vec<(u32, u32)>);
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+fn merge_three_times(world: &mut World) {
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    let target = world.spawn_empty().id();
+
+    for _ in 0..3 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+}
+
+#[test]
+fn track_stacks_automatically() {
+    let mut world = World::new();
+    merge_three_times(&mut world);
+
+    assert_eq!(
+        world.query::<&EffectStacks>().single(&world).unwrap(),
+        &EffectStacks(3)
+    );
+}
+
+#[test]
+fn track_stacks_disabled() {
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        track_merge_stacks: false,
+        ..Default::default()
+    });
+    merge_three_times(&mut world);
+
+    assert!(world.query::<&EffectStacks>().iter(&world).next().is_none());
+}
+
+#[test]
+fn stacks_changed() {
+    let mut world = World::new();
+    world.init_resource::<Changes>();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    world.add_observer(|event: On<StacksChanged>, mut changes: ResMut<Changes>| {
+        changes.0.push((event.old, event.new));
+    });
+
+    let target = world.spawn_empty().id();
+
+    for _ in 0..3 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: EffectStacks::default(),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    assert_eq!(
+        world.query::<&EffectStacks>().single(&world).unwrap(),
+        &EffectStacks(3)
+    );
+    assert_eq!(world.resource::<Changes>().0, vec![(1, 2), (2, 3)]);
+}
+
+#[test]
+fn saturating_add() {
+    assert_eq!(EffectStacks(250) + EffectStacks(10), EffectStacks(u8::MAX));
+    assert_eq!(EffectStacks(u8::MAX) + 1, EffectStacks(u8::MAX));
+
+    let mut stacks = EffectStacks(u8::MAX);
+    stacks += 1;
+    stacks += EffectStacks(1);
+    assert_eq!(stacks, EffectStacks(u8::MAX));
+}
+
+#[test]
+fn saturating_sub() {
+    assert_eq!(EffectStacks(2) - EffectStacks(3), EffectStacks(0));
+    assert_eq!(EffectStacksU32(5) - 2, EffectStacksU32(3));
+}
+
+#[test]
+fn saturating_mul() {
+    assert_eq!(EffectStacks(100) * EffectStacks(3), EffectStacks(u8::MAX));
+    assert_eq!(EffectStacksU32(u32::MAX) * 2, EffectStacksU32(u32::MAX));
+}
+
+#[test]
+fn merge_at_max_stacks() {
+    let mut world = World::new();
+    world.init_resource::<Changes>();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    world.add_observer(|event: On<StacksChanged>, mut changes: ResMut<Changes>| {
+        changes.0.push((event.old, event.new));
+    });
+
+    let target = world.spawn_empty().id();
+
+    for stacks in [u8::MAX, 1] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: EffectStacks(stacks),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    assert_eq!(
+        world.query::<&EffectStacks>().single(&world).unwrap(),
+        &EffectStacks(u8::MAX)
+    );
+    assert!(world.resource::<Changes>().0.is_empty());
+}
+
+#[test]
+fn merge_u32_stacks() {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacksU32>(merge_effect_stacks_u32);
+
+    world.init_resource::<WideChanges>();
+    world.add_observer(
+        |event: On<StacksChanged<u32>>, mut changes: ResMut<WideChanges>| {
+            changes.0.push((event.old, event.new));
+        },
+    );
+
+    let target = world.spawn_empty().id();
+
+    for stacks in [1000, 500] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: EffectStacksU32(stacks),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    assert_eq!(
+        world.query::<&EffectStacksU32>().single(&world).unwrap(),
+        &EffectStacksU32(1500)
+    );
+    assert_eq!(world.resource::<WideChanges>().0, vec![(1000, 1500)]);
+}
+
+/// Applies a merge effect with an initial stack count, then reapplies it with and without one.
+fn apply_initial_stacks(resolver: Option<EffectResolver>) -> Vec<EffectStacks> {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    let target = world.spawn_empty().id();
+    let mut history = Vec::new();
+
+    for stacks in [Some(EffectStacks(3)), Some(EffectStacks(3)), None] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            resolver,
+            stacks,
+            bundle: MyEffect(0),
+            ..Default::default()
+        });
+
+        world.flush();
+        history.push(*world.query::<&EffectStacks>().single(&world).unwrap());
+    }
+
+    history
+}
+
+#[test]
+fn initial_stacks() {
+    assert_eq!(
+        apply_initial_stacks(None),
+        vec![EffectStacks(3), EffectStacks(6), EffectStacks(7)]
+    );
+}
+
+#[test]
+fn initial_stacks_with_resolver() {
+    assert_eq!(
+        apply_initial_stacks(Some(EffectResolver::MERGE)),
+        vec![EffectStacks(3), EffectStacks(6), EffectStacks(7)]
+    );
+}
+
+#[test]
+fn initial_stacks_without_tracking() {
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        track_merge_stacks: false,
+        ..Default::default()
+    });
+
+    let target = world.spawn_empty().id();
+    world.commands().entity(target).with_effect(EffectBundle {
+        mode: Some(EffectMode::Merge),
+        stacks: Some(EffectStacks(3)),
+        bundle: MyEffect(0),
+        ..Default::default()
+    });
+    world.flush();
+
+    assert_eq!(
+        world.query::<&EffectStacks>().single(&world).unwrap(),
+        &EffectStacks(3)
+    );
+}
+
+/// Merges an effect with 3 stacks with an effect with 2 stacks, using the given mode.
+fn merge_with_mode(mode: StackMergeMode) -> EffectStacks {
+    let mut world = World::new();
+    world
+        .get_resource_or_init::<EffectMergeRegistry>()
+        .register::<EffectStacks>(merge_effect_stacks);
+
+    let target = world.spawn_empty().id();
+
+    for stacks in [3, 2] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            bundle: (MyEffect(0), EffectStacks(stacks), mode),
+            ..Default::default()
+        });
+    }
+
+    world.flush();
+
+    *world.query::<&EffectStacks>().single(&world).unwrap()
+}
+
+#[test]
+fn merge_mode_sum() {
+    assert_eq!(merge_with_mode(StackMergeMode::Sum), EffectStacks(5));
+}
+
+#[test]
+fn merge_mode_max() {
+    assert_eq!(merge_with_mode(StackMergeMode::Max), EffectStacks(3));
+}
+
+#[test]
+fn merge_mode_replace() {
+    assert_eq!(merge_with_mode(StackMergeMode::Replace), EffectStacks(2));
+}
+
+#[test]
+fn merge_mode_keep() {
+    assert_eq!(merge_with_mode(StackMergeMode::Keep), EffectStacks(3));
+}
+
+#[test]
+fn merge_mode_product() {
+    assert_eq!(merge_with_mode(StackMergeMode::Product), EffectStacks(6));
+}
+
+#[test]
+fn merge_with_alchemy_plugin() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(AlchemyPlugin);
+
+    let target = app.world_mut().spawn_empty().id();
+    for _ in 0..2 {
+        app.world_mut()
+            .commands()
+            .entity(target)
+            .with_effect(EffectBundle {
+                name: "Poison".into(),
+                mode: Some(EffectMode::Merge),
+                bundle: (MyEffect(0), EffectStacks(1)),
+                ..Default::default()
+            });
+    }
+    app.world_mut().flush();
+
+    let world = app.world_mut();
+    assert_eq!(
+        world.query::<&EffectStacks>().single(world).unwrap(),
+        &EffectStacks(2)
+    );
+}
+
+#[test]
+fn register_stack_types() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(AlchemyPlugin);
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    for id in [
+        std::any::TypeId::of::<EffectStacks>(),
+        std::any::TypeId::of::<EffectStacksU32>(),
+        std::any::TypeId::of::<StackMergeMode>(),
+        std::any::TypeId::of::<StackGainModifier>(),
+    ] {
+        assert!(registry.contains(id));
+    }
+}
@@ -0,0 +1,44 @@
+//! Tests the behaviour of applying effects to multiple targets at once.
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+#[test]
+fn apply_to_many() {
+    let mut world = World::new();
+
+    let targets: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+    let untouched = world.spawn_empty().id();
+
+    world.commands().apply_effect_to(
+        targets.clone(),
+        EffectBundle {
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(0),
+            ..Default::default()
+        },
+    );
+    world.commands().apply_effect_to(
+        targets.clone(),
+        EffectBundle {
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(1),
+            ..Default::default()
+        },
+    );
+
+    world.flush();
+
+    for target in targets {
+        let effected_by = world.get::<EffectedBy>(target).unwrap();
+        assert_eq!(effected_by.len(), 1);
+
+        let effect = effected_by.collection()[0];
+        assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(1)));
+    }
+
+    assert!(world.get::<EffectedBy>(untouched).is_none());
+}
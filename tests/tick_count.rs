@@ -0,0 +1,66 @@
+//! Tests despawning effects after a fixed number of [`Delay`] ticks, using [`TickCount`].
+#![cfg(feature = "test_utils")]
+
+use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Hits(u32);
+
+fn count_hits(query: Query<&Delay>, mut hits: ResMut<Hits>) {
+    for delay in &query {
+        hits.0 += delay.ticks_this_frame();
+    }
+}
+
+fn init_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((AlchemyPlugin, TestTimePlugin))
+        .init_resource::<Hits>()
+        .add_systems(Update, count_hits);
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(target)
+        .with_effect(EffectBundle::new((
+            Delay::from_seconds(1.0),
+            TickCount::new(3),
+        )));
+    app.world_mut().flush();
+
+    (app, target)
+}
+
+#[test]
+fn despawn_after_ticks() {
+    let (mut app, target) = init_app();
+
+    for _ in 0..3 {
+        app.advance_time(Duration::from_secs(1));
+        assert!(app.world().get::<EffectedBy>(target).is_some());
+    }
+    assert_eq!(app.world().resource::<Hits>().0, 3);
+
+    app.advance_time(Duration::from_secs(1));
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+    assert_eq!(app.world().resource::<Hits>().0, 3);
+}
+
+#[test]
+fn multiple_ticks_per_frame() {
+    let (mut app, target) = init_app();
+
+    app.advance_time(Duration::from_secs(5));
+    assert!(app.world().get::<EffectedBy>(target).is_some());
+
+    let effect = app.world().get::<EffectedBy>(target).unwrap().collection()[0];
+    assert_eq!(app.world().get::<TickCount>(effect).unwrap().remaining, 0);
+    // The delay doesn't trigger more times than it has ticks remaining.
+    assert_eq!(app.world().resource::<Hits>().0, 3);
+
+    app.advance_time(Duration::ZERO);
+    assert!(app.world().get::<EffectedBy>(target).is_none());
+}
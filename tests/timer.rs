@@ -1,6 +1,11 @@
-//! Tests the behaviour of timers for each [`TimerMergeMode`].
+//! Tests the behaviour of effect timers, including each [`TimerMergeMode`].
+#![cfg(feature = "timers")]
 
 use bevy_alchemy::*;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypePath;
+use bevy_time::TimerMode;
 use std::time::Duration;
 
 #[test]
@@ -57,3 +62,283 @@ fn merge_sum() {
 
     assert_eq!(result.timer.duration(), Duration::from_secs_f32(6.0));
 }
+
+#[test]
+fn merge_sum_permanent() {
+    let first = Lifetime::permanent().with_mode(TimerMergeMode::Sum);
+    let second = Lifetime::from_seconds(2.0).with_mode(TimerMergeMode::Sum);
+    let mut result = second.clone();
+    result.merge(&first);
+
+    assert!(result.is_permanent());
+}
+
+#[test]
+fn delay_immediate() {
+    let delay = Delay::from_seconds_immediate(1.0);
+    assert!(delay.timer.is_finished());
+    assert!(delay.timer.just_finished());
+
+    let mut fraction = Delay::from_seconds_immediate(1.0);
+    fraction.merge(&Delay::from_seconds(1.0));
+    assert!(fraction.timer.is_finished());
+
+    let mut keep = Delay::from_seconds_immediate(1.0).with_mode(TimerMergeMode::Keep);
+    keep.merge(&Delay::from_seconds(1.0));
+    assert!(!keep.timer.is_finished());
+}
+
+#[test]
+fn delay_ticks_this_frame() {
+    assert_eq!(Delay::from_seconds_immediate(1.0).ticks_this_frame(), 1);
+
+    let mut delay = Delay::from_seconds(1.0);
+    delay.timer.tick(Duration::from_millis(500));
+    assert_eq!(delay.ticks_this_frame(), 0);
+
+    // A long frame triggers the delay multiple times.
+    delay.timer.tick(Duration::from_millis(2600));
+    assert_eq!(delay.ticks_this_frame(), 3);
+}
+
+#[test]
+fn lifetime_varied() {
+    let min = Duration::from_secs_f32(3.5);
+    let max = Duration::from_secs_f32(4.5);
+
+    let durations: Vec<Duration> = (0..20)
+        .map(|_| Lifetime::from_seconds_varied(4.0, 0.5).timer.duration())
+        .collect();
+
+    assert!(durations.iter().all(|d| (min..=max).contains(d)));
+    assert!(durations.iter().any(|d| *d != durations[0]));
+}
+
+#[test]
+fn lifetime_varied_seeded() {
+    let a = Lifetime::from_seconds_varied_seeded(4.0, 0.5, 7);
+    let b = Lifetime::from_seconds_varied_seeded(4.0, 0.5, 7);
+    assert_eq!(a.timer.duration(), b.timer.duration());
+
+    // Durations can't be negative.
+    let short = Lifetime::from_seconds_varied_seeded(0.1, 1.0, 7);
+    assert!(short.timer.duration() <= Duration::from_secs_f32(1.1));
+
+    // Without variance, the duration is exact.
+    let exact = Lifetime::from_seconds_varied_seeded(4.0, 0.0, 7);
+    assert_eq!(exact.timer.duration(), Duration::from_secs(4));
+}
+
+#[test]
+fn delay_offset() {
+    let delay = Delay::from_seconds_offset(1.0, 0.25);
+    assert_eq!(delay.timer.elapsed(), Duration::from_millis(250));
+
+    // Offsets longer than the duration wrap around.
+    let delay = Delay::from_seconds_offset(1.0, 2.5);
+    assert_eq!(delay.timer.elapsed(), Duration::from_millis(500));
+
+    let mut replace = Delay::from_seconds_offset(1.0, 0.25).with_mode(TimerMergeMode::Replace);
+    replace.merge(&Delay::from_seconds(1.0));
+    assert_eq!(replace.timer.elapsed(), Duration::from_millis(250));
+
+    let mut keep = Delay::from_seconds_offset(1.0, 0.25).with_mode(TimerMergeMode::Keep);
+    keep.merge(&Delay::from_seconds(1.0));
+    assert_eq!(keep.timer.elapsed(), Duration::ZERO);
+}
+
+#[test]
+fn merge_extend() {
+    let mut first = Lifetime::from_seconds(3.0).with_mode(TimerMergeMode::Extend);
+    first.timer.tick(Duration::from_secs(1));
+
+    let mut result = Lifetime::from_seconds(2.0).with_mode(TimerMergeMode::Extend);
+    result.merge(&first);
+
+    assert_eq!(result.timer.elapsed(), Duration::from_secs(1));
+    assert_eq!(result.timer.duration(), Duration::from_secs(5));
+    assert_eq!(result.timer.remaining(), Duration::from_secs(4));
+    assert_eq!(result.mode, TimerMergeMode::Extend);
+}
+
+#[test]
+fn convert_delay_to_lifetime() {
+    let mut delay = Delay::from_seconds(2.0)
+        .with_mode(TimerMergeMode::Sum)
+        .with_jitter(Duration::from_secs(1)..=Duration::from_secs(3));
+    delay.timer.tick(Duration::from_millis(500));
+
+    let lifetime = Lifetime::from(delay);
+
+    assert_eq!(lifetime.timer.mode(), TimerMode::Once);
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(2));
+    assert_eq!(lifetime.timer.elapsed(), Duration::from_millis(500));
+    assert_eq!(lifetime.mode, TimerMergeMode::Sum);
+}
+
+#[test]
+fn convert_lifetime_to_delay() {
+    let mut lifetime = Lifetime::from_seconds(1.0);
+    lifetime.timer.tick(Duration::from_millis(250));
+    lifetime.timer.pause();
+
+    let delay = Delay::from(lifetime);
+
+    assert_eq!(delay.timer.mode(), TimerMode::Repeating);
+    assert_eq!(delay.timer.elapsed(), Duration::from_millis(250));
+    assert!(delay.timer.is_paused());
+    assert_eq!(delay.mode, TimerMergeMode::Max);
+    assert_eq!(delay.jitter, None);
+
+    // A finished lifetime restarts as a delay.
+    let mut lifetime = Lifetime::from_seconds(1.0);
+    lifetime.timer.tick(Duration::from_secs(2));
+
+    let delay = Delay::from(lifetime);
+    assert_eq!(delay.timer.elapsed(), Duration::ZERO);
+}
+
+/// The plugin should register the same timer types that are exported, with no duplicates under another path.
+#[test]
+fn single_timer_types() {
+    let mut app = App::new();
+    app.add_plugins(AlchemyPlugin);
+
+    let merges = app.world().resource::<EffectMergeRegistry>();
+    assert!(merges.contains::<Lifetime>());
+    assert!(merges.contains::<Delay>());
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    for (ident, path) in [
+        ("Lifetime", Lifetime::type_path()),
+        ("Delay", Delay::type_path()),
+    ] {
+        let paths: Vec<&str> = registry
+            .iter()
+            .map(|registration| registration.type_info().type_path_table())
+            .filter(|table| table.ident() == Some(ident))
+            .map(|table| table.path())
+            .collect();
+
+        assert_eq!(paths, vec![path]);
+    }
+}
+
+fn elapsed_lifetime(seconds: f32, elapsed: f32) -> Lifetime {
+    let mut lifetime = Lifetime::from_seconds(seconds);
+    lifetime.timer.set_elapsed(Duration::from_secs_f32(elapsed));
+    lifetime
+}
+
+#[test]
+fn extend_lifetime() {
+    let mut lifetime = elapsed_lifetime(5.0, 3.0);
+    lifetime.extend(Duration::from_secs(2), None);
+
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(7));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(4));
+}
+
+#[test]
+fn extend_lifetime_up_to_max() {
+    let mut lifetime = elapsed_lifetime(5.0, 3.0);
+    lifetime.extend(Duration::from_secs(10), Some(Duration::from_secs(5)));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(5));
+
+    // Lifetimes with more time remaining than the max aren't reduced.
+    let mut lifetime = elapsed_lifetime(10.0, 0.0);
+    lifetime.extend(Duration::from_secs(2), Some(Duration::from_secs(5)));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(10));
+}
+
+#[test]
+fn extend_permanent_lifetime() {
+    let mut lifetime = Lifetime::permanent();
+    lifetime.extend(Duration::from_secs(2), None);
+    assert!(lifetime.is_permanent());
+}
+
+#[test]
+fn extend_lifetime_command() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world.commands().entity(target).with_effects(|effects| {
+        effects.spawn(EffectBundle {
+            name: Name::new("Rage"),
+            bundle: Lifetime::from_seconds(5.0),
+            ..Default::default()
+        });
+        effects.spawn(EffectBundle {
+            name: Name::new("Other"),
+            bundle: Lifetime::from_seconds(5.0),
+            ..Default::default()
+        });
+    });
+    world
+        .commands()
+        .entity(target)
+        .extend_lifetime("Rage", Duration::from_secs(2), None);
+    world.flush();
+
+    let durations: Vec<(&Name, Duration)> = world
+        .query::<(&Name, &Lifetime)>()
+        .iter(&world)
+        .map(|(name, lifetime)| (name, lifetime.timer.duration()))
+        .collect();
+    assert_eq!(
+        durations,
+        vec![
+            (&Name::new("Rage"), Duration::from_secs(7)),
+            (&Name::new("Other"), Duration::from_secs(5)),
+        ]
+    );
+}
+
+#[test]
+fn predict_lifetime() {
+    let mut lifetime = Lifetime::from_seconds(5.0);
+    lifetime.predict(Duration::from_secs(2));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(3));
+
+    lifetime.predict(Duration::from_secs(4));
+    assert!(lifetime.timer.is_finished());
+}
+
+#[test]
+fn reconcile_lifetime() {
+    let mut lifetime = elapsed_lifetime(5.0, 3.0);
+    lifetime.reconcile(Duration::from_secs(3));
+
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(5));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(3));
+
+    // Durations are increased to fit the remaining time.
+    lifetime.reconcile(Duration::from_secs(8));
+    assert_eq!(lifetime.timer.duration(), Duration::from_secs(8));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(8));
+}
+
+#[test]
+fn reconcile_finished_lifetime() {
+    let mut lifetime = Lifetime::from_seconds(5.0);
+    lifetime.predict(Duration::from_secs(6));
+    assert!(lifetime.timer.is_finished());
+
+    // A client may predict past the end before the server's update arrives.
+    lifetime.reconcile(Duration::from_secs(1));
+    assert!(!lifetime.timer.is_finished());
+
+    lifetime.predict(Duration::from_millis(500));
+    assert_eq!(lifetime.timer.remaining(), Duration::from_millis(500));
+    assert!(!lifetime.timer.is_finished());
+}
+
+#[test]
+fn reconcile_permanent_lifetime() {
+    let mut lifetime = Lifetime::permanent();
+    lifetime.reconcile(Duration::from_secs(2));
+
+    assert!(!lifetime.is_permanent());
+    assert_eq!(lifetime.timer.remaining(), Duration::from_secs(2));
+}
@@ -0,0 +1,128 @@
+//! Tests validating misconfigured effects, using [`AlchemySettings::validate_effects`] and [`AlchemySettings::strict`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect;
+
+/// Merges a [`Magnitude`] that has no registered merge function, which is overwritten instead.
+fn merge_unregistered(world: &mut World) {
+    world.init_resource::<EffectMergeRegistry>();
+    let target = world.spawn_empty().id();
+
+    for value in [1.0, 2.0] {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            ..EffectBundle::new((MyEffect, Magnitude::new(value)))
+        });
+    }
+    world.flush();
+
+    assert_eq!(
+        world.query::<&Magnitude>().single(world).unwrap(),
+        &Magnitude::new(2.0)
+    );
+}
+
+#[test]
+fn enabled_by_default() {
+    assert!(AlchemySettings::default().validate_effects);
+}
+
+#[test]
+fn validation_doesnt_change_behaviour() {
+    let mut world = World::new();
+    merge_unregistered(&mut world);
+
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        validate_effects: false,
+        ..Default::default()
+    });
+    merge_unregistered(&mut world);
+}
+
+#[test]
+fn strict_disabled_by_default() {
+    assert!(!AlchemySettings::default().strict);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "Magnitude"))]
+fn strict_missing_merge_function() {
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        strict: true,
+        ..Default::default()
+    });
+    merge_unregistered(&mut world);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "EffectMergeRegistry"))]
+fn strict_missing_registry() {
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        strict: true,
+        ..Default::default()
+    });
+    let target = world.spawn_empty().id();
+
+    for _ in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            ..EffectBundle::new(MyEffect)
+        });
+    }
+    world.flush();
+}
+
+#[test]
+fn strict_without_validation() {
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        validate_effects: false,
+        strict: true,
+        ..Default::default()
+    });
+    merge_unregistered(&mut world);
+}
+
+#[test]
+#[cfg(feature = "timers")]
+#[cfg_attr(debug_assertions, should_panic(expected = "TickCount"))]
+fn strict_batched() {
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        strict: true,
+        ..Default::default()
+    });
+    let target = world.spawn_empty().id();
+
+    world
+        .commands()
+        .entity(target)
+        .with_stacked_effects((0..2).map(|_| EffectBundle::new((MyEffect, TickCount::new(3)))));
+    world.flush();
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "EffectMergeRegistry"))]
+fn strict_missing_registry_when_binding() {
+    let mut world = World::new();
+    world.insert_resource(AlchemySettings {
+        strict: true,
+        ..Default::default()
+    });
+    let target = world.spawn_empty().id();
+
+    for _ in 0..2 {
+        world.commands().entity(target).with_effect(EffectBundle {
+            mode: Some(EffectMode::Merge),
+            resolver: Some(EffectResolver::MERGE),
+            ..EffectBundle::new(MyEffect)
+        });
+    }
+    world.flush();
+}
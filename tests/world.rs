@@ -0,0 +1,86 @@
+//! Tests applying effects immediately using [`EffectWorldExt`] and [`AlchemyWorldExt`].
+
+use bevy_alchemy::*;
+use bevy_ecs::prelude::*;
+
+#[derive(Component, Debug, Eq, PartialEq, Default, Clone)]
+struct MyEffect(u8);
+
+#[test]
+fn apply_immediately() {
+    let mut world = World::new();
+
+    let mut target = world.spawn_empty();
+    target.with_effect(EffectBundle {
+        name: Name::new("Effect"),
+        mode: Some(EffectMode::Insert),
+        bundle: MyEffect(0),
+        ..Default::default()
+    });
+
+    // No flush is needed.
+    let effects = target.get::<EffectedBy>().unwrap();
+    assert_eq!(effects.len(), 1);
+    let effect = effects.collection()[0];
+
+    target.with_effect(EffectBundle {
+        name: Name::new("Effect"),
+        mode: Some(EffectMode::Insert),
+        bundle: MyEffect(1),
+        ..Default::default()
+    });
+
+    assert_eq!(target.get::<EffectedBy>().unwrap().collection(), &[effect]);
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(1)));
+}
+
+#[test]
+fn apply_stacked() {
+    let mut world = World::new();
+
+    let mut target = world.spawn_empty();
+    for i in 0..3 {
+        target.with_effect(EffectBundle {
+            bundle: MyEffect(i),
+            ..Default::default()
+        });
+    }
+
+    assert_eq!(target.get::<EffectedBy>().unwrap().len(), 3);
+}
+
+#[test]
+fn apply_from_world() {
+    let mut world = World::new();
+    let target = world.spawn_empty().id();
+
+    world.apply_effect(
+        target,
+        EffectBundle {
+            name: Name::new("Effect"),
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(0),
+            ..Default::default()
+        },
+    );
+
+    // No flush is needed.
+    let effect = world.get::<EffectedBy>(target).unwrap().collection()[0];
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(0)));
+
+    world.apply_effect(
+        target,
+        EffectBundle {
+            name: Name::new("Effect"),
+            mode: Some(EffectMode::Insert),
+            bundle: MyEffect(1),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        world.get::<EffectedBy>(target).unwrap().collection(),
+        &[effect]
+    );
+    assert_eq!(world.get::<MyEffect>(effect), Some(&MyEffect(1)));
+}